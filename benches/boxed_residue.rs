@@ -4,7 +4,7 @@ use criterion::{
 };
 use crypto_bigint::{
     modular::{BoxedResidue, BoxedResidueParams},
-    BoxedUint,
+    BoxedUint, Public,
 };
 use num_bigint::BigUint;
 use rand_core::OsRng;
@@ -62,6 +62,22 @@ fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
         )
     });
 
+    group.bench_function("modpow_vartime, BoxedUint^BoxedUint", |b| {
+        b.iter_batched(
+            || {
+                let x = BoxedUint::random(&mut OsRng, UINT_BITS);
+                let x_m = BoxedResidue::new(x, params.clone());
+                let p = Public::new(
+                    BoxedUint::random(&mut OsRng, UINT_BITS)
+                        | (BoxedUint::one_with_precision(UINT_BITS) << (UINT_BITS - 1)),
+                );
+                (x_m, p)
+            },
+            |(x, p)| black_box(x.pow_vartime(&p)),
+            BatchSize::SmallInput,
+        )
+    });
+
     group.bench_function("modpow, BigUint^BigUint (num-bigint-dig)", |b| {
         b.iter_batched(
             || {
@@ -90,8 +106,13 @@ fn bench_montgomery_conversion<M: Measurement>(group: &mut BenchmarkGroup<'_, M>
 
     group.bench_function("BoxedResidueParams::new_vartime", |b| {
         b.iter_batched(
-            || BoxedUint::random(&mut OsRng, UINT_BITS) | BoxedUint::one_with_precision(UINT_BITS),
-            |modulus| black_box(BoxedResidueParams::new_vartime(modulus)),
+            || {
+                Public::new(
+                    BoxedUint::random(&mut OsRng, UINT_BITS)
+                        | BoxedUint::one_with_precision(UINT_BITS),
+                )
+            },
+            |modulus| black_box(Public::<BoxedResidueParams>::new_vartime(modulus)),
             BatchSize::SmallInput,
         )
     });
@@ -4,7 +4,7 @@ use criterion::{
 };
 use crypto_bigint::{
     modular::{DynResidue, DynResidueParams},
-    Random, U256,
+    Public, Random, U256,
 };
 use rand_core::OsRng;
 
@@ -40,6 +40,19 @@ fn bench_montgomery_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
         )
     });
 
+    group.bench_function("modpow_vartime, U256^U256", |b| {
+        b.iter_batched(
+            || {
+                let x = U256::random(&mut OsRng);
+                let x_m = DynResidue::new(&x, params);
+                let p = Public::new(U256::random(&mut OsRng) | (U256::ONE << (U256::BITS - 1)));
+                (x_m, p)
+            },
+            |(x, p)| black_box(x.pow_vartime(&p)),
+            BatchSize::SmallInput,
+        )
+    });
+
     #[cfg(feature = "alloc")]
     for i in [1, 2, 3, 4, 10, 100] {
         group.bench_function(
@@ -0,0 +1,83 @@
+use criterion::{
+    black_box, criterion_group, criterion_main, measurement::Measurement, BatchSize,
+    BenchmarkGroup, Criterion,
+};
+use crypto_bigint::{
+    impl_modulus, impl_pseudo_mersenne_modulus, impl_solinas_modulus,
+    modular::{Residue, SpecialResidue},
+    Random, U256,
+};
+use rand_core::OsRng;
+
+impl_pseudo_mersenne_modulus!(Curve25519, U256, 255, 19);
+impl_solinas_modulus!(P256, U256, 256, [+224, -192, -96, +0]);
+impl_modulus!(
+    Curve25519Montgomery,
+    U256,
+    "7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed"
+);
+impl_modulus!(
+    P256Montgomery,
+    U256,
+    "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"
+);
+
+fn bench_special_ops<M: Measurement>(group: &mut BenchmarkGroup<'_, M>) {
+    group.bench_function("multiplication, 2^255 - 19 (pseudo-Mersenne)", |b| {
+        b.iter_batched(
+            || {
+                let x = SpecialResidue::<Curve25519, { U256::LIMBS }>::random(&mut OsRng);
+                let y = SpecialResidue::<Curve25519, { U256::LIMBS }>::random(&mut OsRng);
+                (x, y)
+            },
+            |(x, y)| black_box(x * y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("multiplication, 2^255 - 19 (Montgomery)", |b| {
+        b.iter_batched(
+            || {
+                let x = Residue::<Curve25519Montgomery, { U256::LIMBS }>::random(&mut OsRng);
+                let y = Residue::<Curve25519Montgomery, { U256::LIMBS }>::random(&mut OsRng);
+                (x, y)
+            },
+            |(x, y)| black_box(x * y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("multiplication, P-256 (Solinas)", |b| {
+        b.iter_batched(
+            || {
+                let x = SpecialResidue::<P256, { U256::LIMBS }>::random(&mut OsRng);
+                let y = SpecialResidue::<P256, { U256::LIMBS }>::random(&mut OsRng);
+                (x, y)
+            },
+            |(x, y)| black_box(x * y),
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("multiplication, P-256 (Montgomery)", |b| {
+        b.iter_batched(
+            || {
+                let x = Residue::<P256Montgomery, { U256::LIMBS }>::random(&mut OsRng);
+                let y = Residue::<P256Montgomery, { U256::LIMBS }>::random(&mut OsRng);
+                (x, y)
+            },
+            |(x, y)| black_box(x * y),
+            BatchSize::SmallInput,
+        )
+    });
+}
+
+fn bench_special(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Special-form modulus arithmetic");
+    bench_special_ops(&mut group);
+    group.finish();
+}
+
+criterion_group!(benches, bench_special);
+
+criterion_main!(benches);
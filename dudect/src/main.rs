@@ -3,8 +3,10 @@
 //! These use the `dudect_bencher` crate to check certain operations for
 //! constant-time behavior.
 
-use crypto_bigint::{Random, U128, U256};
+use crypto_bigint::{BoxedUint, ConstantTimeLookup, NonZero, Random, U128, U256};
 use dudect_bencher::{ctbench_main, BenchRng, Class, CtRunner};
+use std::hint::black_box;
+use subtle::Choice;
 
 /// Check `UInt::reduce` for constant-time operation.
 fn reduce(runner: &mut CtRunner, mut rng: &mut BenchRng) {
@@ -18,7 +20,7 @@ fn reduce(runner: &mut CtRunner, mut rng: &mut BenchRng) {
     let b = U256::random(&mut rng);
 
     // Random modulus (256-bit)
-    let modulus = U256::random(&mut rng);
+    let modulus = NonZero::new(U256::random(&mut rng)).unwrap();
 
     // Precomputing the inputs appears to eliminate some noise
     let mut inputs = vec![];
@@ -40,4 +42,101 @@ fn reduce(runner: &mut CtRunner, mut rng: &mut BenchRng) {
     }
 }
 
-ctbench_main!(reduce);
+/// Check `ConstantTimeLookup::ct_lookup` for constant-time operation.
+fn ct_lookup(runner: &mut CtRunner, mut rng: &mut BenchRng) {
+    let table: Vec<U256> = (0..TABLE_SIZE).map(|_| U256::random(&mut rng)).collect();
+
+    for (class, index) in lookup_inputs() {
+        runner.run_one(class, || {
+            for _ in 0..ITERATIONS_INNER {
+                black_box(U256::ct_lookup(&table, index));
+            }
+        })
+    }
+}
+
+/// Check `ConstantTimeLookup::ct_select_slice` for constant-time operation.
+fn ct_select_slice(runner: &mut CtRunner, mut rng: &mut BenchRng) {
+    let a: Vec<U256> = (0..TABLE_SIZE).map(|_| U256::random(&mut rng)).collect();
+    let b: Vec<U256> = (0..TABLE_SIZE).map(|_| U256::random(&mut rng)).collect();
+
+    for (class, choice) in select_inputs() {
+        runner.run_one(class, || {
+            let mut out = vec![U256::ZERO; TABLE_SIZE];
+            for _ in 0..ITERATIONS_INNER {
+                U256::ct_select_slice(&mut out, &a, &b, Choice::from(choice));
+                black_box(&out);
+            }
+        })
+    }
+}
+
+/// Check `ConstantTimeLookup::ct_lookup` for `BoxedUint` for constant-time operation.
+fn boxed_ct_lookup(runner: &mut CtRunner, mut rng: &mut BenchRng) {
+    let table: Vec<BoxedUint> = (0..TABLE_SIZE)
+        .map(|_| BoxedUint::random(&mut rng, 256))
+        .collect();
+
+    for (class, index) in lookup_inputs() {
+        runner.run_one(class, || {
+            for _ in 0..ITERATIONS_INNER {
+                black_box(BoxedUint::ct_lookup(&table, index));
+            }
+        })
+    }
+}
+
+/// Check `ConstantTimeLookup::ct_select_slice` for `BoxedUint` for constant-time operation.
+fn boxed_ct_select_slice(runner: &mut CtRunner, mut rng: &mut BenchRng) {
+    let a: Vec<BoxedUint> = (0..TABLE_SIZE)
+        .map(|_| BoxedUint::random(&mut rng, 256))
+        .collect();
+    let b: Vec<BoxedUint> = (0..TABLE_SIZE)
+        .map(|_| BoxedUint::random(&mut rng, 256))
+        .collect();
+
+    for (class, choice) in select_inputs() {
+        runner.run_one(class, || {
+            let mut out = vec![BoxedUint::zero_with_precision(256); TABLE_SIZE];
+            for _ in 0..ITERATIONS_INNER {
+                BoxedUint::ct_select_slice(&mut out, &a, &b, Choice::from(choice));
+                black_box(&out);
+            }
+        })
+    }
+}
+
+/// Number of measurements of each class for the table operations.
+const ITERATIONS_OUTER: usize = 10_000;
+
+/// Number of operations in each measurement for the table operations.
+const ITERATIONS_INNER: usize = 10_000;
+
+/// Size of the tables.
+const TABLE_SIZE: usize = 16;
+
+/// Looks up the first element for the left class, and the last one for the right class.
+///
+/// Precomputing the inputs appears to eliminate some noise.
+fn lookup_inputs() -> Vec<(Class, usize)> {
+    let mut inputs = vec![(Class::Left, 0); ITERATIONS_OUTER];
+    inputs.extend(vec![(Class::Right, TABLE_SIZE - 1); ITERATIONS_OUTER]);
+    inputs
+}
+
+/// Selects the first slice for the left class, and the second one for the right class.
+///
+/// Precomputing the inputs appears to eliminate some noise.
+fn select_inputs() -> Vec<(Class, u8)> {
+    let mut inputs = vec![(Class::Left, 0); ITERATIONS_OUTER];
+    inputs.extend(vec![(Class::Right, 1); ITERATIONS_OUTER]);
+    inputs
+}
+
+ctbench_main!(
+    reduce,
+    ct_lookup,
+    ct_select_slice,
+    boxed_ct_lookup,
+    boxed_ct_select_slice
+);
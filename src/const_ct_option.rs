@@ -0,0 +1,289 @@
+//! Constant-time optional values usable from `const fn`.
+
+use crate::{
+    modular::{DynResidue, Residue, ResidueParams},
+    CtChoice, Limb, NonZero, Reciprocal, Uint,
+};
+use subtle::CtOption;
+
+/// An optional value returned by constant-time `const fn`s, analogous to [`CtOption`].
+///
+/// The value is always present, but is unspecified (although self-consistent) when
+/// [`is_some`][`ConstCtOption::is_some`] is falsy.
+// TODO: should be replaced by `subtle::CtOption` when `subtle` starts supporting const fns.
+#[derive(Debug, Copy, Clone)]
+pub struct ConstCtOption<T> {
+    value: T,
+    is_some: CtChoice,
+}
+
+impl<T> ConstCtOption<T> {
+    /// Creates a new option which contains `value` if `is_some` is truthy.
+    #[inline]
+    pub const fn new(value: T, is_some: CtChoice) -> Self {
+        Self { value, is_some }
+    }
+
+    /// Creates a new option containing `value`.
+    #[inline]
+    pub const fn some(value: T) -> Self {
+        Self::new(value, CtChoice::TRUE)
+    }
+
+    /// Creates a new empty option, with `dummy_value` as the placeholder for the value.
+    #[inline]
+    pub const fn none(dummy_value: T) -> Self {
+        Self::new(dummy_value, CtChoice::FALSE)
+    }
+
+    /// Returns the contents of this option along with the choice indicating whether it is some,
+    /// without checking the latter.
+    ///
+    /// Closures cannot be called from a `const fn`, so this together with
+    /// [`new`][`ConstCtOption::new`] and [`and_choice`][`ConstCtOption::and_choice`] is the
+    /// `const` counterpart of [`map`][`ConstCtOption::map`] and
+    /// [`and_then`][`ConstCtOption::and_then`].
+    #[inline]
+    pub const fn into_components(self) -> (T, CtChoice)
+    where
+        T: Copy,
+    {
+        (self.value, self.is_some)
+    }
+
+    /// Returns a reference to the contents of this option along with the choice indicating
+    /// whether it is some, without checking the latter.
+    #[inline]
+    pub const fn components_ref(&self) -> (&T, CtChoice) {
+        (&self.value, self.is_some)
+    }
+
+    /// Converts from `&ConstCtOption<T>` to `ConstCtOption<&T>`.
+    #[inline]
+    pub const fn as_ref(&self) -> ConstCtOption<&T> {
+        ConstCtOption {
+            value: &self.value,
+            is_some: self.is_some,
+        }
+    }
+
+    /// Returns the truthy value if this option contains a value.
+    #[inline]
+    pub const fn is_some(&self) -> CtChoice {
+        self.is_some
+    }
+
+    /// Returns the truthy value if this option is empty.
+    #[inline]
+    pub const fn is_none(&self) -> CtChoice {
+        self.is_some.not()
+    }
+
+    /// Combines the choice of this option with `is_some`, leaving the value unchanged.
+    #[inline]
+    pub const fn and_choice(mut self, is_some: CtChoice) -> Self {
+        self.is_some = self.is_some.and(is_some);
+        self
+    }
+
+    /// Returns the contained value, panicking with the given `msg` if this option is empty.
+    ///
+    /// Note that this branches on the choice, and is therefore not constant-time.
+    #[inline]
+    #[track_caller]
+    pub const fn expect(self, msg: &str) -> T
+    where
+        T: Copy,
+    {
+        assert!(self.is_some.is_true_vartime(), "{}", msg);
+        self.value
+    }
+
+    /// Returns the contained value, panicking if this option is empty.
+    ///
+    /// Note that this branches on the choice, and is therefore not constant-time.
+    #[inline]
+    #[track_caller]
+    pub const fn unwrap(self) -> T
+    where
+        T: Copy,
+    {
+        assert!(
+            self.is_some.is_true_vartime(),
+            "called `ConstCtOption::unwrap()` on a `None` value"
+        );
+        self.value
+    }
+
+    /// Maps the contained value using `f`, which is called regardless of whether this option is
+    /// some or none.
+    ///
+    /// Not available in `const fn`s; use [`into_components`][`ConstCtOption::into_components`]
+    /// there instead.
+    #[inline]
+    pub fn map<U, F>(self, f: F) -> ConstCtOption<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        ConstCtOption::new(f(self.value), self.is_some)
+    }
+
+    /// Maps the contained value using `f` and combines the resulting choices; `f` is called
+    /// regardless of whether this option is some or none.
+    ///
+    /// Not available in `const fn`s; use [`into_components`][`ConstCtOption::into_components`]
+    /// with [`and_choice`][`ConstCtOption::and_choice`] there instead.
+    #[inline]
+    pub fn and_then<U, F>(self, f: F) -> ConstCtOption<U>
+    where
+        F: FnOnce(T) -> ConstCtOption<U>,
+    {
+        let is_some = self.is_some;
+        f(self.value).and_choice(is_some)
+    }
+
+    /// Converts this option into an [`Option`].
+    ///
+    /// Note that this branches on the choice, and is therefore not constant-time.
+    #[inline]
+    pub fn into_option(self) -> Option<T> {
+        if self.is_some.is_true_vartime() {
+            Some(self.value)
+        } else {
+            None
+        }
+    }
+}
+
+impl<T> From<ConstCtOption<T>> for CtOption<T> {
+    fn from(opt: ConstCtOption<T>) -> Self {
+        CtOption::new(opt.value, opt.is_some.into())
+    }
+}
+
+impl<T> From<ConstCtOption<T>> for Option<T> {
+    fn from(opt: ConstCtOption<T>) -> Self {
+        opt.into_option()
+    }
+}
+
+impl<const LIMBS: usize> ConstCtOption<Uint<LIMBS>> {
+    /// Returns the contained value if some, or `default` otherwise, in constant time.
+    #[inline]
+    pub const fn unwrap_or(self, default: Uint<LIMBS>) -> Uint<LIMBS> {
+        Uint::ct_select(&default, &self.value, self.is_some)
+    }
+}
+
+impl<const LIMBS: usize> ConstCtOption<NonZero<Uint<LIMBS>>> {
+    /// Returns the contained value if some, or `default` otherwise, in constant time.
+    #[inline]
+    pub const fn unwrap_or(self, default: NonZero<Uint<LIMBS>>) -> NonZero<Uint<LIMBS>> {
+        NonZero(Uint::ct_select(&default.0, &self.value.0, self.is_some))
+    }
+}
+
+impl ConstCtOption<Limb> {
+    /// Returns the contained value if some, or `default` otherwise, in constant time.
+    #[inline]
+    pub const fn unwrap_or(self, default: Limb) -> Limb {
+        Limb::ct_select(default, self.value, self.is_some)
+    }
+}
+
+impl ConstCtOption<NonZero<Limb>> {
+    /// Returns the contained value if some, or `default` otherwise, in constant time.
+    #[inline]
+    pub const fn unwrap_or(self, default: NonZero<Limb>) -> NonZero<Limb> {
+        NonZero(Limb::ct_select(default.0, self.value.0, self.is_some))
+    }
+}
+
+impl ConstCtOption<Reciprocal> {
+    /// Returns the contained value if some, or `default` otherwise, in constant time.
+    #[inline]
+    pub const fn unwrap_or(self, default: Reciprocal) -> Reciprocal {
+        Reciprocal::ct_select(&default, &self.value, self.is_some)
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> ConstCtOption<Residue<MOD, LIMBS>> {
+    /// Returns the contained value if some, or `default` otherwise, in constant time.
+    #[inline]
+    pub const fn unwrap_or(self, default: Residue<MOD, LIMBS>) -> Residue<MOD, LIMBS> {
+        Residue::from_montgomery(Uint::ct_select(
+            default.as_montgomery(),
+            self.value.as_montgomery(),
+            self.is_some,
+        ))
+    }
+}
+
+impl<const LIMBS: usize> ConstCtOption<DynResidue<LIMBS>> {
+    /// Returns the contained value if some, or `default` otherwise, in constant time.
+    ///
+    /// Both values are expected to have the same residue parameters.
+    #[inline]
+    pub const fn unwrap_or(self, default: DynResidue<LIMBS>) -> DynResidue<LIMBS> {
+        DynResidue::from_montgomery(
+            Uint::ct_select(
+                default.as_montgomery(),
+                self.value.as_montgomery(),
+                self.is_some,
+            ),
+            *self.value.params(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ConstCtOption;
+    use crate::{CtChoice, NonZero, U64};
+
+    const TWO: NonZero<U64> = NonZero::<U64>::const_new(U64::from_u8(2)).expect("two is nonzero");
+
+    const fn double_nonzero(x: U64) -> ConstCtOption<U64> {
+        let (nz, is_some) = NonZero::<U64>::const_new(x).into_components();
+        ConstCtOption::new(nz.0.wrapping_add(&nz.0), is_some)
+    }
+
+    #[test]
+    fn unwrap_or() {
+        let some = ConstCtOption::some(U64::ONE);
+        let none = ConstCtOption::none(U64::ONE);
+
+        assert_eq!(some.unwrap_or(U64::MAX), U64::ONE);
+        assert_eq!(none.unwrap_or(U64::MAX), U64::MAX);
+    }
+
+    #[test]
+    fn const_context() {
+        assert_eq!(*TWO, U64::from_u8(2));
+        assert!(NonZero::<U64>::const_new(U64::ZERO)
+            .is_none()
+            .is_true_vartime());
+
+        const FOUR: ConstCtOption<U64> = double_nonzero(U64::from_u8(2));
+        assert_eq!(FOUR.unwrap(), U64::from_u8(4));
+        assert!(double_nonzero(U64::ZERO).is_none().is_true_vartime());
+    }
+
+    #[test]
+    fn map_and_then() {
+        let some = ConstCtOption::some(U64::ONE);
+        let two = some.map(|x| x.wrapping_add(&x));
+        assert!(two.is_some().is_true_vartime());
+        assert_eq!(two.unwrap(), U64::from_u8(2));
+
+        let none = some.and_then(|x| ConstCtOption::new(x, CtChoice::FALSE));
+        assert!(none.is_none().is_true_vartime());
+        assert_eq!(none.into_option(), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "called `ConstCtOption::unwrap()` on a `None` value")]
+    fn unwrap_none() {
+        ConstCtOption::none(U64::ONE).unwrap();
+    }
+}
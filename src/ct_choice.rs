@@ -22,126 +22,142 @@ impl CtChoice {
     }
 
     /// Returns the truthy value if `value == Word::MAX`, and the falsy value if `value == 0`.
-    /// Panics for other values.
+    ///
+    /// Only the most significant bit of `value` is taken into account, so any other input
+    /// still produces a well-formed choice.
     #[inline]
-    pub(crate) const fn from_word_mask(value: Word) -> Self {
-        debug_assert!(value == Self::FALSE.0 || value == Self::TRUE.0);
-        Self(value)
+    pub const fn from_word_mask(value: Word) -> Self {
+        Self::from_word_lsb(value >> (Word::BITS - 1))
     }
 
     /// Returns the truthy value if `value == 1`, and the falsy value if `value == 0`.
-    /// Panics for other values.
+    ///
+    /// Only the least significant bit of `value` is taken into account.
     #[inline]
-    pub(crate) const fn from_word_lsb(value: Word) -> Self {
-        debug_assert!(value == 0 || value == 1);
-        Self(value.wrapping_neg())
+    pub const fn from_word_lsb(value: Word) -> Self {
+        Self((value & 1).wrapping_neg())
     }
 
+    /// Returns the truthy value if `value == 1`, and the falsy value if `value == 0`.
+    ///
+    /// Only the least significant bit of `value` is taken into account.
     #[inline]
-    pub(crate) const fn from_u32_lsb(value: u32) -> Self {
-        debug_assert!(value == 0 || value == 1);
+    pub const fn from_u32_lsb(value: u32) -> Self {
         #[allow(trivial_numeric_casts)]
-        Self((value as Word).wrapping_neg())
+        Self(((value & 1) as Word).wrapping_neg())
     }
 
     /// Returns the truthy value if `value != 0`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_u32_nonzero(value: u32) -> Self {
+    pub const fn from_u32_nonzero(value: u32) -> Self {
         Self::from_u32_lsb((value | value.wrapping_neg()) >> (u32::BITS - 1))
     }
 
     /// Returns the truthy value if `value != 0`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_word_nonzero(value: Word) -> Self {
+    pub const fn from_word_nonzero(value: Word) -> Self {
         Self::from_word_lsb((value | value.wrapping_neg()) >> (Word::BITS - 1))
     }
 
     /// Returns the truthy value if `x == y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_u32_eq(x: u32, y: u32) -> Self {
+    pub const fn from_u32_eq(x: u32, y: u32) -> Self {
         Self::from_u32_nonzero(x ^ y).not()
     }
 
     /// Returns the truthy value if `x == y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_word_eq(x: Word, y: Word) -> Self {
+    pub const fn from_word_eq(x: Word, y: Word) -> Self {
         Self::from_word_nonzero(x ^ y).not()
     }
 
     /// Returns the truthy value if `x < y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_word_lt(x: Word, y: Word) -> Self {
+    pub const fn from_word_lt(x: Word, y: Word) -> Self {
         let bit = (((!x) & y) | (((!x) | y) & (x.wrapping_sub(y)))) >> (Word::BITS - 1);
         Self::from_word_lsb(bit)
     }
 
     /// Returns the truthy value if `x < y`, and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_u32_lt(x: u32, y: u32) -> Self {
+    pub const fn from_u32_lt(x: u32, y: u32) -> Self {
         let bit = (((!x) & y) | (((!x) | y) & (x.wrapping_sub(y)))) >> (u32::BITS - 1);
         Self::from_u32_lsb(bit)
     }
 
     /// Returns the truthy value if `x <= y` and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_word_le(x: Word, y: Word) -> Self {
+    pub const fn from_word_le(x: Word, y: Word) -> Self {
         let bit = (((!x) | y) & ((x ^ y) | !(y.wrapping_sub(x)))) >> (Word::BITS - 1);
         Self::from_word_lsb(bit)
     }
 
     /// Returns the truthy value if `x <= y` and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn from_u32_le(x: u32, y: u32) -> Self {
+    pub const fn from_u32_le(x: u32, y: u32) -> Self {
         let bit = (((!x) | y) & ((x ^ y) | !(y.wrapping_sub(x)))) >> (u32::BITS - 1);
         Self::from_u32_lsb(bit)
     }
 
+    /// Returns the logical negation of `self`.
     #[inline]
-    pub(crate) const fn not(&self) -> Self {
+    pub const fn not(&self) -> Self {
         Self(!self.0)
     }
 
+    /// Returns the logical disjunction of `self` and `other`.
     #[inline]
-    pub(crate) const fn or(&self, other: Self) -> Self {
+    pub const fn or(&self, other: Self) -> Self {
         Self(self.0 | other.0)
     }
 
+    /// Returns the logical conjunction of `self` and `other`.
     #[inline]
-    pub(crate) const fn and(&self, other: Self) -> Self {
+    pub const fn and(&self, other: Self) -> Self {
         Self(self.0 & other.0)
     }
 
+    /// Returns the exclusive disjunction of `self` and `other`.
+    #[inline]
+    pub const fn xor(&self, other: Self) -> Self {
+        Self(self.0 ^ other.0)
+    }
+
     /// Return `b` if `self` is truthy, otherwise return `a`.
     #[inline]
-    pub(crate) const fn select_word(&self, a: Word, b: Word) -> Word {
+    pub const fn select_word(&self, a: Word, b: Word) -> Word {
         a ^ (self.0 & (a ^ b))
     }
 
     /// Return `b` if `self` is truthy, otherwise return `a`.
     #[inline]
-    pub(crate) const fn select_u32(&self, a: u32, b: u32) -> u32 {
+    pub const fn select_u32(&self, a: u32, b: u32) -> u32 {
         a ^ (self.as_u32_mask() & (a ^ b))
     }
 
     /// Return `x` if `self` is truthy, otherwise return 0.
     #[inline]
-    pub(crate) const fn if_true_word(&self, x: Word) -> Word {
+    pub const fn if_true_word(&self, x: Word) -> Word {
         x & self.0
     }
 
     /// Return `x` if `self` is truthy, otherwise return 0.
     #[inline]
-    pub(crate) const fn if_true_u32(&self, x: u32) -> u32 {
+    pub const fn if_true_u32(&self, x: u32) -> u32 {
         x & self.as_u32_mask()
     }
 
+    /// Returns `true` if `self` is truthy.
+    ///
+    /// Note that branching on the result is not constant-time.
     #[inline]
-    pub(crate) const fn is_true_vartime(&self) -> bool {
+    pub const fn is_true_vartime(&self) -> bool {
         self.0 == CtChoice::TRUE.0
     }
 
+    /// Returns `1` if `self` is truthy, and `0` otherwise.
     #[inline]
-    pub(crate) const fn to_u8(self) -> u8 {
+    pub const fn to_u8(self) -> u8 {
         (self.0 as u8) & 1
     }
 }
@@ -170,4 +186,35 @@ mod tests {
         assert_eq!(CtChoice::TRUE.select_word(a, b), b);
         assert_eq!(CtChoice::FALSE.select_word(a, b), a);
     }
+
+    #[test]
+    fn logic() {
+        assert!(CtChoice::TRUE.and(CtChoice::TRUE).is_true_vartime());
+        assert!(!CtChoice::TRUE.and(CtChoice::FALSE).is_true_vartime());
+        assert!(CtChoice::TRUE.or(CtChoice::FALSE).is_true_vartime());
+        assert!(!CtChoice::FALSE.or(CtChoice::FALSE).is_true_vartime());
+        assert!(CtChoice::TRUE.xor(CtChoice::FALSE).is_true_vartime());
+        assert!(!CtChoice::TRUE.xor(CtChoice::TRUE).is_true_vartime());
+        assert!(CtChoice::FALSE.not().is_true_vartime());
+    }
+
+    #[test]
+    fn comparisons() {
+        assert!(CtChoice::from_word_lt(1, 2).is_true_vartime());
+        assert!(!CtChoice::from_word_lt(2, 2).is_true_vartime());
+        assert!(CtChoice::from_word_le(2, 2).is_true_vartime());
+        assert!(CtChoice::from_word_eq(3, 3).is_true_vartime());
+        assert!(!CtChoice::from_u32_nonzero(0).is_true_vartime());
+        assert_eq!(CtChoice::from_u32_lt(3, 1).to_u8(), 0);
+    }
+
+    #[test]
+    fn malformed_inputs() {
+        assert_eq!(CtChoice::from_word_lsb(2).to_u8(), 0);
+        assert_eq!(CtChoice::from_word_lsb(3).to_u8(), 1);
+        assert_eq!(CtChoice::from_u32_lsb(5).to_u8(), 1);
+        assert_eq!(CtChoice::from_word_mask(5).to_u8(), 0);
+        assert_eq!(CtChoice::from_word_mask(Word::MAX - 1).to_u8(), 1);
+        assert_eq!(CtChoice::from_word_mask(5).select_word(1, 2), 1);
+    }
 }
@@ -48,6 +48,15 @@
 //! pub const MODULUS_SHR1: U256 = MODULUS.shr(1);
 //! ```
 //!
+//! Fallible `const fn`s return a [`ConstCtOption`], with the condition represented by a
+//! [`CtChoice`] which can be combined and acted upon in const contexts as well:
+//!
+//! ```
+//! use crypto_bigint::{NonZero, U256};
+//!
+//! pub const TWO: NonZero<U256> = NonZero::<U256>::const_new(U256::from_u8(2)).expect("nonzero");
+//! ```
+//!
 //! Note that large constant computations may accidentally trigger a the `const_eval_limit` of the compiler.
 //! The current way to deal with this problem is to either simplify this computation,
 //! or increase the compiler's limit (currently a nightly feature).
@@ -111,10 +120,30 @@
 //! ```
 //!
 //! It also supports modular arithmetic over constant moduli using `Residue`,
-//! and over moduli set at runtime using `DynResidue`.
+//! and over moduli set at runtime using `DynResidue`, or `BoundedResidue` when
+//! the size of the modulus is chosen at runtime as well.
 //! That includes modular exponentiation and multiplicative inverses.
 //! These features are described in the [`modular`] module.
 //!
+//! ### Variable-time operations on public values
+//!
+//! Operations whose names end in `_vartime` leak their inputs through timing
+//! and must not be used with secret values. They are therefore only available
+//! on values declared public with the [`Public`] wrapper, e.g.
+//! [`Public::<Uint>::bits_vartime`], or take `Public` operands, e.g.
+//! exponentiation with a public exponent:
+//!
+//! ```
+//! use crypto_bigint::{modular::{DynResidue, DynResidueParams}, Public, U256};
+//!
+//! let params = DynResidueParams::new(&U256::from(11u8)).unwrap();
+//! let x = DynResidue::new(&U256::from(3u8), params);
+//! let e = Public::new(U256::from(5u8));
+//!
+//! // 3^5 mod 11 = 1
+//! assert_eq!(x.pow_vartime(&e).retrieve(), U256::ONE);
+//! ```
+//!
 //! ### Random number generation
 //!
 //! When the `rand_core` or `rand` features of this crate are enabled, it's
@@ -145,6 +174,47 @@
 //! # }
 //! ```
 //!
+//! Values in an arbitrary range, with an exact bit length, or odd values can be generated with
+//! [`Uint::random_in_range`], [`Uint::random_with_bit_length`] and [`Uint::random_odd`]:
+//!
+//! ```
+//! # #[cfg(feature = "rand")]
+//! # {
+//! use crypto_bigint::{U256, rand_core::OsRng};
+//!
+//! let n = U256::random_in_range(&mut OsRng, &U256::from(2u8), &U256::from(100u8));
+//! let p = U256::random_with_bit_length(&mut OsRng, 255);
+//! # }
+//! ```
+//!
+//! Sampling in a range uses rejection sampling: every candidate is generated and compared in
+//! constant time, so timing only reveals how many candidates were rejected, which is independent
+//! of the value which is eventually returned.
+//!
+//! ### `num-traits` interoperability
+//!
+//! When the `num-traits` feature is enabled, the traits from the [`num-traits`]
+//! crate are implemented for [`Uint`] and `BoxedUint`. Traits which require the
+//! arithmetic operators (e.g. `Zero`, `One`, `Num`) are implemented on the
+//! [`Wrapping`] types:
+//!
+//! ```
+//! # #[cfg(feature = "num-traits")]
+//! # {
+//! use crypto_bigint::{num_traits::Num, U128, Wrapping};
+//!
+//! let n = Wrapping::<U128>::from_str_radix("123456789", 10).unwrap();
+//! assert_eq!(n.0, U128::from(123456789u32));
+//! # }
+//! ```
+//!
+//! Note that these traits return `bool` and [`Option`] and are therefore not
+//! constant-time.
+//!
+//! The `num-bigint` feature additionally provides conversions to and from
+//! `BigUint`.
+//!
+//! [`num-traits`]: https://docs.rs/num-traits
 //! [`Add`]: core::ops::Add
 //! [`Div`]: core::ops::Div
 //! [`Mul`]: core::ops::Mul
@@ -167,27 +237,49 @@ pub mod modular;
 #[cfg(feature = "generic-array")]
 mod array;
 mod checked;
+mod const_ct_option;
 mod ct_choice;
 mod limb;
 mod non_zero;
+mod public;
+#[cfg(feature = "zeroize")]
+mod secret;
 mod traits;
 mod uint;
 mod wrapping;
 
 pub use crate::{
     checked::Checked,
+    const_ct_option::ConstCtOption,
     ct_choice::CtChoice,
     limb::{Limb, WideWord, Word},
     non_zero::NonZero,
+    public::Public,
     traits::*,
     uint::div_limb::Reciprocal,
+    uint::div_reciprocal::MultiLimbReciprocal,
     uint::*,
     wrapping::Wrapping,
 };
 pub use subtle;
 
 #[cfg(feature = "alloc")]
-pub use crate::uint::boxed::{encoding::DecodeError, BoxedUint};
+pub use crate::uint::boxed::BoxedUint;
+
+#[cfg(feature = "zeroize")]
+pub use crate::secret::SecretUint;
+
+#[cfg(all(feature = "alloc", feature = "zeroize"))]
+pub use crate::secret::SecretBoxedUint;
+
+#[cfg(feature = "num-traits")]
+pub use num_traits;
+
+#[cfg(feature = "num-bigint")]
+pub use num_bigint;
+
+#[cfg(feature = "ff")]
+pub use ff;
 
 #[cfg(feature = "generic-array")]
 pub use {
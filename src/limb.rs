@@ -19,9 +19,12 @@ mod sub;
 #[cfg(feature = "rand_core")]
 mod rand;
 
-use crate::{Bounded, Constants, ZeroConstant};
+use crate::{
+    traits::{ct_lookup_selectable, ct_select_slice_selectable},
+    Bounded, ConstantTimeLookup, Constants, ZeroConstant,
+};
 use core::fmt;
-use subtle::{Choice, ConditionallySelectable};
+use subtle::{Choice, ConditionallySelectable, CtOption};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -114,6 +117,16 @@ impl ConditionallySelectable for Limb {
     }
 }
 
+impl ConstantTimeLookup for Limb {
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_selectable(table, index)
+    }
+
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_selectable(out, a, b, choice)
+    }
+}
+
 impl fmt::Debug for Limb {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Limb(0x{self:X})")
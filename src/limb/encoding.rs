@@ -30,7 +30,6 @@ impl Encoding for Limb {
     }
 }
 
-#[cfg(feature = "alloc")]
 impl Limb {
     /// Decode limb from a big endian byte slice.
     ///
@@ -10,15 +10,40 @@
 //!
 //! The [`impl_modulus!`][`crate::impl_modulus`] macro can be used to define a compile-time modulus,
 //! whereas the [`const_residue!`][`crate::const_residue`] macro can define a [`Residue`] constant.
+//! For prime moduli, the [`impl_prime_modulus!`][`crate::impl_prime_modulus`] macro additionally
+//! checks primality at compile time and implements [`PrimeParams`], and the
+//! [`impl_multiplicative_generator!`][`crate::impl_multiplicative_generator`] macro implements
+//! [`GeneratorParams`] with a generator checked against a factorization of `MODULUS - 1`.
+//!
+//! When the `ff` feature is enabled, the `impl_field_element!` macro can be used to define a prime
+//! field element type implementing the [`ff::Field`] and [`ff::PrimeField`] traits on top of such
+//! a prime modulus and generator.
+//!
+//! # Special-form constant moduli
+//!
+//! The [`SpecialResidue`] and [`SpecialResidueParams`] types implement support for modular
+//! arithmetic with a fixed pseudo-Mersenne (`2^n - c`) or generalized Mersenne (Solinas) modulus,
+//! such as the base field moduli of Curve25519, secp256k1, P-256, P-384 and Ed448. These use a
+//! dedicated constant-time reduction instead of Montgomery form.
+//!
+//! The [`impl_pseudo_mersenne_modulus!`][`crate::impl_pseudo_mersenne_modulus`] and
+//! [`impl_solinas_modulus!`][`crate::impl_solinas_modulus`] macros can be used to define such a
+//! modulus.
 //!
 //! # Dynamic moduli chosen at runtime
 //!
 //! The [`DynResidue`] and [`DynResidueParams`] types implement support for modular arithmetic where
 //! the modulus can vary at runtime.
+//!
+//! The [`BoundedResidue`] and [`BoundedResidueParams`] types additionally allow the size of the
+//! modulus to be chosen at runtime, up to a maximum precision fixed at compile-time, without
+//! requiring heap allocations.
 
+mod bounded_residue;
 mod dyn_residue;
 mod reduction;
 mod residue;
+mod special_residue;
 
 mod add;
 mod bernstein_yang;
@@ -28,18 +53,34 @@ mod mul;
 mod pow;
 mod sub;
 
+#[cfg(feature = "ff")]
+mod prime_field;
+
 #[cfg(feature = "alloc")]
 pub(crate) mod boxed_residue;
 
 pub use self::{
     bernstein_yang::BernsteinYangInverter,
-    dyn_residue::{DynResidue, DynResidueParams},
+    bounded_residue::{BoundedResidue, BoundedResidueParams},
+    dyn_residue::{DynResidue, DynResidueAccumulator, DynResidueParams},
     reduction::montgomery_reduction,
-    residue::{Residue, ResidueParams},
+    residue::{
+        is_generator_vartime, is_prime_vartime, GeneratorParams, PrimeParams, Residue,
+        ResidueAccumulator, ResidueParams,
+    },
+    special_residue::{SolinasTerm, SpecialReduction, SpecialResidue, SpecialResidueParams},
 };
 
 #[cfg(feature = "alloc")]
-pub use self::boxed_residue::{BoxedResidue, BoxedResidueParams};
+pub use self::{
+    bernstein_yang::BoxedBernsteinYangInverter,
+    boxed_residue::{
+        BoxedResidue, BoxedResidueAccumulator, BoxedResidueParams, BoxedResidueScratch,
+    },
+};
+
+#[cfg(feature = "ff")]
+pub use self::prime_field::{to_hex_string, to_u64_words, PrimeFieldRepr};
 
 /// A generalization for numbers kept in optimized representations (e.g. Montgomery)
 /// that can be converted back to the original form.
@@ -58,9 +99,11 @@ mod tests {
         modular::{
             reduction::montgomery_reduction,
             residue::{Residue, ResidueParams},
+            DynResidue, DynResidueParams,
         },
         NonZero, Uint, U256, U64,
     };
+    use hex_literal::hex;
 
     impl_modulus!(
         Modulus1,
@@ -180,6 +223,40 @@ mod tests {
         assert_eq!(x, x_mod.retrieve());
     }
 
+    #[test]
+    fn test_reduce_wide_bytes() {
+        let bytes = hex!(
+            "44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56"
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        );
+        let (hi, lo) = (
+            U256::from_be_slice(&bytes[..32]),
+            U256::from_be_slice(&bytes[32..]),
+        );
+        let expected = U256::const_rem_wide((lo, hi), &Modulus2::MODULUS).unwrap();
+
+        let x_mod = Residue::<Modulus2, { Modulus2::LIMBS }>::reduce_wide_bytes(&bytes);
+        assert_eq!(x_mod.retrieve(), expected);
+
+        let params = DynResidueParams::from_residue_params::<Modulus2>();
+        assert_eq!(
+            DynResidue::reduce_wide_bytes(&bytes, params).retrieve(),
+            expected
+        );
+
+        // Shorter inputs are padded with zeros
+        let x_mod = Residue::<Modulus2, { Modulus2::LIMBS }>::from_uniform_bytes(&bytes[16..]);
+        let expected =
+            U256::const_rem_wide((lo, hi.bitand(&U256::MAX.shr(128))), &Modulus2::MODULUS);
+        assert_eq!(x_mod.retrieve(), expected.unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "not enough uniform bytes for the modulus")]
+    fn test_from_uniform_bytes_too_short() {
+        Residue::<Modulus2, { Modulus2::LIMBS }>::from_uniform_bytes(&[0u8; 47]);
+    }
+
     #[test]
     fn test_residue_macro() {
         let x =
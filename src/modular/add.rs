@@ -7,3 +7,10 @@ pub(crate) const fn add_montgomery_form<const LIMBS: usize>(
 ) -> Uint<LIMBS> {
     a.add_mod(b, modulus)
 }
+
+pub(crate) const fn double_montgomery_form<const LIMBS: usize>(
+    a: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+) -> Uint<LIMBS> {
+    a.add_mod(a, modulus)
+}
@@ -0,0 +1,435 @@
+//! Heap-allocated Bernstein-Yang inverter, whose size is chosen at runtime.
+
+use super::{inv_mod62, Matrix};
+use crate::{BoxedUint, Word};
+use alloc::{boxed::Box, vec};
+use subtle::{Choice, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Number of bits in each chunk of [`BoxedCInt`].
+const CHUNK_BITS: u32 = 62;
+
+/// Mask, in which the [`CHUNK_BITS`] lowest bits are 1 and only they.
+const CHUNK_MASK: u64 = u64::MAX >> (64 - CHUNK_BITS);
+
+/// Modular multiplicative inverter based on the Bernstein-Yang method, using heap-allocated
+/// integers whose size is determined by the modulus at runtime.
+///
+/// This is the counterpart of [`BernsteinYangInverter`][`super::BernsteinYangInverter`] for
+/// [`BoxedUint`], and computes the adjusted inverses `(1 / x) * A (mod M)` for a modulus `M` and
+/// an adjusting parameter `A` chosen as described there. The inverter can be created once for a
+/// given modulus and reused for any number of inversions.
+///
+/// Unlike [`BernsteinYangInverter`][`super::BernsteinYangInverter`], the inversion is constant
+/// time: it always performs the number of divsteps which is sufficient for any input with the bit
+/// precision of the modulus, as bounded in Theorem 11.2 of the Bernstein-Yang paper.
+#[derive(Clone, Debug)]
+pub struct BoxedBernsteinYangInverter {
+    /// Modulus
+    modulus: BoxedCInt,
+
+    /// Adjusting parameter
+    adjuster: BoxedCInt,
+
+    /// Multiplicative inverse of the modulus modulo 2^62
+    inverse: i64,
+
+    /// Number of 62-divstep jumps which is sufficient for any input
+    iterations: usize,
+
+    /// Bit precision of the modulus, used for the returned values
+    bits_precision: u32,
+}
+
+impl BoxedBernsteinYangInverter {
+    /// Creates the inverter for specified modulus and adjusting parameter.
+    ///
+    /// Returns `None` if the modulus is not odd. The adjusting parameter must not exceed the bit
+    /// precision of the modulus.
+    pub fn new(modulus: &BoxedUint, adjuster: &BoxedUint) -> CtOption<Self> {
+        CtOption::new(Self::new_unchecked(modulus, adjuster), modulus.is_odd())
+    }
+
+    /// Creates the inverter without checking that the modulus is odd, in which case the inverter
+    /// returns meaningless values.
+    pub(crate) fn new_unchecked(modulus: &BoxedUint, adjuster: &BoxedUint) -> Self {
+        debug_assert!(adjuster.bits_precision() <= modulus.bits_precision());
+
+        let bits_precision = modulus.bits_precision();
+        // Both the modulus and the inverted values have to fit in `62 * len - 64` bits
+        let len = ((bits_precision + 64 + CHUNK_BITS - 1) / CHUNK_BITS) as usize;
+
+        // A modulus of 0 bits is treated as one of a single zero word
+        let modulus_lo = [
+            modulus.as_words().first().copied().unwrap_or_default(),
+            modulus.as_words().get(1).copied().unwrap_or_default(),
+        ];
+
+        Self {
+            modulus: BoxedCInt::from_words(modulus.as_words(), len),
+            adjuster: BoxedCInt::from_words(adjuster.as_words(), len),
+            inverse: inv_mod62(&modulus_lo),
+            iterations: iterations(bits_precision),
+            bits_precision,
+        }
+    }
+
+    /// Returns either the adjusted modular multiplicative inverse for the argument or `None`
+    /// depending on invertibility of the argument, i.e. its coprimality with the modulus.
+    ///
+    /// The argument must not exceed the bit precision of the modulus.
+    pub fn invert(&self, value: &BoxedUint) -> CtOption<BoxedUint> {
+        let (inverse, is_some) = self.invert_inner(value);
+        CtOption::new(inverse, is_some)
+    }
+
+    /// Computes the adjusted inverse, returning an unspecified value along with a falsy
+    /// [`Choice`] if `value` is not invertible.
+    pub(crate) fn invert_inner(&self, value: &BoxedUint) -> (BoxedUint, Choice) {
+        debug_assert!(value.bits_precision() <= self.bits_precision);
+
+        let len = self.modulus.0.len();
+        let (mut d, mut e) = (BoxedCInt::zero(len), self.adjuster.clone());
+        let mut g = BoxedCInt::from_words(value.as_words(), len);
+        let (mut delta, mut f) = (1, self.modulus.clone());
+        let mut matrix;
+
+        for _ in 0..self.iterations {
+            (delta, matrix) = jump(&f, &g, delta);
+            (f, g) = fg(&f, &g, matrix);
+            (d, e) = self.de(&d, &e, matrix);
+        }
+
+        // At this point the absolute value of "f" equals the greatest common divisor
+        // of the integer to be inverted and the modulus the inverter was created for.
+        // Thus, if "f" is neither 1 nor -1, then the sought inverse does not exist
+        let antiunit = f.ct_eq(&BoxedCInt::minus_one(len));
+        let is_some = f.ct_eq(&BoxedCInt::one(len)) | antiunit;
+        let inverse = self.norm(d, antiunit).to_uint(self.bits_precision);
+
+        (inverse, is_some)
+    }
+
+    /// Returns the updated values of the variables d and e for specified initial ones and
+    /// Bernstein-Yang transition matrix multiplied by 2^62. The returned vector is congruent
+    /// modulo M to "matrix * (d, e)' / 2^62 (mod M)", where M is the modulus the inverter was
+    /// created for and "'" stands for the transpose operator. Both the input and output values
+    /// lie in the interval (-2 * M, M)
+    fn de(&self, d: &BoxedCInt, e: &BoxedCInt, t: Matrix) -> (BoxedCInt, BoxedCInt) {
+        let mask = CHUNK_MASK as i64;
+        let (d_sign, e_sign) = (d.sign() as i64, e.sign() as i64);
+        let mut md = t[0][0] * d_sign + t[0][1] * e_sign;
+        let mut me = t[1][0] * d_sign + t[1][1] * e_sign;
+
+        let cd = t[0][0]
+            .wrapping_mul(d.lowest() as i64)
+            .wrapping_add(t[0][1].wrapping_mul(e.lowest() as i64))
+            & mask;
+        let ce = t[1][0]
+            .wrapping_mul(d.lowest() as i64)
+            .wrapping_add(t[1][1].wrapping_mul(e.lowest() as i64))
+            & mask;
+
+        md -= (self.inverse.wrapping_mul(cd).wrapping_add(md)) & mask;
+        me -= (self.inverse.wrapping_mul(ce).wrapping_add(me)) & mask;
+
+        let cd = d
+            .mul(t[0][0])
+            .add(&e.mul(t[0][1]))
+            .add(&self.modulus.mul(md));
+        let ce = d
+            .mul(t[1][0])
+            .add(&e.mul(t[1][1]))
+            .add(&self.modulus.mul(me));
+
+        (cd.shift(), ce.shift())
+    }
+
+    /// Returns either "value (mod M)" or "-value (mod M)", where M is the modulus the
+    /// inverter was created for, depending on "negate", which determines the presence
+    /// of "-" in the used formula. The input integer lies in the interval (-2 * M, M)
+    fn norm(&self, value: BoxedCInt, negate: Choice) -> BoxedCInt {
+        let value = value.add(&self.modulus.and(value.sign_mask()));
+        let value = value.conditional_neg(0u64.wrapping_sub(negate.unwrap_u8().into()));
+        value.add(&self.modulus.and(value.sign_mask()))
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for BoxedBernsteinYangInverter {
+    fn zeroize(&mut self) {
+        self.modulus.0.zeroize();
+        self.adjuster.0.zeroize();
+        self.inverse.zeroize();
+    }
+}
+
+/// Returns the number of 62-divstep jumps which suffices to compute the inverse modulo a
+/// modulus of the given bit precision, according to Theorem 11.2 of the Bernstein-Yang paper.
+fn iterations(bits_precision: u32) -> usize {
+    let d = bits_precision as usize;
+    let divsteps = if d < 46 {
+        (49 * d + 80) / 17
+    } else {
+        (49 * d + 57) / 17
+    };
+    (divsteps + CHUNK_BITS as usize - 1) / CHUNK_BITS as usize
+}
+
+/// Returns the Bernstein-Yang transition matrix multiplied by 2^62 and the new value of the delta
+/// variable for the 62 basic steps of the Bernstein-Yang method, which are to be performed
+/// sequentially for specified initial values of f, g and delta.
+///
+/// Unlike the variable time jump of [`BernsteinYangInverter`][`super::BernsteinYangInverter`],
+/// the divsteps are performed one by one using masks instead of branches.
+fn jump(f: &BoxedCInt, g: &BoxedCInt, mut delta: i64) -> (i64, Matrix) {
+    let (mut f, mut g) = (f.lowest(), g.lowest());
+    // The matrix entries are kept as wrapping unsigned integers
+    let (mut u, mut v, mut q, mut r) = (1u64, 0u64, 0u64, 1u64);
+
+    for _ in 0..CHUNK_BITS {
+        // All ones if delta > 0
+        let c1 = (delta.wrapping_neg() >> 63) as u64;
+        // All ones if g is odd
+        let c2 = (g & 1).wrapping_neg();
+
+        // Conditionally negated f, u and v
+        let x = (f ^ c1).wrapping_sub(c1);
+        let y = (u ^ c1).wrapping_sub(c1);
+        let z = (v ^ c1).wrapping_sub(c1);
+
+        // If g is odd, add or subtract f to g depending on the sign of delta
+        g = g.wrapping_add(x & c2);
+        q = q.wrapping_add(y & c2);
+        r = r.wrapping_add(z & c2);
+
+        // If both delta > 0 and g is odd, replace f with the original g and negate delta
+        let swap = c1 & c2;
+        delta = (delta ^ swap as i64)
+            .wrapping_sub(swap as i64)
+            .wrapping_add(1);
+        f = f.wrapping_add(g & swap);
+        u = u.wrapping_add(q & swap);
+        v = v.wrapping_add(r & swap);
+
+        g >>= 1;
+        u <<= 1;
+        v <<= 1;
+    }
+
+    (delta, [[u as i64, v as i64], [q as i64, r as i64]])
+}
+
+/// Returns the updated values of the variables f and g for specified initial ones and
+/// Bernstein-Yang transition matrix multiplied by 2^62. The returned vector is
+/// "matrix * (f, g)' / 2^62", where "'" is the transpose operator
+fn fg(f: &BoxedCInt, g: &BoxedCInt, t: Matrix) -> (BoxedCInt, BoxedCInt) {
+    (
+        f.mul(t[0][0]).add(&g.mul(t[0][1])).shift(),
+        f.mul(t[1][0]).add(&g.mul(t[1][1])).shift(),
+    )
+}
+
+/// Big signed integer type with a number of 62-bit chunks chosen at runtime, whose values
+/// are stored in the two's complement code. The ordering of the chunks is little-endian.
+/// The arithmetic operations for this type are wrapping ones, and are performed in
+/// constant time.
+#[derive(Clone, Debug)]
+struct BoxedCInt(Box<[u64]>);
+
+impl BoxedCInt {
+    /// Representation of 0 using `len` chunks
+    fn zero(len: usize) -> Self {
+        Self(vec![0; len].into())
+    }
+
+    /// Representation of 1 using `len` chunks
+    fn one(len: usize) -> Self {
+        let mut ret = Self::zero(len);
+        ret.0[0] = 1;
+        ret
+    }
+
+    /// Representation of -1 using `len` chunks
+    fn minus_one(len: usize) -> Self {
+        Self(vec![CHUNK_MASK; len].into())
+    }
+
+    /// Returns the number equal modulo `2^(62 * len)` to the unsigned integer stored as
+    /// little-endian words.
+    #[allow(trivial_numeric_casts)]
+    fn from_words(words: &[Word], len: usize) -> Self {
+        let mut ret = Self::zero(len);
+        let (mut acc, mut acc_bits, mut i) = (0u128, 0, 0);
+
+        for &word in words {
+            acc |= (word as u128) << acc_bits;
+            acc_bits += Word::BITS;
+
+            while acc_bits >= CHUNK_BITS && i < len {
+                ret.0[i] = acc as u64 & CHUNK_MASK;
+                acc >>= CHUNK_BITS;
+                acc_bits -= CHUNK_BITS;
+                i += 1;
+            }
+        }
+
+        if i < len {
+            ret.0[i] = acc as u64 & CHUNK_MASK;
+        }
+
+        ret
+    }
+
+    /// Returns the lowest `bits_precision` bits of the current number as a [`BoxedUint`].
+    #[allow(trivial_numeric_casts)]
+    fn to_uint(&self, bits_precision: u32) -> BoxedUint {
+        let nlimbs = ((bits_precision + Word::BITS - 1) / Word::BITS) as usize;
+        let mut words = vec![0 as Word; nlimbs];
+        let (mut acc, mut acc_bits, mut i) = (0u128, 0, 0);
+
+        for &chunk in self.0.iter() {
+            acc |= (chunk as u128) << acc_bits;
+            acc_bits += CHUNK_BITS;
+
+            while acc_bits >= Word::BITS && i < nlimbs {
+                words[i] = acc as Word;
+                acc >>= Word::BITS;
+                acc_bits -= Word::BITS;
+                i += 1;
+            }
+        }
+
+        BoxedUint::from_words(words)
+    }
+
+    /// Returns the result of applying 62-bit right arithmetical shift to the current number
+    fn shift(&self) -> Self {
+        let len = self.0.len();
+        let mut ret = Self::zero(len);
+        ret.0[..len - 1].copy_from_slice(&self.0[1..]);
+        ret.0[len - 1] = self.sign_mask() & CHUNK_MASK;
+        ret
+    }
+
+    /// Returns the lowest 62 bits of the current number
+    fn lowest(&self) -> u64 {
+        self.0[0]
+    }
+
+    /// Returns 1 if the current number is negative, and 0 otherwise
+    fn sign(&self) -> u64 {
+        self.0[self.0.len() - 1] >> (CHUNK_BITS - 1)
+    }
+
+    /// Returns all ones if the current number is negative, and 0 otherwise
+    fn sign_mask(&self) -> u64 {
+        self.sign().wrapping_neg()
+    }
+
+    /// Returns the bitwise AND of every chunk of the current number with `mask`
+    fn and(&self, mask: u64) -> Self {
+        Self(self.0.iter().map(|chunk| chunk & mask).collect())
+    }
+
+    /// Returns the sum of the current number and `other`
+    fn add(&self, other: &Self) -> Self {
+        let mut ret = Self::zero(self.0.len());
+        let mut carry = 0;
+
+        for (out, (a, b)) in ret.0.iter_mut().zip(self.0.iter().zip(other.0.iter())) {
+            let sum = a + b + carry;
+            *out = sum & CHUNK_MASK;
+            carry = sum >> CHUNK_BITS;
+        }
+
+        ret
+    }
+
+    /// Returns the additive negation of the current number if `mask` is all ones, or the number
+    /// itself if `mask` is 0
+    fn conditional_neg(&self, mask: u64) -> Self {
+        // For the two's complement code the additive negation is the result
+        // of adding 1 to the bitwise inverted argument's representation
+        let mut ret = Self::zero(self.0.len());
+        let mut carry = mask & 1;
+
+        for (out, a) in ret.0.iter_mut().zip(self.0.iter()) {
+            let sum = (a ^ (mask & CHUNK_MASK)) + carry;
+            *out = sum & CHUNK_MASK;
+            carry = sum >> CHUNK_BITS;
+        }
+
+        ret
+    }
+
+    /// Returns the product of the current number and `other`, where `other` is not `i64::MIN`
+    fn mul(&self, other: i64) -> Self {
+        // See `CInt::mul` for the treatment of a negative short multiplicand, which is selected
+        // here using a mask instead of a branch
+        let sign = (other >> 63) as u64;
+        let abs = (other as u64 ^ sign).wrapping_sub(sign);
+        let mask = sign & CHUNK_MASK;
+
+        let mut ret = Self::zero(self.0.len());
+        let mut carry = abs & sign;
+
+        for (out, a) in ret.0.iter_mut().zip(self.0.iter()) {
+            let sum = (carry as u128) + ((a ^ mask) as u128) * (abs as u128);
+            *out = sum as u64 & CHUNK_MASK;
+            carry = (sum >> CHUNK_BITS) as u64;
+        }
+
+        ret
+    }
+}
+
+impl ConstantTimeEq for BoxedCInt {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::BoxedBernsteinYangInverter;
+    use crate::BoxedUint;
+    use hex_literal::hex;
+
+    #[test]
+    fn invert() {
+        let modulus = BoxedUint::from_be_slice(
+            &hex!("15477BCCEFE197328255BFA79A1217899016D927EF460F4FF404029D24FA4409"),
+            256,
+        )
+        .unwrap();
+        let value = BoxedUint::from_be_slice(
+            &hex!("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+            256,
+        )
+        .unwrap();
+        let inverter =
+            BoxedBernsteinYangInverter::new(&modulus, &BoxedUint::one_with_precision(256)).unwrap();
+
+        let inverse = inverter.invert(&value).unwrap();
+        let product = inverse.mul_mod(&value, &modulus);
+        assert_eq!(product, BoxedUint::one_with_precision(256));
+
+        assert!(bool::from(
+            inverter
+                .invert(&BoxedUint::zero_with_precision(256))
+                .is_none()
+        ));
+    }
+
+    #[test]
+    fn even_modulus() {
+        let modulus = BoxedUint::from(10u64);
+        assert!(bool::from(
+            BoxedBernsteinYangInverter::new(&modulus, &BoxedUint::one()).is_none()
+        ));
+    }
+}
@@ -0,0 +1,309 @@
+//! Implements `BoundedResidue`s, supporting modular arithmetic with a modulus whose size and value
+//! are chosen at runtime, without heap allocations.
+
+mod add;
+mod inv;
+mod mul;
+mod neg;
+mod pow;
+mod sub;
+
+use self::mul::mul_montgomery_form;
+use super::{reduction::montgomery_reduction_bounded, Retrieve};
+use crate::{
+    traits::{ct_lookup_selectable, ct_select_slice_selectable},
+    BoundedUint, ConstantTimeLookup, Limb, NonZero, Uint, Word,
+};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Parameters to efficiently go to/from the Montgomery form for an odd modulus whose size and value
+/// are both chosen at runtime, with a precision of up to `MAX_LIMBS` limbs.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BoundedResidueParams<const MAX_LIMBS: usize> {
+    /// The constant modulus
+    modulus: BoundedUint<MAX_LIMBS>,
+    /// Parameter used in Montgomery reduction
+    r: BoundedUint<MAX_LIMBS>,
+    /// R^2, used to move into Montgomery form
+    r2: BoundedUint<MAX_LIMBS>,
+    /// R^3, used to compute the multiplicative inverse
+    r3: BoundedUint<MAX_LIMBS>,
+    /// The lowest limbs of -(MODULUS^-1) mod R
+    /// We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
+    mod_neg_inv: Limb,
+}
+
+impl<const MAX_LIMBS: usize> BoundedResidueParams<MAX_LIMBS> {
+    /// Instantiates a new set of [`BoundedResidueParams`] representing the given `modulus`, which
+    /// must be odd.
+    ///
+    /// Returns a `CtOption` that is `None` if the provided modulus is not odd.
+    pub fn new(modulus: BoundedUint<MAX_LIMBS>) -> CtOption<Self> {
+        let bits_precision = modulus.bits_precision();
+        let is_odd = modulus.is_odd();
+
+        // Use a surrogate odd value in case an even modulus is passed.
+        // This will be rejected by the `is_odd` check, which will fail and return `None`.
+        let modulus =
+            BoundedUint::conditional_select(&BoundedUint::max(bits_precision), &modulus, is_odd);
+        let modulus_nz = NonZero::new(modulus).expect("modulus ensured non-zero");
+
+        let r = BoundedUint::max(bits_precision)
+            .rem(&modulus_nz)
+            .wrapping_add(&BoundedUint::one());
+
+        // R^2 is computed by doubling R modulo the modulus once for each bit of R, which avoids
+        // double-width intermediate values that might not fit in `MAX_LIMBS` limbs
+        let mut r2 = r;
+        for _ in 0..bits_precision {
+            r2 = r2.add_mod(&r2, &modulus);
+        }
+
+        // Since we are calculating the inverse modulo (Word::MAX+1),
+        // we can take the modulo right away and calculate the inverse of the first limb only.
+        let modulus_lo = Uint::<1>::new([modulus.limbs[0]]);
+        let mod_neg_inv = Limb(Word::MIN.wrapping_sub(modulus_lo.inv_mod2k(Word::BITS).limbs[0].0));
+
+        let r3 = mul_montgomery_form(&r2, &r2, &modulus, mod_neg_inv);
+
+        let params = Self {
+            modulus,
+            r,
+            r2,
+            r3,
+            mod_neg_inv,
+        };
+
+        CtOption::new(params, is_odd)
+    }
+
+    /// Modulus value.
+    pub fn modulus(&self) -> &BoundedUint<MAX_LIMBS> {
+        &self.modulus
+    }
+
+    /// Bits of precision in the modulus.
+    pub fn bits_precision(&self) -> u32 {
+        self.modulus.bits_precision()
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConditionallySelectable for BoundedResidueParams<MAX_LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            modulus: BoundedUint::conditional_select(&a.modulus, &b.modulus, choice),
+            r: BoundedUint::conditional_select(&a.r, &b.r, choice),
+            r2: BoundedUint::conditional_select(&a.r2, &b.r2, choice),
+            r3: BoundedUint::conditional_select(&a.r3, &b.r3, choice),
+            mod_neg_inv: Limb::conditional_select(&a.mod_neg_inv, &b.mod_neg_inv, choice),
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConstantTimeEq for BoundedResidueParams<MAX_LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.modulus.ct_eq(&other.modulus)
+            & self.r.ct_eq(&other.r)
+            & self.r2.ct_eq(&other.r2)
+            & self.r3.ct_eq(&other.r3)
+            & self.mod_neg_inv.ct_eq(&other.mod_neg_inv)
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const MAX_LIMBS: usize> Zeroize for BoundedResidueParams<MAX_LIMBS> {
+    fn zeroize(&mut self) {
+        self.modulus.zeroize();
+        self.r.zeroize();
+        self.r2.zeroize();
+        self.r3.zeroize();
+        self.mod_neg_inv.zeroize();
+    }
+}
+
+/// A residue represented using stack-allocated limbs, with a modulus chosen at runtime.
+///
+/// Alternative to `BoxedResidue` for targets without a heap, e.g. to verify RSA signatures in
+/// embedded firmware.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BoundedResidue<const MAX_LIMBS: usize> {
+    /// Value in the Montgomery domain.
+    montgomery_form: BoundedUint<MAX_LIMBS>,
+
+    /// Residue parameters.
+    residue_params: BoundedResidueParams<MAX_LIMBS>,
+}
+
+impl<const MAX_LIMBS: usize> BoundedResidue<MAX_LIMBS> {
+    /// Instantiates a new [`BoundedResidue`] that represents an integer modulo the provided
+    /// params.
+    pub fn new(
+        integer: BoundedUint<MAX_LIMBS>,
+        residue_params: BoundedResidueParams<MAX_LIMBS>,
+    ) -> Self {
+        debug_assert_eq!(integer.bits_precision(), residue_params.bits_precision());
+
+        let montgomery_form = mul_montgomery_form(
+            &integer,
+            &residue_params.r2,
+            &residue_params.modulus,
+            residue_params.mod_neg_inv,
+        );
+
+        Self {
+            montgomery_form,
+            residue_params,
+        }
+    }
+
+    /// Bits of precision in the modulus.
+    pub fn bits_precision(&self) -> u32 {
+        self.residue_params.bits_precision()
+    }
+
+    /// Retrieves the integer currently encoded in this [`BoundedResidue`], guaranteed to be
+    /// reduced.
+    pub fn retrieve(&self) -> BoundedUint<MAX_LIMBS> {
+        montgomery_reduction_bounded(
+            &(
+                self.montgomery_form,
+                BoundedUint::zero_with_precision(self.bits_precision()),
+            ),
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        )
+    }
+
+    /// Instantiates a new `Residue` that represents zero.
+    pub fn zero(residue_params: BoundedResidueParams<MAX_LIMBS>) -> Self {
+        Self {
+            montgomery_form: BoundedUint::zero_with_precision(residue_params.bits_precision()),
+            residue_params,
+        }
+    }
+
+    /// Instantiates a new `Residue` that represents 1.
+    pub fn one(residue_params: BoundedResidueParams<MAX_LIMBS>) -> Self {
+        Self {
+            montgomery_form: residue_params.r,
+            residue_params,
+        }
+    }
+
+    /// Returns the parameter struct used to initialize this residue.
+    pub fn params(&self) -> &BoundedResidueParams<MAX_LIMBS> {
+        &self.residue_params
+    }
+
+    /// Access the `BoundedResidue` value in Montgomery form.
+    pub fn as_montgomery(&self) -> &BoundedUint<MAX_LIMBS> {
+        &self.montgomery_form
+    }
+
+    /// Create a `BoundedResidue` from a value in Montgomery form.
+    pub fn from_montgomery(
+        integer: BoundedUint<MAX_LIMBS>,
+        residue_params: BoundedResidueParams<MAX_LIMBS>,
+    ) -> Self {
+        debug_assert_eq!(integer.bits_precision(), residue_params.bits_precision());
+        Self {
+            montgomery_form: integer,
+            residue_params,
+        }
+    }
+
+    /// Extract the value from the `BoundedResidue` in Montgomery form.
+    pub fn to_montgomery(&self) -> BoundedUint<MAX_LIMBS> {
+        self.montgomery_form
+    }
+}
+
+impl<const MAX_LIMBS: usize> Retrieve for BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedUint<MAX_LIMBS>;
+    fn retrieve(&self) -> BoundedUint<MAX_LIMBS> {
+        self.retrieve()
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConditionallySelectable for BoundedResidue<MAX_LIMBS> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            montgomery_form: BoundedUint::conditional_select(
+                &a.montgomery_form,
+                &b.montgomery_form,
+                choice,
+            ),
+            residue_params: BoundedResidueParams::conditional_select(
+                &a.residue_params,
+                &b.residue_params,
+                choice,
+            ),
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConstantTimeLookup for BoundedResidue<MAX_LIMBS> {
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_selectable(table, index)
+    }
+
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_selectable(out, a, b, choice)
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConstantTimeEq for BoundedResidue<MAX_LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.montgomery_form.ct_eq(&other.montgomery_form)
+            & self.residue_params.ct_eq(&other.residue_params)
+    }
+}
+
+/// NOTE: this does _not_ zeroize the parameters, in order to maintain some form of type
+/// consistency.
+#[cfg(feature = "zeroize")]
+impl<const MAX_LIMBS: usize> Zeroize for BoundedResidue<MAX_LIMBS> {
+    fn zeroize(&mut self) {
+        self.montgomery_form.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{BoundedResidue, BoundedResidueParams};
+    use crate::{BoundedUint, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn new_params_with_valid_modulus() {
+        let modulus = B::from(U128::from_be_hex("fffffffffffffffffffffffffffffeef"));
+        let params = BoundedResidueParams::new(modulus).unwrap();
+
+        assert_eq!(params.modulus(), &modulus);
+        assert_eq!(params.bits_precision(), 128);
+    }
+
+    #[test]
+    fn new_params_with_invalid_modulus() {
+        let modulus = B::from(U128::from_be_hex("fffffffffffffffffffffffffffffeee"));
+        assert!(bool::from(BoundedResidueParams::new(modulus).is_none()));
+        assert!(bool::from(
+            BoundedResidueParams::new(B::zero_with_precision(128)).is_none()
+        ));
+    }
+
+    #[test]
+    fn retrieve() {
+        let modulus = B::from(U128::from_be_hex("fffffffffffffffffffffffffffffeef"));
+        let params = BoundedResidueParams::new(modulus).unwrap();
+        let x = B::from(U128::from_be_hex("00112233445566778899aabbccddeeff"));
+
+        assert_eq!(BoundedResidue::new(x, params).retrieve(), x);
+        assert_eq!(BoundedResidue::one(params).retrieve(), B::one());
+        assert_eq!(BoundedResidue::zero(params).retrieve(), B::zero());
+    }
+}
@@ -0,0 +1,110 @@
+//! Additions between bounded residues.
+
+use super::BoundedResidue;
+use core::ops::{Add, AddAssign};
+
+impl<const MAX_LIMBS: usize> BoundedResidue<MAX_LIMBS> {
+    /// Adds `rhs`.
+    pub fn add(&self, rhs: &Self) -> Self {
+        debug_assert_eq!(self.residue_params, rhs.residue_params);
+
+        Self {
+            montgomery_form: self
+                .montgomery_form
+                .add_mod(&rhs.montgomery_form, &self.residue_params.modulus),
+            residue_params: self.residue_params,
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize> Add<&BoundedResidue<MAX_LIMBS>> for &BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    fn add(self, rhs: &BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        BoundedResidue::add(self, rhs)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Add<BoundedResidue<MAX_LIMBS>> for &BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn add(self, rhs: BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        self + &rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> Add<&BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn add(self, rhs: &BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        &self + rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> Add<BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    fn add(self, rhs: BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        &self + &rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> AddAssign<&BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    fn add_assign(&mut self, rhs: &BoundedResidue<MAX_LIMBS>) {
+        *self = BoundedResidue::add(self, rhs);
+    }
+}
+
+impl<const MAX_LIMBS: usize> AddAssign<BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    fn add_assign(&mut self, rhs: BoundedResidue<MAX_LIMBS>) {
+        *self += &rhs;
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{
+        modular::{BoundedResidue, BoundedResidueParams},
+        BoundedUint,
+    };
+    use hex_literal::hex;
+
+    type B = BoundedUint<{ crate::U256::LIMBS }>;
+
+    #[test]
+    fn add_overflow() {
+        let params = BoundedResidueParams::new(
+            B::from_be_slice(
+                &hex!("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"),
+                256,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+
+        let x = B::from_be_slice(
+            &hex!("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56"),
+            256,
+        )
+        .unwrap();
+        let mut x_mod = BoundedResidue::new(x, params);
+
+        let y = B::from_be_slice(
+            &hex!("d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251"),
+            256,
+        )
+        .unwrap();
+        let y_mod = BoundedResidue::new(y, params);
+
+        x_mod += &y_mod;
+
+        let expected = B::from_be_slice(
+            &hex!("1a2472fde50286541d97ca6a3592dd75beb9c9646e40c511b82496cfc3926956"),
+            256,
+        )
+        .unwrap();
+
+        assert_eq!(expected, x_mod.retrieve());
+        assert_eq!(x_mod - y_mod, BoundedResidue::new(x, params));
+        assert_eq!(-x_mod + x_mod, BoundedResidue::zero(params));
+    }
+}
@@ -0,0 +1,74 @@
+//! Multiplicative inverses of bounded residues.
+
+use super::{mul::mul_montgomery_form, BoundedResidue};
+use crate::traits::Invert;
+use subtle::CtOption;
+
+impl<const MAX_LIMBS: usize> BoundedResidue<MAX_LIMBS> {
+    /// Computes the residue `self^-1` representing the multiplicative inverse of `self`.
+    /// I.e. `self * self^-1 = 1`.
+    /// The result is none if the number was not invertible,
+    /// in which case the contained value is unspecified.
+    pub fn invert(&self) -> CtOption<Self> {
+        let params = &self.residue_params;
+
+        // The inverse of the Montgomery form `x * R` is `x^-1 * R^-1`, which is brought back to
+        // the Montgomery form `x^-1 * R` by a Montgomery multiplication by `R^3`.
+        let (inverse, is_some) = self.montgomery_form.inv_odd_mod_inner(&params.modulus);
+        let montgomery_form =
+            mul_montgomery_form(&inverse, &params.r3, &params.modulus, params.mod_neg_inv);
+
+        let value = Self {
+            montgomery_form,
+            residue_params: self.residue_params,
+        };
+
+        CtOption::new(value, is_some)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Invert for BoundedResidue<MAX_LIMBS> {
+    type Output = CtOption<Self>;
+    fn invert(&self) -> Self::Output {
+        self.invert()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::{BoundedResidue, BoundedResidueParams},
+        BoundedUint, U128,
+    };
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn invert() {
+        let modulus = B::from(U128::from_be_hex("ffffffffffffffffffffffffffffff61"));
+        let params = BoundedResidueParams::new(modulus).unwrap();
+        let x = BoundedResidue::new(
+            B::from(U128::from_be_hex("00112233445566778899aabbccddeeff")),
+            params,
+        );
+
+        let x_inv = x.invert().unwrap();
+        assert_eq!(x.mul(&x_inv).retrieve(), B::one_with_precision(128));
+        assert!(bool::from(BoundedResidue::zero(params).invert().is_none()));
+    }
+
+    #[test]
+    fn invert_not_invertible() {
+        // 3 * 5 * 7
+        let params = BoundedResidueParams::new(B::from(105u64)).unwrap();
+        let x = BoundedResidue::new(B::from(15u64), params);
+        assert!(bool::from(x.invert().is_none()));
+        assert_eq!(
+            BoundedResidue::new(B::from(2u64), params)
+                .invert()
+                .unwrap()
+                .retrieve(),
+            B::from(53u64)
+        );
+    }
+}
@@ -0,0 +1,128 @@
+//! Multiplication between bounded residues (i.e. Montgomery multiplication).
+
+use super::BoundedResidue;
+use crate::{modular::reduction::montgomery_reduction_bounded, traits::Square, BoundedUint, Limb};
+use core::ops::{Mul, MulAssign};
+
+impl<const MAX_LIMBS: usize> BoundedResidue<MAX_LIMBS> {
+    /// Multiplies by `rhs`.
+    pub fn mul(&self, rhs: &Self) -> Self {
+        debug_assert_eq!(self.residue_params, rhs.residue_params);
+
+        Self {
+            montgomery_form: mul_montgomery_form(
+                &self.montgomery_form,
+                &rhs.montgomery_form,
+                &self.residue_params.modulus,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Computes the (reduced) square of a residue.
+    pub fn square(&self) -> Self {
+        Self {
+            montgomery_form: square_montgomery_form(
+                &self.montgomery_form,
+                &self.residue_params.modulus,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize> Mul<&BoundedResidue<MAX_LIMBS>> for &BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    fn mul(self, rhs: &BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        BoundedResidue::mul(self, rhs)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Mul<BoundedResidue<MAX_LIMBS>> for &BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn mul(self, rhs: BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        self * &rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> Mul<&BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn mul(self, rhs: &BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        &self * rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> Mul<BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    fn mul(self, rhs: BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        &self * &rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> MulAssign<&BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    fn mul_assign(&mut self, rhs: &BoundedResidue<MAX_LIMBS>) {
+        *self = BoundedResidue::mul(self, rhs);
+    }
+}
+
+impl<const MAX_LIMBS: usize> MulAssign<BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    fn mul_assign(&mut self, rhs: BoundedResidue<MAX_LIMBS>) {
+        *self *= &rhs;
+    }
+}
+
+impl<const MAX_LIMBS: usize> Square for BoundedResidue<MAX_LIMBS> {
+    fn square(&self) -> Self {
+        BoundedResidue::square(self)
+    }
+}
+
+/// Computes the Montgomery product `a * b / R mod modulus`, with the double-width product held in
+/// two halves so that it never exceeds the precision of `MAX_LIMBS` limbs.
+pub(super) fn mul_montgomery_form<const MAX_LIMBS: usize>(
+    a: &BoundedUint<MAX_LIMBS>,
+    b: &BoundedUint<MAX_LIMBS>,
+    modulus: &BoundedUint<MAX_LIMBS>,
+    mod_neg_inv: Limb,
+) -> BoundedUint<MAX_LIMBS> {
+    montgomery_reduction_bounded(&a.mul_wide(b), modulus, mod_neg_inv)
+}
+
+/// Computes the Montgomery square `a * a / R mod modulus`.
+pub(super) fn square_montgomery_form<const MAX_LIMBS: usize>(
+    a: &BoundedUint<MAX_LIMBS>,
+    modulus: &BoundedUint<MAX_LIMBS>,
+    mod_neg_inv: Limb,
+) -> BoundedUint<MAX_LIMBS> {
+    montgomery_reduction_bounded(&a.square_wide(), modulus, mod_neg_inv)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::{BoundedResidue, BoundedResidueParams},
+        BoundedUint, U128,
+    };
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn mul_and_square() {
+        let modulus = U128::from_be_hex("fffffffffffffffffffffffffffffeef");
+        let x = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let y = U128::from_be_hex("0123456789abcdeffedcba9876543210");
+        let params = BoundedResidueParams::new(B::from(modulus)).unwrap();
+        let x_mod = BoundedResidue::new(B::from(x), params);
+        let y_mod = BoundedResidue::new(B::from(y), params);
+
+        let expected = x.mul_mod(&y, &modulus);
+        assert_eq!((x_mod * y_mod).retrieve(), B::from(expected));
+
+        let expected = x.mul_mod(&x, &modulus);
+        assert_eq!(x_mod.square().retrieve(), B::from(expected));
+    }
+}
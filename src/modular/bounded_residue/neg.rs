@@ -0,0 +1,25 @@
+//! Negations of bounded residues.
+
+use super::BoundedResidue;
+use core::ops::Neg;
+
+impl<const MAX_LIMBS: usize> BoundedResidue<MAX_LIMBS> {
+    /// Negates the number.
+    pub fn neg(&self) -> Self {
+        Self::zero(self.residue_params).sub(self)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Neg for BoundedResidue<MAX_LIMBS> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        BoundedResidue::neg(&self)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Neg for &BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    fn neg(self) -> BoundedResidue<MAX_LIMBS> {
+        BoundedResidue::neg(self)
+    }
+}
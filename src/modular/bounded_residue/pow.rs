@@ -0,0 +1,221 @@
+//! Modular exponentiation support for [`BoundedResidue`].
+
+use super::{
+    mul::{mul_montgomery_form, square_montgomery_form},
+    BoundedResidue,
+};
+use crate::{
+    modular::pow::{limbs_bit_vartime, sliding_window_vartime, VARTIME_POWERS},
+    BoundedUint, Limb, PowBoundedExp, Public, Word,
+};
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+impl<const MAX_LIMBS: usize> BoundedResidue<MAX_LIMBS> {
+    /// Raises to the `exponent` power.
+    pub fn pow<const RHS_LIMBS: usize>(&self, exponent: &BoundedUint<RHS_LIMBS>) -> Self {
+        self.pow_bounded_exp(exponent, exponent.bits_precision())
+    }
+
+    /// Raises to the `exponent` power,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub fn pow_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &BoundedUint<RHS_LIMBS>,
+        exponent_bits: u32,
+    ) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form(
+                &self.montgomery_form,
+                exponent,
+                exponent_bits,
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Raises to the `exponent` power using a sliding window which skips the leading zeros of
+    /// the exponent.
+    ///
+    /// NOTE: this is variable-time with respect to `exponent`, which is therefore required to be
+    /// [`Public`]. Use [`BoundedResidue::pow`] for secret exponents.
+    pub fn pow_vartime<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Public<BoundedUint<RHS_LIMBS>>,
+    ) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form_vartime(
+                &self.montgomery_form,
+                exponent.as_inner(),
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize, const RHS_LIMBS: usize> PowBoundedExp<BoundedUint<RHS_LIMBS>>
+    for BoundedResidue<MAX_LIMBS>
+{
+    fn pow_bounded_exp(&self, exponent: &BoundedUint<RHS_LIMBS>, exponent_bits: u32) -> Self {
+        self.pow_bounded_exp(exponent, exponent_bits)
+    }
+}
+
+/// Window size of the constant-time exponentiation.
+const WINDOW: u32 = 4;
+const WINDOW_MASK: Word = (1 << WINDOW) - 1;
+
+/// Performs modular exponentiation using Montgomery's ladder.
+/// `exponent_bits` represents the number of bits to take into account for the exponent.
+///
+/// NOTE: this value is leaked in the time pattern.
+fn pow_montgomery_form<const MAX_LIMBS: usize, const RHS_LIMBS: usize>(
+    x: &BoundedUint<MAX_LIMBS>,
+    exponent: &BoundedUint<RHS_LIMBS>,
+    exponent_bits: u32,
+    modulus: &BoundedUint<MAX_LIMBS>,
+    r: &BoundedUint<MAX_LIMBS>,
+    mod_neg_inv: Limb,
+) -> BoundedUint<MAX_LIMBS> {
+    if exponent_bits == 0 {
+        return *r; // 1 in Montgomery form
+    }
+
+    // powers[i] contains x^i
+    let mut powers = [*r; 1 << WINDOW];
+    powers[1] = *x;
+    for i in 2..(1 << WINDOW) {
+        powers[i] = mul_montgomery_form(&powers[i - 1], x, modulus, mod_neg_inv);
+    }
+
+    let starting_limb = ((exponent_bits - 1) / Limb::BITS) as usize;
+    let starting_bit_in_limb = (exponent_bits - 1) % Limb::BITS;
+    let starting_window = starting_bit_in_limb / WINDOW;
+    let starting_window_mask = (1 << (starting_bit_in_limb % WINDOW + 1)) - 1;
+
+    let mut z = *r; // 1 in Montgomery form
+
+    for limb_num in (0..=starting_limb).rev() {
+        let w = exponent.as_limbs()[limb_num].0;
+
+        let mut window_num = if limb_num == starting_limb {
+            starting_window + 1
+        } else {
+            Limb::BITS / WINDOW
+        };
+
+        while window_num > 0 {
+            window_num -= 1;
+
+            let mut idx = (w >> (window_num * WINDOW)) & WINDOW_MASK;
+
+            if limb_num == starting_limb && window_num == starting_window {
+                idx &= starting_window_mask;
+            } else {
+                for _ in 1..=WINDOW {
+                    z = square_montgomery_form(&z, modulus, mod_neg_inv);
+                }
+            }
+
+            // Constant-time lookup in the array of powers
+            let mut power = powers[0];
+            for i in 1..(1 << WINDOW) {
+                power.conditional_assign(&powers[i as usize], i.ct_eq(&idx));
+            }
+
+            z = mul_montgomery_form(&z, &power, modulus, mod_neg_inv);
+        }
+    }
+
+    z
+}
+
+/// Performs modular exponentiation using a sliding window over the odd powers of `x`.
+///
+/// NOTE: this is variable-time with respect to the exponent.
+fn pow_montgomery_form_vartime<const MAX_LIMBS: usize, const RHS_LIMBS: usize>(
+    x: &BoundedUint<MAX_LIMBS>,
+    exponent: &BoundedUint<RHS_LIMBS>,
+    modulus: &BoundedUint<MAX_LIMBS>,
+    r: &BoundedUint<MAX_LIMBS>,
+    mod_neg_inv: Limb,
+) -> BoundedUint<MAX_LIMBS> {
+    // odd_powers[j] contains x^(2j + 1)
+    let x2 = square_montgomery_form(x, modulus, mod_neg_inv);
+    let mut odd_powers = [*x; VARTIME_POWERS];
+    for j in 1..VARTIME_POWERS {
+        odd_powers[j] = mul_montgomery_form(&odd_powers[j - 1], &x2, modulus, mod_neg_inv);
+    }
+
+    let exponent = exponent.as_limbs();
+    let mut z = *r; // 1 in Montgomery form
+    let mut z_is_one = true;
+    let mut bit = exponent.len() as u32 * Limb::BITS;
+
+    while bit > 0 {
+        if !limbs_bit_vartime(exponent, bit - 1) {
+            if !z_is_one {
+                z = square_montgomery_form(&z, modulus, mod_neg_inv);
+            }
+            bit -= 1;
+            continue;
+        }
+
+        let (low, value) = sliding_window_vartime(exponent, bit - 1);
+        if !z_is_one {
+            for _ in low..bit {
+                z = square_montgomery_form(&z, modulus, mod_neg_inv);
+            }
+        }
+
+        let power = &odd_powers[(value >> 1) as usize];
+        if z_is_one {
+            z = *power;
+            z_is_one = false;
+        } else {
+            z = mul_montgomery_form(&z, power, modulus, mod_neg_inv);
+        }
+        bit = low;
+    }
+
+    z
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        modular::{BoundedResidue, BoundedResidueParams},
+        BoundedUint, Public, U128,
+    };
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn pow_matches_pow_vartime() {
+        let modulus = U128::from_be_hex("fffffffffffffffffffffffffffffeef");
+        let x = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let params = BoundedResidueParams::new(B::from(modulus)).unwrap();
+        let x_mod = BoundedResidue::new(B::from(x), params);
+
+        let exponent = B::from(0x1234_5678_9abc_def0u64);
+        let mut expected = BoundedResidue::one(params);
+        for _ in 0..5 {
+            expected *= x_mod;
+        }
+        assert_eq!(x_mod.pow(&B::from(5u8)), expected);
+        assert_eq!(x_mod.pow_vartime(&Public::new(B::from(5u8))), expected);
+        assert_eq!(x_mod.pow(&B::zero()), BoundedResidue::one(params));
+        assert_eq!(
+            x_mod.pow(&exponent),
+            x_mod.pow_vartime(&Public::new(exponent))
+        );
+    }
+}
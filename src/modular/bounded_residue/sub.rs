@@ -0,0 +1,60 @@
+//! Subtractions between bounded residues.
+
+use super::BoundedResidue;
+use core::ops::{Sub, SubAssign};
+
+impl<const MAX_LIMBS: usize> BoundedResidue<MAX_LIMBS> {
+    /// Subtracts `rhs`.
+    pub fn sub(&self, rhs: &Self) -> Self {
+        debug_assert_eq!(self.residue_params, rhs.residue_params);
+
+        Self {
+            montgomery_form: self
+                .montgomery_form
+                .sub_mod(&rhs.montgomery_form, &self.residue_params.modulus),
+            residue_params: self.residue_params,
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize> Sub<&BoundedResidue<MAX_LIMBS>> for &BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    fn sub(self, rhs: &BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        BoundedResidue::sub(self, rhs)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Sub<BoundedResidue<MAX_LIMBS>> for &BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn sub(self, rhs: BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        self - &rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> Sub<&BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn sub(self, rhs: &BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        &self - rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> Sub<BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    type Output = BoundedResidue<MAX_LIMBS>;
+    fn sub(self, rhs: BoundedResidue<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+        &self - &rhs
+    }
+}
+
+impl<const MAX_LIMBS: usize> SubAssign<&BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    fn sub_assign(&mut self, rhs: &BoundedResidue<MAX_LIMBS>) {
+        *self = BoundedResidue::sub(self, rhs);
+    }
+}
+
+impl<const MAX_LIMBS: usize> SubAssign<BoundedResidue<MAX_LIMBS>> for BoundedResidue<MAX_LIMBS> {
+    fn sub_assign(&mut self, rhs: BoundedResidue<MAX_LIMBS>) {
+        *self -= &rhs;
+    }
+}
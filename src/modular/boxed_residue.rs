@@ -1,29 +1,48 @@
 //! Implements `BoxedResidue`s, supporting modular arithmetic with a modulus whose size and value
 //! is chosen at runtime.
 
+mod accumulator;
 mod add;
 mod inv;
 mod mul;
 mod neg;
 mod pow;
+mod scratch;
 mod sub;
 
+pub use self::{accumulator::BoxedResidueAccumulator, scratch::BoxedResidueScratch};
+
+pub(crate) use self::mul::mul_mod_into;
+
+use self::mul::conditional_sub_modulus;
+
 use super::{
-    reduction::{montgomery_reduction_boxed, montgomery_reduction_boxed_mut},
-    Retrieve,
+    div_by_2::div_by_2_boxed,
+    reduction::{
+        assert_uniform_bytes_len, montgomery_form_wide_boxed, montgomery_reduction_boxed,
+        montgomery_reduction_boxed_mut,
+    },
+    BoxedBernsteinYangInverter, DynResidue, DynResidueParams, Retrieve,
 };
-use crate::{BoxedUint, Limb, NonZero, Word};
-use subtle::CtOption;
+use crate::{
+    traits::{ct_lookup_with, ct_select_slice_with},
+    BoxedUint, ConstantTimeLookup, DecodeError, Limb, NonZero, Public, Uint, Word,
+};
+use alloc::boxed::Box;
+use subtle::{Choice, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess, CtOption};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(feature = "std")]
 use std::sync::Arc;
 
 #[cfg(feature = "zeroize")]
-use zeroize::Zeroize;
+use zeroize::{Zeroize, ZeroizeOnDrop};
 
 /// Parameters to efficiently go to/from the Montgomery form for an odd modulus whose size and value
 /// are both chosen at runtime.
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug)]
 pub struct BoxedResidueParams {
     /// The constant modulus
     modulus: BoxedUint,
@@ -36,8 +55,24 @@ pub struct BoxedResidueParams {
     /// The lowest limbs of -(MODULUS^-1) mod R
     /// We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
     mod_neg_inv: Limb,
+    /// Inverter for the modulus, adjusted by R^2 so that it inverts in the Montgomery form.
+    /// Created once here so that it is reused by all the inversions.
+    inverter: BoxedBernsteinYangInverter,
 }
 
+impl PartialEq for BoxedResidueParams {
+    fn eq(&self, other: &Self) -> bool {
+        // The inverter is fully determined by the modulus and R^2.
+        self.modulus == other.modulus
+            && self.r == other.r
+            && self.r2 == other.r2
+            && self.r3 == other.r3
+            && self.mod_neg_inv == other.mod_neg_inv
+    }
+}
+
+impl Eq for BoxedResidueParams {}
+
 impl BoxedResidueParams {
     /// Instantiates a new set of [`BoxedResidueParams`] representing the given `modulus`, which
     /// must be odd.
@@ -73,7 +108,7 @@ impl BoxedResidueParams {
     ///
     /// Returns `None` if the provided modulus is not odd.
     /// TODO(tarcieri): DRY out with `DynResidueParams::new`?
-    pub fn new_vartime(modulus: BoxedUint) -> Option<Self> {
+    pub(crate) fn new_vartime(modulus: BoxedUint) -> Option<Self> {
         if modulus.is_even().into() {
             return None;
         }
@@ -107,9 +142,10 @@ impl BoxedResidueParams {
 
         // Since we are calculating the inverse modulo (Word::MAX+1),
         // we can take the modulo right away and calculate the inverse of the first limb only.
-        let modulus_lo = BoxedUint::from(modulus.limbs.get(0).copied().unwrap_or_default());
+        let modulus_lo = BoxedUint::from(modulus.limbs.first().copied().unwrap_or_default());
         let mod_neg_inv = Limb(Word::MIN.wrapping_sub(modulus_lo.inv_mod2k(Word::BITS).limbs[0].0));
         let r3 = montgomery_reduction_boxed(&mut r2.square(), &modulus, mod_neg_inv);
+        let inverter = BoxedBernsteinYangInverter::new_unchecked(&modulus, &r2);
 
         let params = Self {
             modulus,
@@ -117,22 +153,157 @@ impl BoxedResidueParams {
             r2,
             r3,
             mod_neg_inv,
+            inverter,
         };
 
         CtOption::new(params, is_odd)
     }
 
+    /// Instantiates a new set of [`BoxedResidueParams`] from precomputed Montgomery constants,
+    /// e.g. the ones returned by [`BoxedResidueParams::r`], [`BoxedResidueParams::r2`],
+    /// [`BoxedResidueParams::r3`] and [`BoxedResidueParams::mod_neg_inv`], which avoids the
+    /// divisions performed by [`BoxedResidueParams::new`].
+    ///
+    /// The constants are checked for consistency with the modulus at the cost of three Montgomery
+    /// reductions. Returns a `CtOption` that is `None` if the provided modulus is not odd or
+    /// the constants don't match it.
+    ///
+    /// Panics if the precisions of the constants don't match the precision of the modulus.
+    pub fn from_precomputed(
+        modulus: BoxedUint,
+        r: BoxedUint,
+        r2: BoxedUint,
+        r3: BoxedUint,
+        mod_neg_inv: Limb,
+    ) -> CtOption<Self> {
+        let bits_precision = modulus.bits_precision();
+        assert!(
+            [&r, &r2, &r3]
+                .iter()
+                .all(|n| n.bits_precision() == bits_precision),
+            "precision mismatch"
+        );
+
+        // `r` and `1` are only reduced modulo `modulus` if the latter is larger than 1.
+        let one = BoxedUint::one_with_precision(bits_precision);
+        let r_reduced =
+            BoxedUint::conditional_select(&r, &r.wrapping_sub(&modulus), r.ct_eq(&modulus));
+        let one_reduced = BoxedUint::conditional_select(
+            &one,
+            &BoxedUint::zero_with_precision(bits_precision),
+            modulus.ct_eq(&one),
+        );
+        let modulus_lo = modulus.limbs.first().copied().unwrap_or_default();
+
+        let is_consistent = modulus.is_odd()
+            & modulus_lo.wrapping_mul(mod_neg_inv).ct_eq(&Limb::MAX)
+            & !r.ct_gt(&modulus)
+            & r2.ct_lt(&modulus)
+            & r3.ct_lt(&modulus)
+            // `r * R^-1 = 1` binds `r` to `R`, which the other checks then extend to `r2` and `r3`
+            & montgomery_reduction_boxed(
+                &mut r_reduced.widen(bits_precision * 2),
+                &modulus,
+                mod_neg_inv,
+            )
+            .ct_eq(&one_reduced)
+            & montgomery_reduction_boxed(&mut r2.widen(bits_precision * 2), &modulus, mod_neg_inv)
+                .ct_eq(&r_reduced)
+            & montgomery_reduction_boxed(&mut r2.square(), &modulus, mod_neg_inv).ct_eq(&r3);
+        let inverter = BoxedBernsteinYangInverter::new_unchecked(&modulus, &r2);
+
+        let params = Self {
+            modulus,
+            r,
+            r2,
+            r3,
+            mod_neg_inv,
+            inverter,
+        };
+
+        CtOption::new(params, is_consistent)
+    }
+
+    /// Decodes a set of [`BoxedResidueParams`] from the big endian encoding produced by
+    /// [`BoxedResidueParams::to_be_bytes`], checking the consistency of the precomputed constants
+    /// as in [`BoxedResidueParams::from_precomputed`].
+    ///
+    /// Returns `None` if the encoding is malformed or the constants don't match the modulus.
+    pub fn from_be_bytes(bytes: &[u8]) -> Option<Self> {
+        let nbytes = bytes.len().checked_sub(Limb::BYTES)? / 4;
+        if nbytes == 0 || nbytes % Limb::BYTES != 0 || bytes.len() != nbytes * 4 + Limb::BYTES {
+            return None;
+        }
+
+        let bits_precision = (nbytes * 8) as u32;
+        let mut chunks = bytes.chunks_exact(nbytes);
+        let mut next_uint = || BoxedUint::from_be_slice(chunks.next()?, bits_precision).ok();
+        let (modulus, r, r2, r3) = (next_uint()?, next_uint()?, next_uint()?, next_uint()?);
+        let mod_neg_inv = Limb::from_be_slice(&bytes[nbytes * 4..]);
+
+        Self::from_precomputed(modulus, r, r2, r3, mod_neg_inv).into()
+    }
+
+    /// Serializes the modulus along with its precomputed Montgomery constants as big endian,
+    /// i.e. `modulus || r || r2 || r3 || mod_neg_inv`, where the first four have the precision of
+    /// the modulus and the last one is a single limb.
+    pub fn to_be_bytes(&self) -> Box<[u8]> {
+        let nbytes = self.modulus.nlimbs() * Limb::BYTES;
+        let mut bytes = vec![0u8; nbytes * 4 + Limb::BYTES];
+
+        for (chunk, uint) in
+            bytes
+                .chunks_exact_mut(nbytes)
+                .zip([&self.modulus, &self.r, &self.r2, &self.r3])
+        {
+            chunk.copy_from_slice(&uint.to_be_bytes());
+        }
+        bytes[nbytes * 4..].copy_from_slice(&self.mod_neg_inv.0.to_be_bytes());
+
+        bytes.into_boxed_slice()
+    }
+
     /// Modulus value.
     pub fn modulus(&self) -> &BoxedUint {
         &self.modulus
     }
 
+    /// Returns the Montgomery constant `R mod modulus`.
+    pub fn r(&self) -> &BoxedUint {
+        &self.r
+    }
+
+    /// Returns `R^2 mod modulus`, used to move into Montgomery form.
+    pub fn r2(&self) -> &BoxedUint {
+        &self.r2
+    }
+
+    /// Returns `R^3 mod modulus`, used to compute multiplicative inverses.
+    pub fn r3(&self) -> &BoxedUint {
+        &self.r3
+    }
+
+    /// Returns the lowest limb of `-(modulus^-1) mod R`.
+    pub fn mod_neg_inv(&self) -> Limb {
+        self.mod_neg_inv
+    }
+
     /// Bits of precision in the modulus.
     pub fn bits_precision(&self) -> u32 {
         self.modulus.bits_precision()
     }
 }
 
+impl Public<BoxedResidueParams> {
+    /// Instantiates a new set of [`BoxedResidueParams`] representing the given public `modulus`,
+    /// which must be odd, in variable time.
+    ///
+    /// Returns `None` if the provided modulus is not odd.
+    pub fn new_vartime(modulus: Public<BoxedUint>) -> Option<Self> {
+        BoxedResidueParams::new_vartime(modulus.into_inner()).map(Self::new)
+    }
+}
+
 /// A residue represented using heap-allocated limbs.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct BoxedResidue {
@@ -167,10 +338,55 @@ impl BoxedResidue {
 
         Self {
             montgomery_form: integer,
-            residue_params: residue_params.into(),
+            residue_params: share_params(residue_params),
         }
     }
 
+    /// Instantiates a new [`BoxedResidue`] by reducing the integer encoded by the given big
+    /// endian bytes modulo the provided params, e.g. the output of `expand_message` in
+    /// RFC 9380's `hash_to_field`.
+    ///
+    /// The bytes can be at most twice as long as the precision of the modulus, and shorter
+    /// inputs are implicitly padded with leading zeros. This function is constant-time with
+    /// respect to the value of the bytes.
+    ///
+    /// Panics if there are too many bytes.
+    pub fn reduce_wide_bytes(bytes: &[u8], residue_params: BoxedResidueParams) -> Self {
+        let wide = BoxedUint::from_be_slice(bytes, residue_params.bits_precision() * 2)
+            .expect("bytes are longer than twice the modulus precision");
+
+        let montgomery_form = montgomery_form_wide_boxed(
+            &wide,
+            &residue_params.modulus,
+            &residue_params.r2,
+            &residue_params.r3,
+            residue_params.mod_neg_inv,
+        );
+
+        #[cfg(feature = "zeroize")]
+        {
+            let mut wide = wide;
+            wide.zeroize();
+        }
+
+        Self {
+            montgomery_form,
+            residue_params: share_params(residue_params),
+        }
+    }
+
+    /// Instantiates a new [`BoxedResidue`] from uniformly random big endian bytes, e.g. hash or
+    /// XOF output, such that the result is uniformly distributed modulo the provided params up
+    /// to a bias of at most `2^-128`.
+    ///
+    /// Panics if there are fewer than `ceil((modulus_bits + 128) / 8)` bytes (the length `L`
+    /// from RFC 9380 with `k = 128`), and otherwise behaves like
+    /// [`reduce_wide_bytes`][`BoxedResidue::reduce_wide_bytes`].
+    pub fn from_uniform_bytes(bytes: &[u8], residue_params: BoxedResidueParams) -> Self {
+        assert_uniform_bytes_len(bytes.len(), residue_params.modulus.bits());
+        Self::reduce_wide_bytes(bytes, residue_params)
+    }
+
     /// Bits of precision in the modulus.
     pub fn bits_precision(&self) -> u32 {
         self.residue_params.bits_precision()
@@ -192,11 +408,23 @@ impl BoxedResidue {
         ret
     }
 
+    /// Performs the modular division by 2, that is for given `x` returns `y`
+    /// such that `y * 2 = x mod p`. This means:
+    /// - if `x` is even, returns `x / 2`,
+    /// - if `x` is odd, returns `(x + p) / 2`
+    ///   (since the modulus `p` in Montgomery form is always odd, this divides entirely).
+    pub fn div_by_2(&self) -> Self {
+        Self {
+            montgomery_form: div_by_2_boxed(&self.montgomery_form, &self.residue_params.modulus),
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
     /// Instantiates a new `Residue` that represents zero.
     pub fn zero(residue_params: BoxedResidueParams) -> Self {
         Self {
             montgomery_form: BoxedUint::zero_with_precision(residue_params.bits_precision()),
-            residue_params: residue_params.into(),
+            residue_params: share_params(residue_params),
         }
     }
 
@@ -204,7 +432,7 @@ impl BoxedResidue {
     pub fn one(residue_params: BoxedResidueParams) -> Self {
         Self {
             montgomery_form: residue_params.r.clone(),
-            residue_params: residue_params.into(),
+            residue_params: share_params(residue_params),
         }
     }
 
@@ -223,7 +451,7 @@ impl BoxedResidue {
         debug_assert_eq!(integer.bits_precision(), residue_params.bits_precision());
         Self {
             montgomery_form: integer,
-            residue_params: residue_params.into(),
+            residue_params: share_params(residue_params),
         }
     }
 
@@ -233,6 +461,180 @@ impl BoxedResidue {
     }
 }
 
+/// Wraps the parameters in an `Arc` when `std` is available, as stored in [`BoxedResidue`].
+#[cfg(feature = "std")]
+fn share_params(residue_params: BoxedResidueParams) -> Arc<BoxedResidueParams> {
+    Arc::new(residue_params)
+}
+
+/// Returns the parameters as is, since `Arc` is only used when `std` is available.
+#[cfg(not(feature = "std"))]
+fn share_params(residue_params: BoxedResidueParams) -> BoxedResidueParams {
+    residue_params
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for BoxedResidueParams {
+    fn zeroize(&mut self) {
+        self.modulus.zeroize();
+        self.r.zeroize();
+        self.r2.zeroize();
+        self.r3.zeroize();
+        self.mod_neg_inv.zeroize();
+        self.inverter.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BoxedResidueParams {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for BoxedResidueParams {}
+
+/// NOTE: this does _not_ zeroize the parameters, which may be shared with other residues.
+/// They are zeroized when dropped instead.
+#[cfg(feature = "zeroize")]
+impl Zeroize for BoxedResidue {
+    fn zeroize(&mut self) {
+        self.montgomery_form.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BoxedResidue {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl ZeroizeOnDrop for BoxedResidue {}
+
+impl<const LIMBS: usize> From<&DynResidueParams<LIMBS>> for BoxedResidueParams {
+    fn from(params: &DynResidueParams<LIMBS>) -> Self {
+        let modulus = params.modulus.into();
+        let r2 = params.r2.into();
+        let inverter = BoxedBernsteinYangInverter::new_unchecked(&modulus, &r2);
+
+        Self {
+            modulus,
+            r: params.r.into(),
+            r2,
+            r3: params.r3.into(),
+            mod_neg_inv: params.mod_neg_inv,
+            inverter,
+        }
+    }
+}
+
+impl<const LIMBS: usize> From<DynResidueParams<LIMBS>> for BoxedResidueParams {
+    fn from(params: DynResidueParams<LIMBS>) -> Self {
+        Self::from(&params)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BoxedResidueParams> for DynResidueParams<LIMBS> {
+    type Error = DecodeError;
+
+    /// Converts [`BoxedResidueParams`] into [`DynResidueParams`], which requires the precision of
+    /// the modulus to be exactly [`Uint::BITS`] so that both use the same Montgomery `R`.
+    ///
+    /// Returns [`DecodeError::Precision`] otherwise.
+    fn try_from(params: &BoxedResidueParams) -> Result<Self, DecodeError> {
+        if params.bits_precision() != Uint::<LIMBS>::BITS {
+            return Err(DecodeError::Precision);
+        }
+
+        Ok(Self {
+            modulus: (&params.modulus).try_into()?,
+            r: (&params.r).try_into()?,
+            r2: (&params.r2).try_into()?,
+            r3: (&params.r3).try_into()?,
+            mod_neg_inv: params.mod_neg_inv,
+        })
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BoxedResidueParams> for DynResidueParams<LIMBS> {
+    type Error = DecodeError;
+
+    fn try_from(params: BoxedResidueParams) -> Result<Self, DecodeError> {
+        Self::try_from(&params)
+    }
+}
+
+impl<const LIMBS: usize> From<&DynResidue<LIMBS>> for BoxedResidue {
+    /// Converts a [`DynResidue`] into a [`BoxedResidue`], keeping it in Montgomery form.
+    fn from(residue: &DynResidue<LIMBS>) -> Self {
+        Self {
+            montgomery_form: residue.montgomery_form.into(),
+            residue_params: share_params(BoxedResidueParams::from(&residue.residue_params)),
+        }
+    }
+}
+
+impl<const LIMBS: usize> From<DynResidue<LIMBS>> for BoxedResidue {
+    fn from(residue: DynResidue<LIMBS>) -> Self {
+        Self::from(&residue)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BoxedResidue> for DynResidue<LIMBS> {
+    type Error = DecodeError;
+
+    /// Converts a [`BoxedResidue`] into a [`DynResidue`], keeping it in Montgomery form.
+    ///
+    /// Returns [`DecodeError::Precision`] if the precision of the modulus isn't exactly
+    /// [`Uint::BITS`].
+    fn try_from(residue: &BoxedResidue) -> Result<Self, DecodeError> {
+        let residue_params = DynResidueParams::try_from(residue.params())?;
+
+        // `DynResidue` relies on the Montgomery form being fully reduced
+        let mut montgomery_form = residue.montgomery_form.clone();
+        conditional_sub_modulus(&mut montgomery_form, &residue.residue_params.modulus);
+
+        Ok(Self {
+            montgomery_form: (&montgomery_form).try_into()?,
+            residue_params,
+        })
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BoxedResidue> for DynResidue<LIMBS> {
+    type Error = DecodeError;
+
+    fn try_from(residue: BoxedResidue) -> Result<Self, DecodeError> {
+        Self::try_from(&residue)
+    }
+}
+
+/// Serializes the [`BoxedResidueParams::to_be_bytes`] encoding.
+#[cfg(feature = "serde")]
+impl Serialize for BoxedResidueParams {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serdect::slice::serialize_hex_lower_or_bin(&self.to_be_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for BoxedResidueParams {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let bytes = serdect::slice::deserialize_hex_or_bin_vec(deserializer)?;
+        Self::from_be_bytes(&bytes)
+            .ok_or_else(|| D::Error::custom("invalid or inconsistent residue parameters"))
+    }
+}
+
 impl Retrieve for BoxedResidue {
     type Output = BoxedUint;
     fn retrieve(&self) -> BoxedUint {
@@ -240,9 +642,36 @@ impl Retrieve for BoxedResidue {
     }
 }
 
+impl ConstantTimeLookup for BoxedResidue {
+    /// All of the elements of `table` must have the same residue parameters.
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_with(table, index, |ret, item, choice| {
+            ret.montgomery_form
+                .conditional_assign(&item.montgomery_form, choice)
+        })
+    }
+
+    /// All of the elements of `a` and `b` must have the same residue parameters.
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_with(out, a, b, choice, |a, b, choice| Self {
+            montgomery_form: BoxedUint::conditional_select(
+                &a.montgomery_form,
+                &b.montgomery_form,
+                choice,
+            ),
+            residue_params: a.residue_params.clone(),
+        })
+    }
+}
+
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
-    use super::{BoxedResidueParams, BoxedUint};
+    use super::{BoxedResidue, BoxedResidueParams, BoxedUint};
+    use crate::{
+        modular::{DynResidue, DynResidueParams},
+        ConstantTimeLookup, DecodeError, Public, U128, U256, U64,
+    };
 
     #[test]
     fn new_params_with_invalid_modulus() {
@@ -259,4 +688,163 @@ mod tests {
     fn new_params_with_valid_modulus() {
         BoxedResidueParams::new(BoxedUint::from(3u8)).unwrap();
     }
+
+    #[test]
+    fn new_public_params() {
+        let params = Public::<BoxedResidueParams>::new_vartime(Public::new(BoxedUint::from(11u8)));
+        assert_eq!(
+            params.unwrap().into_inner(),
+            BoxedResidueParams::new(BoxedUint::from(11u8)).unwrap()
+        );
+        assert!(
+            Public::<BoxedResidueParams>::new_vartime(Public::new(BoxedUint::from(10u8))).is_none()
+        );
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn zeroize() {
+        use zeroize::Zeroize;
+
+        let params = BoxedResidueParams::new(BoxedUint::from(11u8)).unwrap();
+        let mut x = BoxedResidue::new(BoxedUint::from(2u8), params.clone());
+        x.zeroize();
+        assert!(bool::from(x.retrieve().is_zero()));
+        assert_eq!(x.params(), &params);
+
+        let mut params = params;
+        params.zeroize();
+        assert!(bool::from(params.modulus().is_zero()));
+    }
+
+    #[test]
+    fn ct_lookup() {
+        let params = BoxedResidueParams::new(BoxedUint::from(11u8)).unwrap();
+        let table = [
+            BoxedResidue::new(BoxedUint::from(2u8), params.clone()),
+            BoxedResidue::new(BoxedUint::from(3u8), params.clone()),
+            BoxedResidue::new(BoxedUint::from(5u8), params),
+        ];
+
+        for (i, expected) in table.iter().enumerate() {
+            assert_eq!(&BoxedResidue::ct_lookup(&table, i).unwrap(), expected);
+        }
+
+        assert!(bool::from(BoxedResidue::ct_lookup(&table, 3).is_none()));
+    }
+
+    #[test]
+    fn from_precomputed() {
+        for modulus in [
+            BoxedUint::one_with_precision(128),
+            BoxedUint::from(3u8),
+            BoxedUint::max(320),
+        ] {
+            let params = BoxedResidueParams::new(modulus).unwrap();
+            let precomputed = BoxedResidueParams::from_precomputed(
+                params.modulus().clone(),
+                params.r().clone(),
+                params.r2().clone(),
+                params.r3().clone(),
+                params.mod_neg_inv(),
+            )
+            .unwrap();
+            assert_eq!(precomputed, params);
+
+            let bytes = params.to_be_bytes();
+            assert_eq!(
+                BoxedResidueParams::from_be_bytes(&bytes),
+                Some(params.clone())
+            );
+            assert_eq!(BoxedResidueParams::from_be_bytes(&bytes[1..]), None);
+
+            let mut corrupted = bytes.clone();
+            corrupted[bytes.len() / 2] ^= 1;
+            assert_eq!(BoxedResidueParams::from_be_bytes(&corrupted), None);
+        }
+    }
+
+    #[test]
+    fn from_precomputed_scaled() {
+        // `(x, x * R, x^2 * R)` satisfies the relations between `r`, `r2` and `r3` for any `x`
+        let params = BoxedResidueParams::new(BoxedUint::from(11u8)).unwrap();
+        let two = BoxedUint::from(2u8);
+        let r2 = BoxedResidue::new(two.clone(), params.clone()).to_montgomery();
+        let r3 = BoxedResidue::new(BoxedUint::from(4u8), params.clone()).to_montgomery();
+
+        assert!(bool::from(
+            BoxedResidueParams::from_precomputed(
+                params.modulus().clone(),
+                two,
+                r2,
+                r3,
+                params.mod_neg_inv(),
+            )
+            .is_none()
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn serde() {
+        let params = BoxedResidueParams::new(BoxedUint::max(256)).unwrap();
+        let serialized = bincode::serialize(&params).unwrap();
+        let deserialized: BoxedResidueParams = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, params);
+    }
+
+    #[test]
+    fn dyn_residue_conversions() {
+        let modulus =
+            U256::from_be_hex("ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let dyn_params = DynResidueParams::new(&modulus).unwrap();
+        let dyn_residue = DynResidue::new(&x, dyn_params);
+
+        let boxed_params = BoxedResidueParams::from(dyn_params);
+        assert_eq!(
+            boxed_params,
+            BoxedResidueParams::new(modulus.into()).unwrap()
+        );
+        assert_eq!(DynResidueParams::try_from(&boxed_params), Ok(dyn_params));
+        assert_eq!(
+            DynResidueParams::<{ U128::LIMBS }>::try_from(&boxed_params),
+            Err(DecodeError::Precision)
+        );
+
+        let boxed_residue = BoxedResidue::from(dyn_residue);
+        assert_eq!(
+            boxed_residue,
+            BoxedResidue::new(x.into(), boxed_params.clone())
+        );
+        assert_eq!(boxed_residue.retrieve(), BoxedUint::from(x));
+        assert_eq!(DynResidue::try_from(&boxed_residue), Ok(dyn_residue));
+
+        let product = &boxed_residue * &boxed_residue;
+        assert_eq!(
+            DynResidue::try_from(&product),
+            Ok(dyn_residue * dyn_residue)
+        );
+    }
+
+    #[test]
+    fn dyn_residue_conversion_of_unreduced() {
+        let boxed_params = BoxedResidueParams::new(BoxedUint::from(9u64)).unwrap();
+        let dyn_params = DynResidueParams::new(&U64::from(9u64)).unwrap();
+
+        // The Montgomery form of zero as left by an "Almost Montgomery Multiplication"
+        let zero = BoxedResidue::from_montgomery(BoxedUint::from(9u64), boxed_params.clone());
+        assert_eq!(
+            DynResidue::try_from(&zero),
+            Ok(DynResidue::zero(dyn_params))
+        );
+
+        let three = BoxedResidue::new(BoxedUint::from(3u64), boxed_params);
+        assert_eq!(
+            DynResidue::try_from(&(&three * &three)),
+            Ok(DynResidue::zero(dyn_params))
+        );
+    }
 }
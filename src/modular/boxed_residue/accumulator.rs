@@ -0,0 +1,170 @@
+//! Lazily reduced sums of products of boxed residues.
+
+use super::{mul::conditional_sub_modulus, share_params, BoxedResidue, BoxedResidueParams};
+use crate::{modular::reduction::montgomery_reduction_boxed, BoxedUint, Limb};
+use subtle::Choice;
+
+#[cfg(feature = "std")]
+use std::sync::Arc;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// An unreduced, double-width sum of products of [`BoxedResidue`]s.
+///
+/// Each product is added without being reduced, and a single Montgomery reduction is performed by
+/// [`BoxedResidueAccumulator::reduce`], which makes computing sums of products such as inner
+/// products cheaper than adding up the results of [`BoxedResidue::mul`]. Adding products doesn't
+/// allocate.
+#[derive(Clone, Debug)]
+pub struct BoxedResidueAccumulator {
+    /// Lower half of the sum.
+    lower: BoxedUint,
+    /// Upper half of the sum, kept below the modulus.
+    upper: BoxedUint,
+    /// Double-width buffer for the product being added.
+    product: BoxedUint,
+    /// Buffer for either half of the product being added, or for its first factor.
+    half: BoxedUint,
+    /// Buffer for the second factor of the product being added.
+    factor: BoxedUint,
+
+    /// Residue parameters.
+    #[cfg(not(feature = "std"))]
+    residue_params: BoxedResidueParams,
+
+    /// Residue parameters.
+    // Uses `Arc` when `std` is available.
+    #[cfg(feature = "std")]
+    residue_params: Arc<BoxedResidueParams>,
+}
+
+impl BoxedResidueAccumulator {
+    /// Instantiates an empty sum of products of residues with the given parameters.
+    pub fn new(residue_params: BoxedResidueParams) -> Self {
+        let bits_precision = residue_params.bits_precision();
+
+        Self {
+            lower: BoxedUint::zero_with_precision(bits_precision),
+            upper: BoxedUint::zero_with_precision(bits_precision),
+            product: BoxedUint::zero_with_precision(bits_precision * 2),
+            half: BoxedUint::zero_with_precision(bits_precision),
+            factor: BoxedUint::zero_with_precision(bits_precision),
+            residue_params: share_params(residue_params),
+        }
+    }
+
+    /// Adds the product `a * b` to the sum.
+    ///
+    /// Both residues must have the same parameters as the accumulator.
+    pub fn mul_add_assign(&mut self, a: &BoxedResidue, b: &BoxedResidue) {
+        debug_assert_eq!(a.residue_params, self.residue_params);
+        debug_assert_eq!(b.residue_params, self.residue_params);
+
+        let modulus = &self.residue_params.modulus;
+        let nlimbs = modulus.nlimbs();
+
+        // Make sure both factors are below the modulus, so that the upper half of the product is.
+        self.half.limbs.copy_from_slice(&a.montgomery_form.limbs);
+        conditional_sub_modulus(&mut self.half, modulus);
+        self.factor.limbs.copy_from_slice(&b.montgomery_form.limbs);
+        conditional_sub_modulus(&mut self.factor, modulus);
+        self.half.mul_into(&self.factor, &mut self.product);
+
+        self.half
+            .limbs
+            .copy_from_slice(&self.product.limbs[..nlimbs]);
+        let carry = self.lower.adc_assign(&self.half, Limb::ZERO);
+
+        // Add the upper half of the product and the carry to the upper half of the sum modulo the
+        // modulus, i.e. subtract `modulus * R` from the sum if necessary. Both are below the
+        // modulus, so a single conditional subtraction is enough.
+        self.half
+            .limbs
+            .copy_from_slice(&self.product.limbs[nlimbs..]);
+        let carry = self.upper.adc_assign(&self.half, carry);
+        let borrow = self.upper.sbb_assign(modulus, Limb::ZERO);
+        let (_, mask) = carry.sbb(Limb::ZERO, borrow);
+        self.upper
+            .conditional_adc_assign(modulus, Choice::from((mask.0 & 1) as u8));
+    }
+
+    /// Reduces the sum to a [`BoxedResidue`].
+    pub fn reduce(&self) -> BoxedResidue {
+        let nlimbs = self.lower.nlimbs();
+        let mut wide = self.lower.widen(self.lower.bits_precision() * 2);
+        wide.limbs[nlimbs..].copy_from_slice(&self.upper.limbs);
+
+        let montgomery_form = montgomery_reduction_boxed(
+            &mut wide,
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        );
+
+        #[cfg(feature = "zeroize")]
+        wide.zeroize();
+
+        BoxedResidue {
+            montgomery_form,
+            residue_params: self.residue_params.clone(),
+        }
+    }
+}
+
+impl BoxedResidue {
+    /// Computes the sum of products `a_1 * b_1 + ... + a_n * b_n` with a single Montgomery
+    /// reduction, see [`BoxedResidueAccumulator`].
+    ///
+    /// All the residues must share the same parameters.
+    ///
+    /// # Panics
+    ///
+    /// If `pairs` is empty.
+    pub fn sum_of_products(pairs: &[(Self, Self)]) -> Self {
+        assert!(!pairs.is_empty(), "pairs must not be empty");
+
+        let mut acc = BoxedResidueAccumulator::new(pairs[0].0.params().clone());
+        for (a, b) in pairs {
+            acc.mul_add_assign(a, b);
+        }
+
+        acc.reduce()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BoxedResidueAccumulator {
+    fn drop(&mut self) {
+        self.lower.zeroize();
+        self.upper.zeroize();
+        self.product.zeroize();
+        self.half.zeroize();
+        self.factor.zeroize();
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{
+        modular::{BoxedResidue, BoxedResidueParams},
+        BoxedUint,
+    };
+
+    #[test]
+    fn sum_of_unreduced_products() {
+        let modulus = 0x8000_0000_0000_001du64;
+        let params = BoxedResidueParams::new(BoxedUint::from(modulus)).unwrap();
+
+        // A Montgomery form above the modulus, as left by an "Almost Montgomery Multiplication"
+        let x = BoxedResidue::from_montgomery(BoxedUint::from(u64::MAX), params);
+        let pairs = vec![(x.clone(), x.clone()); 4];
+
+        let x_retrieved = u128::from(x.retrieve().as_words()[0]);
+        let expected = 4 * (x_retrieved * x_retrieved % u128::from(modulus)) % u128::from(modulus);
+        assert_eq!(
+            BoxedResidue::sum_of_products(&pairs).retrieve(),
+            BoxedUint::from(expected as u64)
+        );
+    }
+}
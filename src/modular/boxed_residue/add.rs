@@ -15,6 +15,21 @@ impl BoxedResidue {
             residue_params: self.residue_params.clone(),
         }
     }
+
+    /// Computes `2 * self`.
+    pub fn double(&self) -> Self {
+        Self {
+            montgomery_form: self
+                .montgomery_form
+                .add_mod(&self.montgomery_form, &self.residue_params.modulus),
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
+    /// Computes `3 * self`.
+    pub fn triple(&self) -> Self {
+        Self::add(&self.double(), self)
+    }
 }
 
 impl Add<&BoxedResidue> for &BoxedResidue {
@@ -1,32 +1,37 @@
 //! Multiplicative inverses of boxed residue.
 
-use super::BoxedResidue;
-use crate::{modular::reduction::montgomery_reduction_boxed_mut, traits::Invert};
-use subtle::CtOption;
+use super::{mul::MontgomeryMultiplier, BoxedResidue, BoxedResidueParams};
+use crate::{
+    modular::inv::batch_invert_in_place_with,
+    traits::{BatchInvert, Invert},
+    BoxedUint,
+};
+use alloc::{vec, vec::Vec};
+use core::borrow::Borrow;
+use subtle::{Choice, CtOption};
 
 impl BoxedResidue {
     /// Computes the residue `self^-1` representing the multiplicative inverse of `self`.
     /// I.e. `self * self^-1 = 1`.
     pub fn invert(&self) -> CtOption<Self> {
-        let (mut inverse, is_some) = self
-            .montgomery_form
-            .inv_odd_mod(&self.residue_params.modulus);
-
-        let mut product = inverse.mul(&self.residue_params.r3);
+        let (value, is_some) = self.invert_inner();
+        CtOption::new(value, is_some)
+    }
 
-        montgomery_reduction_boxed_mut(
-            &mut product,
-            &self.residue_params.modulus,
-            self.residue_params.mod_neg_inv,
-            &mut inverse,
-        );
+    /// Computes the inverse, returning an unspecified value along with a falsy [`Choice`] if
+    /// `self` is not invertible.
+    fn invert_inner(&self) -> (Self, Choice) {
+        // The value is in the Montgomery form `x * R`, and adjusting its inverse by `R^2` gives
+        // `x^-1 * R`, which is the Montgomery form of the result.
+        let params = &self.residue_params;
+        let (inverse, is_some) = params.inverter.invert_inner(&self.montgomery_form);
 
         let value = Self {
             montgomery_form: inverse,
-            residue_params: self.residue_params.clone(),
+            residue_params: params.clone(),
         };
 
-        CtOption::new(value, is_some)
+        (value, is_some)
     }
 }
 
@@ -36,3 +41,82 @@ impl Invert for BoxedResidue {
         self.invert()
     }
 }
+
+impl BatchInvert<[Self]> for BoxedResidue {
+    type Output = CtOption<Vec<Self>>;
+
+    /// All of the `elements` must have the same residue parameters.
+    fn batch_invert(elements: &[Self]) -> Self::Output {
+        let first = match elements.first() {
+            Some(first) => first,
+            None => return CtOption::new(Vec::new(), Choice::from(1)),
+        };
+
+        // Work on the Montgomery forms directly, sharing the parameters and the multiplier's
+        // buffer between all the multiplications.
+        let params: &BoxedResidueParams = first.residue_params.borrow();
+        let one = &params.r;
+        let zero = BoxedUint::zero_with_precision(one.bits_precision());
+        let mut multiplier = MontgomeryMultiplier::from(params);
+
+        let mut inverses: Vec<BoxedUint> =
+            elements.iter().map(|x| x.montgomery_form.clone()).collect();
+        let mut scratch = vec![one.clone(); elements.len()];
+        let is_some = batch_invert_in_place_with(
+            &mut inverses,
+            &mut scratch,
+            &zero,
+            one,
+            BoxedUint::conditional_select,
+            |a, b| multiplier.mul(a, b),
+            |x| params.inverter.invert_inner(x),
+        );
+
+        let inverses = inverses
+            .into_iter()
+            .map(|montgomery_form| Self {
+                montgomery_form,
+                residue_params: first.residue_params.clone(),
+            })
+            .collect();
+
+        CtOption::new(inverses, is_some)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::BoxedResidue;
+    use crate::{modular::BoxedResidueParams, BatchInvert, BoxedUint};
+    use hex_literal::hex;
+
+    #[test]
+    fn batch_invert() {
+        let params = BoxedResidueParams::new(
+            BoxedUint::from_be_slice(
+                &hex!("15477BCCEFE197328255BFA79A1217899016D927EF460F4FF404029D24FA4409"),
+                256,
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let x = BoxedResidue::new(
+            BoxedUint::from_be_slice(
+                &hex!("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+                256,
+            )
+            .unwrap(),
+            params.clone(),
+        );
+        let zero = BoxedResidue::zero(params);
+
+        let elements = [x.clone(), zero.clone(), x.square()];
+        let inverses = BoxedResidue::batch_invert(&elements[..]).unwrap();
+
+        assert_eq!(inverses[0], x.invert().unwrap());
+        assert_eq!(inverses[1], zero);
+        assert_eq!(inverses[2], x.square().invert().unwrap());
+        assert!(bool::from(BoxedResidue::batch_invert(&[][..]).is_some()));
+    }
+}
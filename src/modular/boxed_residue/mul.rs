@@ -5,12 +5,16 @@
 //!
 //! Originally (c) 2014 The Rust Project Developers, dual licensed Apache 2.0+MIT.
 
-use super::{BoxedResidue, BoxedResidueParams};
-use crate::{traits::Square, BoxedUint, Limb, WideWord, Word};
+use super::{BoxedResidue, BoxedResidueParams, BoxedResidueScratch};
+use crate::{
+    modular::reduction::montgomery_reduction_boxed_mut, traits::Square, BoxedUint, Limb, WideWord,
+    Word,
+};
 use core::{
     borrow::Borrow,
     ops::{Mul, MulAssign},
 };
+use subtle::Choice;
 
 #[cfg(feature = "zeroize")]
 use zeroize::Zeroize;
@@ -39,6 +43,97 @@ impl BoxedResidue {
             residue_params: self.residue_params.clone(),
         }
     }
+
+    /// Multiplies by the small constant `k`, which only takes modular additions and is
+    /// cheaper than a full Montgomery multiplication for the constants found in formulas.
+    ///
+    /// NOTE: this is variable-time with respect to `k`.
+    pub fn mul_by_small(&self, k: u32) -> Self {
+        let modulus = &self.residue_params.modulus;
+        let mut montgomery_form = BoxedUint::zero_with_precision(modulus.bits_precision());
+
+        // Double-and-add over the bits of `k`, which works in Montgomery form as it is linear
+        for i in (0..(u32::BITS - k.leading_zeros())).rev() {
+            montgomery_form = montgomery_form.add_mod(&montgomery_form, modulus);
+            if (k >> i) & 1 == 1 {
+                montgomery_form = montgomery_form.add_mod(&self.montgomery_form, modulus);
+            }
+        }
+
+        Self {
+            montgomery_form,
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
+    /// Multiplies by `rhs` in place, using the given scratch space instead of allocating.
+    ///
+    /// Panics if the precision of `scratch` doesn't match the precision of `self`.
+    pub fn mul_assign_with_scratch(&mut self, rhs: &Self, scratch: &mut BoxedResidueScratch) {
+        debug_assert_eq!(&self.residue_params, &rhs.residue_params);
+        assert_eq!(
+            scratch.bits_precision(),
+            self.residue_params.bits_precision(),
+            "scratch precision mismatch"
+        );
+
+        montgomery_mul_assign(
+            &mut scratch.product,
+            &mut self.montgomery_form,
+            &rhs.montgomery_form,
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        );
+    }
+
+    /// Squares in place, using the given scratch space instead of allocating.
+    ///
+    /// Panics if the precision of `scratch` doesn't match the precision of `self`.
+    pub fn square_assign_with_scratch(&mut self, scratch: &mut BoxedResidueScratch) {
+        assert_eq!(
+            scratch.bits_precision(),
+            self.residue_params.bits_precision(),
+            "scratch precision mismatch"
+        );
+
+        montgomery_square_assign(
+            &mut scratch.product,
+            &mut self.montgomery_form,
+            &self.residue_params.modulus,
+            self.residue_params.mod_neg_inv,
+        );
+    }
+}
+
+/// Computes `a * b mod p` for the modulus `p` of `residue_params`, writing the result to `out`.
+///
+/// This takes two exact Montgomery multiplications: `a * R^2 / R = a * R` brings `a` into
+/// Montgomery form, after which `(a * R) * b / R = a * b`. Both products are less than
+/// `p * R`, so the reductions produce fully reduced results even for unreduced inputs.
+pub(crate) fn mul_mod_into(
+    a: &BoxedUint,
+    b: &BoxedUint,
+    residue_params: &BoxedResidueParams,
+    scratch: &mut BoxedResidueScratch,
+    out: &mut BoxedUint,
+) {
+    let bits_precision = residue_params.bits_precision();
+    assert_eq!(a.bits_precision(), bits_precision, "precision mismatch");
+    assert_eq!(b.bits_precision(), bits_precision, "precision mismatch");
+    assert_eq!(out.bits_precision(), bits_precision, "precision mismatch");
+    assert_eq!(
+        scratch.bits_precision(),
+        bits_precision,
+        "scratch precision mismatch"
+    );
+
+    let modulus = &residue_params.modulus;
+    let mod_neg_inv = residue_params.mod_neg_inv;
+
+    a.mul_into(&residue_params.r2, &mut scratch.product);
+    montgomery_reduction_boxed_mut(&mut scratch.product, modulus, mod_neg_inv, out);
+    out.mul_into(b, &mut scratch.product);
+    montgomery_reduction_boxed_mut(&mut scratch.product, modulus, mod_neg_inv, out);
 }
 
 impl Mul<&BoxedResidue> for &BoxedResidue {
@@ -114,59 +209,28 @@ impl<'a> MontgomeryMultiplier<'a> {
         }
     }
 
-    /// Perform an "Almost Montgomery Multiplication".
+    /// Perform a Montgomery multiplication.
     pub(super) fn mul(&mut self, a: &BoxedUint, b: &BoxedUint) -> BoxedUint {
         let mut ret = a.clone();
         self.mul_assign(&mut ret, b);
         ret
     }
 
-    /// Perform an "Almost Montgomery Multiplication", assigning the product to `a`.
+    /// Perform a Montgomery multiplication, assigning the product to `a`.
     pub(super) fn mul_assign(&mut self, a: &mut BoxedUint, b: &BoxedUint) {
-        debug_assert_eq!(a.bits_precision(), self.modulus.bits_precision());
-        debug_assert_eq!(b.bits_precision(), self.modulus.bits_precision());
-
-        self.clear_product();
-        montgomery_mul(
-            self.product.as_words_mut(),
-            a.as_words(),
-            b.as_words(),
-            self.modulus.as_words(),
-            self.mod_neg_inv.into(),
-        );
-        a.limbs
-            .copy_from_slice(&self.product.limbs[..a.limbs.len()]);
+        montgomery_mul_assign(&mut self.product, a, b, self.modulus, self.mod_neg_inv);
     }
 
-    /// Perform a squaring "Almost Montgomery Multiplication".
+    /// Perform a Montgomery squaring.
     pub(super) fn square(&mut self, a: &BoxedUint) -> BoxedUint {
         let mut ret = a.clone();
         self.square_assign(&mut ret);
         ret
     }
 
-    /// Perform a squaring using "Almost Montgomery Multiplication"
+    /// Perform a Montgomery squaring, assigning the product to `a`.
     pub(super) fn square_assign(&mut self, a: &mut BoxedUint) {
-        debug_assert_eq!(a.bits_precision(), self.modulus.bits_precision());
-
-        self.clear_product();
-        montgomery_mul(
-            self.product.as_words_mut(),
-            a.as_words(),
-            a.as_words(),
-            self.modulus.as_words(),
-            self.mod_neg_inv.into(),
-        );
-        a.limbs
-            .copy_from_slice(&self.product.limbs[..a.limbs.len()]);
-    }
-
-    /// Clear the internal product buffer.
-    fn clear_product(&mut self) {
-        self.product
-            .limbs
-            .iter_mut()
-            .for_each(|limb| *limb = Limb::ZERO);
+        montgomery_square_assign(&mut self.product, a, self.modulus, self.mod_neg_inv);
     }
 }
 
@@ -177,6 +241,74 @@ impl Drop for MontgomeryMultiplier<'_> {
     }
 }
 
+/// Perform a Montgomery multiplication, assigning the product to `a` and using `product` as a
+/// double-width buffer.
+///
+/// The product is fully reduced if `a` and `b` are.
+pub(super) fn montgomery_mul_assign(
+    product: &mut BoxedUint,
+    a: &mut BoxedUint,
+    b: &BoxedUint,
+    modulus: &BoxedUint,
+    mod_neg_inv: Limb,
+) {
+    debug_assert_eq!(a.bits_precision(), modulus.bits_precision());
+    debug_assert_eq!(b.bits_precision(), modulus.bits_precision());
+    debug_assert_eq!(product.nlimbs(), modulus.nlimbs() * 2);
+
+    clear_product(product);
+    montgomery_mul(
+        product.as_words_mut(),
+        a.as_words(),
+        b.as_words(),
+        modulus.as_words(),
+        mod_neg_inv.into(),
+    );
+    let nlimbs = a.nlimbs();
+    a.limbs.copy_from_slice(&product.limbs[..nlimbs]);
+    conditional_sub_modulus(a, modulus);
+}
+
+/// Perform a Montgomery squaring, assigning the product to `a` and using `product` as a
+/// double-width buffer.
+///
+/// The product is fully reduced if `a` is.
+pub(super) fn montgomery_square_assign(
+    product: &mut BoxedUint,
+    a: &mut BoxedUint,
+    modulus: &BoxedUint,
+    mod_neg_inv: Limb,
+) {
+    debug_assert_eq!(a.bits_precision(), modulus.bits_precision());
+    debug_assert_eq!(product.nlimbs(), modulus.nlimbs() * 2);
+
+    clear_product(product);
+    montgomery_mul(
+        product.as_words_mut(),
+        a.as_words(),
+        a.as_words(),
+        modulus.as_words(),
+        mod_neg_inv.into(),
+    );
+    let nlimbs = a.nlimbs();
+    a.limbs.copy_from_slice(&product.limbs[..nlimbs]);
+    conditional_sub_modulus(a, modulus);
+}
+
+/// Subtract `modulus` from `a` if `a >= modulus`.
+///
+/// The output of an "Almost Montgomery Multiplication" of reduced inputs is less than
+/// `2 * modulus`, so this fully reduces it.
+pub(super) fn conditional_sub_modulus(a: &mut BoxedUint, modulus: &BoxedUint) {
+    let borrow = a.sbb_assign(modulus, Limb::ZERO);
+    a.conditional_adc_assign(modulus, Choice::from((borrow.0 & 1) as u8));
+}
+
+/// Clear the product buffer.
+fn clear_product(product: &mut BoxedUint) {
+    product.limbs.iter_mut().for_each(|limb| *limb = Limb::ZERO);
+}
+
 /// Compute an "Almost Montgomery Multiplication (AMM)" as described in the paper
 /// "Efficient Software Implementations of Modular Exponentiation"
 /// <https://eprint.iacr.org/2011/239.pdf>
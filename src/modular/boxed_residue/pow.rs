@@ -1,7 +1,13 @@
 //! Modular exponentiation support for [`BoxedResidue`].
 
-use super::{mul::MontgomeryMultiplier, BoxedResidue};
-use crate::{BoxedUint, Limb, PowBoundedExp, Word};
+use super::{
+    mul::{montgomery_mul_assign, montgomery_square_assign, MontgomeryMultiplier},
+    BoxedResidue, BoxedResidueScratch,
+};
+use crate::{
+    modular::pow::{limbs_bit_vartime, sliding_window_vartime, VARTIME_POWERS},
+    BoxedUint, Limb, PowBoundedExp, Public, Word,
+};
 use alloc::vec::Vec;
 use subtle::ConstantTimeEq;
 
@@ -31,6 +37,106 @@ impl BoxedResidue {
             residue_params: self.residue_params.clone(),
         }
     }
+
+    /// Raises to the `exponent` power in place, using the given scratch space instead of
+    /// allocating.
+    ///
+    /// Panics if the precision of `scratch` doesn't match the precision of `self`.
+    pub fn pow_assign_with_scratch(
+        &mut self,
+        exponent: &BoxedUint,
+        scratch: &mut BoxedResidueScratch,
+    ) {
+        self.pow_bounded_exp_assign_with_scratch(exponent, exponent.bits_precision(), scratch);
+    }
+
+    /// Raises to the `exponent` power in place, using the given scratch space instead of
+    /// allocating, with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    ///
+    /// Panics if the precision of `scratch` doesn't match the precision of `self`.
+    pub fn pow_bounded_exp_assign_with_scratch(
+        &mut self,
+        exponent: &BoxedUint,
+        exponent_bits: u32,
+        scratch: &mut BoxedResidueScratch,
+    ) {
+        assert_eq!(
+            scratch.bits_precision(),
+            self.residue_params.bits_precision(),
+            "scratch precision mismatch"
+        );
+
+        pow_montgomery_form_assign(
+            &mut self.montgomery_form,
+            exponent,
+            exponent_bits,
+            &self.residue_params.modulus,
+            &self.residue_params.r,
+            self.residue_params.mod_neg_inv,
+            scratch,
+        );
+    }
+
+    /// Raises to the `exponent` power using a sliding window which skips the leading zeros of
+    /// the exponent.
+    ///
+    /// NOTE: this is variable-time with respect to `exponent`, which is therefore required to be
+    /// [`Public`], e.g. an RSA public exponent or a scalar in signature verification. Use
+    /// [`BoxedResidue::pow`] for secret exponents.
+    pub fn pow_vartime(&self, exponent: &Public<BoxedUint>) -> Self {
+        Self {
+            montgomery_form: multi_exponentiate_montgomery_form_vartime(
+                &[(&self.montgomery_form, exponent.as_inner())],
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params.clone(),
+        }
+    }
+
+    /// Computes `x1 ^ k1 * ... * xn ^ kn` with interleaved sliding windows sharing the
+    /// squarings, which is faster than computing each power separately.
+    ///
+    /// All the bases must share the same modulus. The exponents may have any precision.
+    ///
+    /// NOTE: this is variable-time with respect to the exponents, which are therefore required to
+    /// be [`Public`].
+    ///
+    /// # Panics
+    ///
+    /// If `bases_and_exponents` is empty or the bases don't share the same modulus.
+    pub fn multi_exponentiate_vartime(bases_and_exponents: &[(Self, Public<BoxedUint>)]) -> Self {
+        assert!(
+            !bases_and_exponents.is_empty(),
+            "bases_and_exponents must not be empty"
+        );
+        let residue_params = &bases_and_exponents[0].0.residue_params;
+        assert!(
+            bases_and_exponents
+                .iter()
+                .all(|(base, _)| base.residue_params == *residue_params),
+            "all the bases must share the same modulus"
+        );
+
+        let bases_and_exponents_montgomery_form = bases_and_exponents
+            .iter()
+            .map(|(base, exponent)| (&base.montgomery_form, exponent.as_inner()))
+            .collect::<Vec<_>>();
+
+        Self {
+            montgomery_form: multi_exponentiate_montgomery_form_vartime(
+                &bases_and_exponents_montgomery_form,
+                &residue_params.modulus,
+                &residue_params.r,
+                residue_params.mod_neg_inv,
+            ),
+            residue_params: residue_params.clone(),
+        }
+    }
 }
 
 impl PowBoundedExp<BoxedUint> for BoxedResidue {
@@ -39,6 +145,10 @@ impl PowBoundedExp<BoxedUint> for BoxedResidue {
     }
 }
 
+/// Window size of the constant-time exponentiation.
+pub(super) const WINDOW: u32 = 4;
+const WINDOW_MASK: Word = (1 << WINDOW) - 1;
+
 /// Performs modular exponentiation using Montgomery's ladder.
 /// `exponent_bits` represents the number of bits to take into account for the exponent.
 ///
@@ -51,22 +161,52 @@ fn pow_montgomery_form(
     r: &BoxedUint,
     mod_neg_inv: Limb,
 ) -> BoxedUint {
-    if exponent_bits == 0 {
-        return r.clone(); // 1 in Montgomery form
-    }
+    let mut scratch = BoxedResidueScratch::with_precision(modulus.bits_precision());
+    let mut z = x.clone();
+    pow_montgomery_form_assign(
+        &mut z,
+        exponent,
+        exponent_bits,
+        modulus,
+        r,
+        mod_neg_inv,
+        &mut scratch,
+    );
+    z
+}
 
-    const WINDOW: u32 = 4;
-    const WINDOW_MASK: Word = (1 << WINDOW) - 1;
+/// Performs modular exponentiation of `z` in place using Montgomery's ladder, without allocating.
+/// `exponent_bits` represents the number of bits to take into account for the exponent.
+///
+/// NOTE: this value is leaked in the time pattern.
+fn pow_montgomery_form_assign(
+    z: &mut BoxedUint,
+    exponent: &BoxedUint,
+    exponent_bits: u32,
+    modulus: &BoxedUint,
+    r: &BoxedUint,
+    mod_neg_inv: Limb,
+    scratch: &mut BoxedResidueScratch,
+) {
+    let BoxedResidueScratch {
+        product,
+        powers,
+        power,
+    } = scratch;
 
-    let mut multiplier = MontgomeryMultiplier::new(modulus, mod_neg_inv);
+    if exponent_bits == 0 {
+        z.limbs.copy_from_slice(&r.limbs); // 1 in Montgomery form
+        return;
+    }
 
     // powers[i] contains x^i
-    let mut powers = Vec::with_capacity(1 << WINDOW);
-    powers.push(r.clone()); // 1 in Montgomery form
-    powers.push(x.clone());
+    powers[0].limbs.copy_from_slice(&r.limbs); // 1 in Montgomery form
+    powers[1].limbs.copy_from_slice(&z.limbs);
 
     for i in 2..(1 << WINDOW) {
-        powers.push(multiplier.mul(&powers[i - 1], x));
+        let (lower, upper) = powers.split_at_mut(i);
+        upper[0].limbs.copy_from_slice(&lower[i - 1].limbs);
+        montgomery_mul_assign(product, &mut upper[0], &lower[1], modulus, mod_neg_inv);
     }
 
     let starting_limb = ((exponent_bits - 1) / Limb::BITS) as usize;
@@ -74,8 +214,7 @@ fn pow_montgomery_form(
     let starting_window = starting_bit_in_limb / WINDOW;
     let starting_window_mask = (1 << (starting_bit_in_limb % WINDOW + 1)) - 1;
 
-    let mut z = r.clone(); // 1 in Montgomery form
-    let mut power = powers[0].clone();
+    z.limbs.copy_from_slice(&r.limbs); // 1 in Montgomery form
 
     for limb_num in (0..=starting_limb).rev() {
         let w = exponent.as_limbs()[limb_num].0;
@@ -95,7 +234,7 @@ fn pow_montgomery_form(
                 idx &= starting_window_mask;
             } else {
                 for _ in 1..=WINDOW {
-                    multiplier.square_assign(&mut z);
+                    montgomery_square_assign(product, z, modulus, mod_neg_inv);
                 }
             }
 
@@ -105,7 +244,78 @@ fn pow_montgomery_form(
                 power.conditional_assign(&powers[i as usize], i.ct_eq(&idx));
             }
 
-            multiplier.mul_assign(&mut z, &power);
+            montgomery_mul_assign(product, z, power, modulus, mod_neg_inv);
+        }
+    }
+}
+
+/// Performs modular multi-exponentiation using interleaved sliding windows, one for each
+/// exponent, which share the squarings.
+///
+/// NOTE: this is variable-time with respect to the exponents.
+fn multi_exponentiate_montgomery_form_vartime(
+    bases_and_exponents: &[(&BoxedUint, &BoxedUint)],
+    modulus: &BoxedUint,
+    r: &BoxedUint,
+    mod_neg_inv: Limb,
+) -> BoxedUint {
+    let mut multiplier = MontgomeryMultiplier::new(modulus, mod_neg_inv);
+
+    // odd_powers[i][j] contains x_i^(2j + 1)
+    let odd_powers = bases_and_exponents
+        .iter()
+        .map(|&(x, _)| {
+            let x2 = multiplier.square(x);
+            let mut powers = Vec::with_capacity(VARTIME_POWERS);
+            powers.push(x.clone());
+            for j in 1..VARTIME_POWERS {
+                powers.push(multiplier.mul(&powers[j - 1], &x2));
+            }
+            powers
+        })
+        .collect::<Vec<_>>();
+
+    // The number of bits of each exponent which haven't been scanned for windows yet, and the
+    // lowest bit and the value of the window of each exponent which is being processed
+    let mut unscanned = bases_and_exponents
+        .iter()
+        .map(|(_, exponent)| exponent.bits_vartime())
+        .collect::<Vec<_>>();
+    let mut windows: Vec<(u32, Word)> = vec![(0, 0); bases_and_exponents.len()];
+    let max_bits = unscanned.iter().copied().max().unwrap_or(0);
+
+    let mut z = r.clone(); // 1 in Montgomery form
+    let mut z_is_one = true;
+
+    for bit in (0..max_bits).rev() {
+        if !z_is_one {
+            multiplier.square_assign(&mut z);
+        }
+
+        for (i, (_, exponent)) in bases_and_exponents.iter().enumerate() {
+            if unscanned[i] == bit + 1 {
+                let exponent = exponent.as_limbs();
+                if limbs_bit_vartime(exponent, bit) {
+                    let (low, value) = sliding_window_vartime(exponent, bit);
+                    windows[i] = (low, value);
+                    unscanned[i] = low;
+                } else {
+                    unscanned[i] = bit;
+                }
+            }
+
+            // The window is multiplied in at its lowest bit, which is set.
+            let (low, value) = windows[i];
+            if value != 0 && low == bit {
+                let power = &odd_powers[i][(value >> 1) as usize];
+                if z_is_one {
+                    z = power.clone();
+                    z_is_one = false;
+                } else {
+                    multiplier.mul_assign(&mut z, power);
+                }
+                windows[i] = (0, 0);
+            }
         }
     }
 
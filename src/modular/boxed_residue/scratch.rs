@@ -0,0 +1,65 @@
+//! Scratch space for allocation-free arithmetic on [`BoxedResidue`]s.
+
+use super::{pow::WINDOW, BoxedResidueParams};
+use crate::BoxedUint;
+use alloc::vec::Vec;
+use core::fmt;
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(doc)]
+use super::BoxedResidue;
+
+/// Pre-allocated scratch space for multiplying, squaring and exponentiating [`BoxedResidue`]s in
+/// place, without performing any further allocations.
+///
+/// A scratch space can be reused for any number of operations on residues with the precision it
+/// was created for, e.g. to run many exponentiations with the same modulus using a fixed number of
+/// allocations made up front.
+pub struct BoxedResidueScratch {
+    /// Double-width buffer for Montgomery multiplication.
+    pub(super) product: BoxedUint,
+    /// Table of the powers of the base, for windowed exponentiation.
+    pub(super) powers: Vec<BoxedUint>,
+    /// Power selected from the table in constant time.
+    pub(super) power: BoxedUint,
+}
+
+impl BoxedResidueScratch {
+    /// Allocates scratch space for residues with the given parameters.
+    pub fn new(residue_params: &BoxedResidueParams) -> Self {
+        Self::with_precision(residue_params.bits_precision())
+    }
+
+    /// Allocates scratch space for residues with the given precision.
+    pub(super) fn with_precision(bits_precision: u32) -> Self {
+        Self {
+            product: BoxedUint::zero_with_precision(bits_precision * 2),
+            powers: vec![BoxedUint::zero_with_precision(bits_precision); 1 << WINDOW],
+            power: BoxedUint::zero_with_precision(bits_precision),
+        }
+    }
+
+    /// Get the precision of the residues this scratch space can be used with.
+    pub fn bits_precision(&self) -> u32 {
+        self.power.bits_precision()
+    }
+}
+
+impl fmt::Debug for BoxedResidueScratch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BoxedResidueScratch")
+            .field("bits_precision", &self.bits_precision())
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Drop for BoxedResidueScratch {
+    fn drop(&mut self) {
+        self.product.zeroize();
+        self.powers.iter_mut().for_each(Zeroize::zeroize);
+        self.power.zeroize();
+    }
+}
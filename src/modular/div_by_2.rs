@@ -1,6 +1,12 @@
 use crate::Uint;
 
-pub(crate) fn div_by_2<const LIMBS: usize>(a: &Uint<LIMBS>, modulus: &Uint<LIMBS>) -> Uint<LIMBS> {
+#[cfg(feature = "alloc")]
+use crate::BoxedUint;
+
+pub(crate) const fn div_by_2<const LIMBS: usize>(
+    a: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+) -> Uint<LIMBS> {
     // We are looking for such `x` that `x * 2 = y mod modulus`,
     // where the given `a = M(y)` is the Montgomery representation of some `y`.
     // This means that in Montgomery representation it would still apply:
@@ -28,3 +34,19 @@ pub(crate) fn div_by_2<const LIMBS: usize>(a: &Uint<LIMBS>, modulus: &Uint<LIMBS
 
     Uint::<LIMBS>::ct_select(&if_even, &if_odd, is_odd)
 }
+
+/// Boxed variant of [`div_by_2`], see there for details.
+#[cfg(feature = "alloc")]
+pub(crate) fn div_by_2_boxed(a: &BoxedUint, modulus: &BoxedUint) -> BoxedUint {
+    debug_assert_eq!(a.bits_precision(), modulus.bits_precision());
+
+    let (half, is_odd) = a.shr1_with_overflow();
+    let half_modulus = modulus.shr1();
+
+    let if_even = half;
+    let if_odd = if_even
+        .wrapping_add(&half_modulus)
+        .wrapping_add(&BoxedUint::one_with_precision(a.bits_precision()));
+
+    BoxedUint::conditional_select(&if_even, &if_odd, is_odd)
+}
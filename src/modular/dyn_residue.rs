@@ -1,5 +1,6 @@
 //! Implements `DynResidue`s, supporting modular arithmetic with a modulus set at runtime.
 
+mod accumulator;
 mod add;
 mod inv;
 mod mul;
@@ -9,27 +10,41 @@ mod sub;
 
 use super::{
     div_by_2::div_by_2,
-    reduction::montgomery_reduction,
+    reduction::{assert_uniform_bytes_len, montgomery_form_wide, montgomery_reduction},
     residue::{Residue, ResidueParams},
     Retrieve,
 };
-use crate::{Integer, Limb, Uint, Word};
-use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+pub use self::accumulator::DynResidueAccumulator;
+use crate::{
+    traits::{ct_lookup_selectable, ct_select_slice_selectable},
+    ConstantTimeLookup, Integer, Limb, Uint, Word,
+};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+    CtOption,
+};
+
+#[cfg(feature = "serde")]
+use {
+    crate::Encoding,
+    serdect::serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer},
+};
 
 /// Parameters to efficiently go to/from the Montgomery form for an odd modulus provided at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DynResidueParams<const LIMBS: usize> {
     /// The constant modulus
-    modulus: Uint<LIMBS>,
+    pub(super) modulus: Uint<LIMBS>,
     /// Parameter used in Montgomery reduction
-    r: Uint<LIMBS>,
+    pub(super) r: Uint<LIMBS>,
     /// R^2, used to move into Montgomery form
-    r2: Uint<LIMBS>,
+    pub(super) r2: Uint<LIMBS>,
     /// R^3, used to compute the multiplicative inverse
-    r3: Uint<LIMBS>,
+    pub(super) r3: Uint<LIMBS>,
     /// The lowest limbs of -(MODULUS^-1) mod R
     /// We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
-    mod_neg_inv: Limb,
+    pub(super) mod_neg_inv: Limb,
 }
 
 impl<const LIMBS: usize> DynResidueParams<LIMBS> {
@@ -37,8 +52,20 @@ impl<const LIMBS: usize> DynResidueParams<LIMBS> {
     ///
     /// Returns a `CtOption` that is `None` if the provided modulus is not odd.
     pub fn new(modulus: &Uint<LIMBS>) -> CtOption<Self> {
-        let r = Uint::MAX.const_rem(modulus).0.wrapping_add(&Uint::ONE);
-        let r2 = Uint::const_rem_wide(r.square_wide(), modulus).0;
+        CtOption::new(Self::new_unchecked(modulus), modulus.is_odd())
+    }
+
+    /// Instantiates a new set of `ResidueParams` representing the given `modulus`, which must be
+    /// odd, in a `const fn`. The parameters are meaningless for an even modulus.
+    pub(crate) const fn new_unchecked(modulus: &Uint<LIMBS>) -> Self {
+        let r = Uint::MAX
+            .const_rem(modulus)
+            .components_ref()
+            .0
+            .wrapping_add(&Uint::ONE);
+        let r2 = *Uint::const_rem_wide(r.square_wide(), modulus)
+            .components_ref()
+            .0;
 
         // Since we are calculating the inverse modulo (Word::MAX+1),
         // we can take the modulo right away and calculate the inverse of the first limb only.
@@ -48,15 +75,55 @@ impl<const LIMBS: usize> DynResidueParams<LIMBS> {
 
         let r3 = montgomery_reduction(&r2.square_wide(), modulus, mod_neg_inv);
 
-        let params = Self {
+        Self {
             modulus: *modulus,
             r,
             r2,
             r3,
             mod_neg_inv,
+        }
+    }
+
+    /// Instantiates a new set of `ResidueParams` from precomputed Montgomery constants, e.g. the
+    /// ones returned by [`DynResidueParams::r`], [`DynResidueParams::r2`],
+    /// [`DynResidueParams::r3`] and [`DynResidueParams::mod_neg_inv`], which avoids the divisions
+    /// performed by [`DynResidueParams::new`].
+    ///
+    /// The constants are checked for consistency with the modulus at the cost of three Montgomery
+    /// reductions. Returns a `CtOption` that is `None` if the provided modulus is not odd or
+    /// the constants don't match it.
+    pub fn from_precomputed(
+        modulus: &Uint<LIMBS>,
+        r: &Uint<LIMBS>,
+        r2: &Uint<LIMBS>,
+        r3: &Uint<LIMBS>,
+        mod_neg_inv: Limb,
+    ) -> CtOption<Self> {
+        // `r` and `1` are only reduced modulo `modulus` if the latter is larger than 1.
+        let is_one = Uint::ct_eq(modulus, &Uint::ONE);
+        let r_reduced = Uint::ct_select(r, &r.wrapping_sub(modulus), Uint::ct_eq(r, modulus));
+        let one_reduced = Uint::ct_select(&Uint::ONE, &Uint::ZERO, is_one);
+
+        let is_consistent = modulus.is_odd()
+            & modulus.limbs[0].wrapping_mul(mod_neg_inv).ct_eq(&Limb::MAX)
+            & !r.ct_gt(modulus)
+            & r2.ct_lt(modulus)
+            & r3.ct_lt(modulus)
+            // `r * R^-1 = 1` binds `r` to `R`, which the other checks then extend to `r2` and `r3`
+            & montgomery_reduction(&(r_reduced, Uint::ZERO), modulus, mod_neg_inv)
+                .ct_eq(&one_reduced)
+            & montgomery_reduction(&(*r2, Uint::ZERO), modulus, mod_neg_inv).ct_eq(&r_reduced)
+            & montgomery_reduction(&r2.square_wide(), modulus, mod_neg_inv).ct_eq(r3);
+
+        let params = Self {
+            modulus: *modulus,
+            r: *r,
+            r2: *r2,
+            r3: *r3,
+            mod_neg_inv,
         };
 
-        CtOption::new(params, modulus.is_odd())
+        CtOption::new(params, is_consistent)
     }
 
     /// Returns the modulus which was used to initialize these parameters.
@@ -64,6 +131,26 @@ impl<const LIMBS: usize> DynResidueParams<LIMBS> {
         &self.modulus
     }
 
+    /// Returns the Montgomery constant `R mod modulus`.
+    pub const fn r(&self) -> &Uint<LIMBS> {
+        &self.r
+    }
+
+    /// Returns `R^2 mod modulus`, used to move into Montgomery form.
+    pub const fn r2(&self) -> &Uint<LIMBS> {
+        &self.r2
+    }
+
+    /// Returns `R^3 mod modulus`, used to compute multiplicative inverses.
+    pub const fn r3(&self) -> &Uint<LIMBS> {
+        &self.r3
+    }
+
+    /// Returns the lowest limb of `-(modulus^-1) mod R`.
+    pub const fn mod_neg_inv(&self) -> Limb {
+        self.mod_neg_inv
+    }
+
     /// Create `DynResidueParams` corresponding to a `ResidueParams`.
     pub const fn from_residue_params<P>() -> Self
     where
@@ -104,8 +191,8 @@ impl<const LIMBS: usize> ConstantTimeEq for DynResidueParams<LIMBS> {
 /// A residue represented using `LIMBS` limbs. The odd modulus of this residue is set at runtime.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DynResidue<const LIMBS: usize> {
-    montgomery_form: Uint<LIMBS>,
-    residue_params: DynResidueParams<LIMBS>,
+    pub(super) montgomery_form: Uint<LIMBS>,
+    pub(super) residue_params: DynResidueParams<LIMBS>,
 }
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
@@ -124,6 +211,42 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
         }
     }
 
+    /// Instantiates a new `Residue` by reducing the integer encoded by the given big endian bytes
+    /// mod the modulus, e.g. the output of `expand_message` in RFC 9380's `hash_to_field`.
+    ///
+    /// The bytes can be at most twice as long as [`Uint::BYTES`], and shorter inputs are implicitly
+    /// padded with leading zeros. This function is constant-time with respect to the value of the
+    /// bytes.
+    ///
+    /// Panics if there are too many bytes.
+    pub const fn reduce_wide_bytes(bytes: &[u8], residue_params: DynResidueParams<LIMBS>) -> Self {
+        let wide = Uint::from_be_slice_wide(bytes);
+        let montgomery_form = montgomery_form_wide(
+            &wide,
+            &residue_params.modulus,
+            &residue_params.r2,
+            &residue_params.r3,
+            residue_params.mod_neg_inv,
+        );
+
+        Self {
+            montgomery_form,
+            residue_params,
+        }
+    }
+
+    /// Instantiates a new `Residue` from uniformly random big endian bytes, e.g. hash or XOF
+    /// output, such that the result is uniformly distributed mod the modulus up to a bias of at
+    /// most `2^-128`.
+    ///
+    /// Panics if there are fewer than `ceil((modulus_bits + 128) / 8)` bytes (the length `L`
+    /// from RFC 9380 with `k = 128`), and otherwise behaves like
+    /// [`reduce_wide_bytes`][`DynResidue::reduce_wide_bytes`].
+    pub fn from_uniform_bytes(bytes: &[u8], residue_params: DynResidueParams<LIMBS>) -> Self {
+        assert_uniform_bytes_len(bytes.len(), residue_params.modulus.bits());
+        Self::reduce_wide_bytes(bytes, residue_params)
+    }
+
     /// Retrieves the integer currently encoded in this `Residue`, guaranteed to be reduced.
     pub const fn retrieve(&self) -> Uint<LIMBS> {
         montgomery_reduction(
@@ -185,7 +308,7 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
     /// - if `x` is even, returns `x / 2`,
     /// - if `x` is odd, returns `(x + p) / 2`
     ///   (since the modulus `p` in Montgomery form is always odd, this divides entirely).
-    pub fn div_by_2(&self) -> Self {
+    pub const fn div_by_2(&self) -> Self {
         Self {
             montgomery_form: div_by_2(&self.montgomery_form, &self.residue_params.modulus),
             residue_params: self.residue_params,
@@ -226,6 +349,16 @@ impl<const LIMBS: usize> ConditionallySelectable for DynResidue<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> ConstantTimeLookup for DynResidue<LIMBS> {
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_selectable(table, index)
+    }
+
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_selectable(out, a, b, choice)
+    }
+}
+
 impl<const LIMBS: usize> ConstantTimeEq for DynResidue<LIMBS> {
     fn ct_eq(&self, other: &Self) -> Choice {
         self.montgomery_form.ct_eq(&other.montgomery_form)
@@ -233,6 +366,58 @@ impl<const LIMBS: usize> ConstantTimeEq for DynResidue<LIMBS> {
     }
 }
 
+#[cfg(feature = "zeroize")]
+impl<const LIMBS: usize> zeroize::Zeroize for DynResidueParams<LIMBS> {
+    fn zeroize(&mut self) {
+        self.modulus.zeroize();
+        self.r.zeroize();
+        self.r2.zeroize();
+        self.r3.zeroize();
+        self.mod_neg_inv.zeroize();
+    }
+}
+
+/// Serializes the modulus along with its precomputed Montgomery constants, so that deserializing
+/// them only requires the consistency check of [`DynResidueParams::from_precomputed`].
+#[cfg(feature = "serde")]
+impl<const LIMBS: usize> Serialize for DynResidueParams<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        (
+            &self.modulus,
+            &self.r,
+            &self.r2,
+            &self.r3,
+            &self.mod_neg_inv,
+        )
+            .serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, const LIMBS: usize> Deserialize<'de> for DynResidueParams<LIMBS>
+where
+    Uint<LIMBS>: Encoding,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let (modulus, r, r2, r3, mod_neg_inv) =
+            <(Uint<LIMBS>, Uint<LIMBS>, Uint<LIMBS>, Uint<LIMBS>, Limb)>::deserialize(
+                deserializer,
+            )?;
+
+        Option::from(Self::from_precomputed(&modulus, &r, &r2, &r3, mod_neg_inv))
+            .ok_or_else(|| D::Error::custom("inconsistent residue parameters"))
+    }
+}
+
 /// NOTE: this does _not_ zeroize the parameters, in order to maintain some form of type consistency
 #[cfg(feature = "zeroize")]
 impl<const LIMBS: usize> zeroize::Zeroize for DynResidue<LIMBS> {
@@ -254,6 +439,73 @@ mod test {
         DynResidueParams::<LIMBS>::new(&valid_modulus).unwrap();
     }
 
+    #[test]
+    fn from_precomputed() {
+        for modulus in [
+            Uint::<LIMBS>::ONE,
+            Uint::from(3u8),
+            Uint::MAX,
+            Uint::MAX.shr_vartime(1),
+        ] {
+            let params = DynResidueParams::new(&modulus).unwrap();
+            let precomputed = DynResidueParams::from_precomputed(
+                params.modulus(),
+                params.r(),
+                params.r2(),
+                params.r3(),
+                params.mod_neg_inv(),
+            )
+            .unwrap();
+            assert_eq!(precomputed, params);
+
+            let wrong = params.r2().wrapping_add(&Uint::ONE);
+            assert!(bool::from(
+                DynResidueParams::from_precomputed(
+                    params.modulus(),
+                    params.r(),
+                    &wrong,
+                    params.r3(),
+                    params.mod_neg_inv(),
+                )
+                .is_none()
+            ));
+        }
+    }
+
+    #[test]
+    fn from_precomputed_scaled() {
+        // `(x, x * R, x^2 * R)` satisfies the relations between `r`, `r2` and `r3` for any `x`
+        let params = DynResidueParams::new(&Uint::<LIMBS>::from(11u8)).unwrap();
+        let two = Uint::from(2u8);
+        let r2 = DynResidue::new(&two, params).to_montgomery();
+        let r3 = DynResidue::new(&Uint::from(4u8), params).to_montgomery();
+
+        assert!(bool::from(
+            DynResidueParams::from_precomputed(
+                params.modulus(),
+                &two,
+                &r2,
+                &r3,
+                params.mod_neg_inv(),
+            )
+            .is_none()
+        ));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    #[allow(clippy::unwrap_used)]
+    fn serde() {
+        let params = DynResidueParams::new(&Uint::<LIMBS>::MAX).unwrap();
+        let serialized = bincode::serialize(&params).unwrap();
+        let deserialized: DynResidueParams<LIMBS> = bincode::deserialize(&serialized).unwrap();
+        assert_eq!(deserialized, params);
+
+        let mut corrupted = serialized.clone();
+        corrupted[0] ^= 2;
+        assert!(bincode::deserialize::<DynResidueParams<LIMBS>>(&corrupted).is_err());
+    }
+
     #[test]
     // Test that an invalid checked modulus does not yield `DynResidueParams`
     fn test_invalid_checked_modulus() {
@@ -0,0 +1,99 @@
+//! Lazily reduced sums of products of residues with a modulus set at runtime.
+
+use super::{DynResidue, DynResidueParams};
+use crate::{
+    modular::{mul::mul_accumulate_montgomery_form, reduction::montgomery_reduction},
+    Uint,
+};
+
+/// An unreduced, double-width sum of products of [`DynResidue`]s.
+///
+/// Each product is added without being reduced, and a single Montgomery reduction is performed by
+/// [`DynResidueAccumulator::reduce`], which makes computing sums of products such as inner
+/// products cheaper than adding up the results of [`DynResidue::mul`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DynResidueAccumulator<const LIMBS: usize> {
+    /// Lower and upper halves of the sum, the latter being kept below the modulus.
+    lower_upper: (Uint<LIMBS>, Uint<LIMBS>),
+    residue_params: DynResidueParams<LIMBS>,
+}
+
+impl<const LIMBS: usize> DynResidueAccumulator<LIMBS> {
+    /// Instantiates an empty sum of products of residues with the given parameters.
+    pub const fn new(residue_params: DynResidueParams<LIMBS>) -> Self {
+        Self {
+            lower_upper: (Uint::ZERO, Uint::ZERO),
+            residue_params,
+        }
+    }
+
+    /// Returns the sum with the product `a * b` added to it.
+    ///
+    /// Both residues must have the same parameters as the accumulator.
+    pub const fn mul_add(&self, a: &DynResidue<LIMBS>, b: &DynResidue<LIMBS>) -> Self {
+        Self {
+            lower_upper: mul_accumulate_montgomery_form(
+                &self.lower_upper,
+                &a.montgomery_form,
+                &b.montgomery_form,
+                &self.residue_params.modulus,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Adds the product `a * b` to the sum.
+    ///
+    /// Both residues must have the same parameters as the accumulator.
+    pub fn mul_add_assign(&mut self, a: &DynResidue<LIMBS>, b: &DynResidue<LIMBS>) {
+        debug_assert_eq!(a.residue_params, self.residue_params);
+        debug_assert_eq!(b.residue_params, self.residue_params);
+
+        *self = self.mul_add(a, b);
+    }
+
+    /// Reduces the sum to a [`DynResidue`].
+    pub const fn reduce(&self) -> DynResidue<LIMBS> {
+        DynResidue {
+            montgomery_form: montgomery_reduction(
+                &self.lower_upper,
+                &self.residue_params.modulus,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+}
+
+impl<const LIMBS: usize> DynResidue<LIMBS> {
+    /// Computes the sum of products `a_1 * b_1 + ... + a_n * b_n` with a single Montgomery
+    /// reduction, see [`DynResidueAccumulator`].
+    ///
+    /// All the residues must share the same parameters.
+    ///
+    /// # Panics
+    ///
+    /// If `pairs` is empty.
+    pub const fn sum_of_products(pairs: &[(Self, Self)]) -> Self {
+        assert!(!pairs.is_empty(), "pairs must not be empty");
+
+        let mut acc = DynResidueAccumulator::new(pairs[0].0.residue_params);
+        let mut i = 0;
+
+        while i < pairs.len() {
+            acc = acc.mul_add(&pairs[i].0, &pairs[i].1);
+            i += 1;
+        }
+
+        acc.reduce()
+    }
+}
+
+/// NOTE: this does _not_ zeroize the parameters, in order to maintain some form of type consistency
+#[cfg(feature = "zeroize")]
+impl<const LIMBS: usize> zeroize::Zeroize for DynResidueAccumulator<LIMBS> {
+    fn zeroize(&mut self) {
+        self.lower_upper.0.zeroize();
+        self.lower_upper.1.zeroize();
+    }
+}
@@ -1,7 +1,7 @@
 //! Additions between residues with a modulus set at runtime.
 
 use super::DynResidue;
-use crate::modular::add::add_montgomery_form;
+use crate::modular::add::{add_montgomery_form, double_montgomery_form};
 use core::ops::{Add, AddAssign};
 
 impl<const LIMBS: usize> DynResidue<LIMBS> {
@@ -16,6 +16,22 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         }
     }
+
+    /// Computes `2 * self`.
+    pub const fn double(&self) -> Self {
+        Self {
+            montgomery_form: double_montgomery_form(
+                &self.montgomery_form,
+                &self.residue_params.modulus,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Computes `3 * self`.
+    pub const fn triple(&self) -> Self {
+        Self::add(&self.double(), self)
+    }
 }
 
 impl<const LIMBS: usize> Add<&DynResidue<LIMBS>> for &DynResidue<LIMBS> {
@@ -1,15 +1,22 @@
 //! Multiplicative inverses of residues with a modulus set at runtime.
 
 use super::DynResidue;
-use crate::{modular::inv::inv_montgomery_form, traits::Invert, CtChoice};
+use crate::{
+    modular::inv::{batch_invert_in_place, inv_montgomery_form},
+    traits::{BatchInvert, Invert},
+    ConstCtOption,
+};
 use subtle::CtOption;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 impl<const LIMBS: usize> DynResidue<LIMBS> {
     /// Computes the residue `self^-1` representing the multiplicative inverse of `self`.
     /// I.e. `self * self^-1 = 1`.
-    /// If the number was invertible, the second element of the tuple is the truthy value,
-    /// otherwise it is the falsy value (in which case the first element's value is unspecified).
-    pub const fn invert(&self) -> (Self, CtChoice) {
+    /// The result is none if the number was not invertible,
+    /// in which case the contained value is unspecified.
+    pub const fn invert(&self) -> ConstCtOption<Self> {
         let (montgomery_form, is_some) = inv_montgomery_form(
             &self.montgomery_form,
             &self.residue_params.modulus,
@@ -22,14 +29,86 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         };
 
-        (value, is_some)
+        ConstCtOption::new(value, is_some)
     }
 }
 
 impl<const LIMBS: usize> Invert for DynResidue<LIMBS> {
     type Output = CtOption<Self>;
     fn invert(&self) -> Self::Output {
-        let (value, is_some) = self.invert();
-        CtOption::new(value, is_some.into())
+        self.invert().into()
+    }
+}
+
+impl<const LIMBS: usize, const N: usize> BatchInvert<[Self; N]> for DynResidue<LIMBS> {
+    type Output = CtOption<[Self; N]>;
+
+    /// All of the `elements` must have the same residue parameters.
+    fn batch_invert(elements: &[Self; N]) -> Self::Output {
+        let mut inverses = *elements;
+
+        if let Some(first) = elements.first() {
+            let zero = Self::zero(first.residue_params);
+            let one = Self::one(first.residue_params);
+            let mut scratch = [one; N];
+            let is_some =
+                batch_invert_in_place(&mut inverses, &mut scratch, &zero, &one, Self::invert);
+            return CtOption::new(inverses, is_some);
+        }
+
+        CtOption::new(inverses, 1.into())
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<const LIMBS: usize> BatchInvert<[Self]> for DynResidue<LIMBS> {
+    type Output = CtOption<Vec<Self>>;
+
+    /// All of the `elements` must have the same residue parameters.
+    fn batch_invert(elements: &[Self]) -> Self::Output {
+        let mut inverses = elements.to_vec();
+
+        if let Some(first) = elements.first() {
+            let zero = Self::zero(first.residue_params);
+            let one = Self::one(first.residue_params);
+            let mut scratch = vec![one; elements.len()];
+            let is_some =
+                batch_invert_in_place(&mut inverses, &mut scratch, &zero, &one, Self::invert);
+            return CtOption::new(inverses, is_some);
+        }
+
+        CtOption::new(inverses, 1.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DynResidue;
+    use crate::{modular::DynResidueParams, BatchInvert, U256};
+
+    #[test]
+    fn test_batch_invert() {
+        let params = DynResidueParams::new(&U256::from_be_hex(
+            "15477BCCEFE197328255BFA79A1217899016D927EF460F4FF404029D24FA4409",
+        ))
+        .unwrap();
+        let x = DynResidue::new(
+            &U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+            params,
+        );
+        let zero = DynResidue::zero(params);
+
+        let elements = [zero, x, x.square()];
+        let inverses = DynResidue::batch_invert(&elements).unwrap();
+
+        assert_eq!(inverses[0], zero);
+        assert_eq!(inverses[1], x.invert().unwrap());
+        assert_eq!(inverses[2], x.square().invert().unwrap());
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            DynResidue::batch_invert(&elements[..]).unwrap(),
+            inverses.to_vec()
+        );
     }
 }
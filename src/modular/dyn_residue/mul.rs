@@ -2,7 +2,7 @@
 
 use super::DynResidue;
 use crate::{
-    modular::mul::{mul_montgomery_form, square_montgomery_form},
+    modular::mul::{mul_by_small_montgomery_form, mul_montgomery_form, square_montgomery_form},
     traits::Square,
 };
 use core::ops::{Mul, MulAssign};
@@ -32,6 +32,21 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         }
     }
+
+    /// Multiplies by the small constant `k`, which only takes modular additions and is
+    /// cheaper than a full Montgomery multiplication for the constants found in formulas.
+    ///
+    /// NOTE: this is variable-time with respect to `k`.
+    pub const fn mul_by_small(&self, k: u32) -> Self {
+        Self {
+            montgomery_form: mul_by_small_montgomery_form(
+                &self.montgomery_form,
+                k,
+                &self.residue_params.modulus,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
 }
 
 impl<const LIMBS: usize> Mul<&DynResidue<LIMBS>> for &DynResidue<LIMBS> {
@@ -2,8 +2,11 @@
 
 use super::DynResidue;
 use crate::{
-    modular::pow::{multi_exponentiate_montgomery_form_array, pow_montgomery_form},
-    MultiExponentiateBoundedExp, PowBoundedExp, Uint,
+    modular::pow::{
+        multi_exponentiate_montgomery_form_array, multi_exponentiate_montgomery_form_vartime,
+        pow_montgomery_form, pow_montgomery_form_vartime,
+    },
+    MultiExponentiateBoundedExp, PowBoundedExp, Public, Uint,
 };
 
 #[cfg(feature = "alloc")]
@@ -40,6 +43,62 @@ impl<const LIMBS: usize> DynResidue<LIMBS> {
             residue_params: self.residue_params,
         }
     }
+
+    /// Raises to the `exponent` power using a sliding window which skips the leading zeros of
+    /// the exponent.
+    ///
+    /// NOTE: this is variable-time with respect to `exponent`, which is therefore required to be
+    /// [`Public`], e.g. an RSA public exponent or a scalar in signature verification. Use
+    /// [`DynResidue::pow`] for secret exponents.
+    pub const fn pow_vartime<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Public<Uint<RHS_LIMBS>>,
+    ) -> Self {
+        Self {
+            montgomery_form: pow_montgomery_form_vartime(
+                &self.montgomery_form,
+                exponent.as_inner(),
+                &self.residue_params.modulus,
+                &self.residue_params.r,
+                self.residue_params.mod_neg_inv,
+            ),
+            residue_params: self.residue_params,
+        }
+    }
+
+    /// Computes `x1 ^ k1 * ... * xn ^ kn` with interleaved sliding windows sharing the
+    /// squarings, which is faster than computing each power separately.
+    ///
+    /// All the bases must share the same modulus, and at least one base must be given.
+    ///
+    /// NOTE: this is variable-time with respect to the exponents, which are therefore required to
+    /// be [`Public`]. Use [`MultiExponentiate`](crate::MultiExponentiate) for secret exponents.
+    pub const fn multi_exponentiate_vartime<const N: usize, const RHS_LIMBS: usize>(
+        bases_and_exponents: &[(Self, Public<Uint<RHS_LIMBS>>); N],
+    ) -> Self {
+        const_assert_ne!(N, 0, "bases_and_exponents must not be empty");
+        let residue_params = bases_and_exponents[0].0.residue_params;
+
+        let mut bases_and_exponents_montgomery_form =
+            [(Uint::<LIMBS>::ZERO, Uint::<RHS_LIMBS>::ZERO); N];
+
+        let mut i = 0;
+        while i < N {
+            let (base, exponent) = &bases_and_exponents[i];
+            bases_and_exponents_montgomery_form[i] = (base.montgomery_form, *exponent.as_inner());
+            i += 1;
+        }
+
+        Self {
+            montgomery_form: multi_exponentiate_montgomery_form_vartime(
+                &bases_and_exponents_montgomery_form,
+                &residue_params.modulus,
+                &residue_params.r,
+                residue_params.mod_neg_inv,
+            ),
+            residue_params,
+        }
+    }
 }
 
 impl<const LIMBS: usize, const RHS_LIMBS: usize> PowBoundedExp<Uint<RHS_LIMBS>>
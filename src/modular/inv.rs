@@ -1,4 +1,6 @@
-use crate::{modular::reduction::montgomery_reduction, CtChoice, Limb, Uint};
+use crate::{modular::reduction::montgomery_reduction, ConstCtOption, CtChoice, Limb, Uint};
+use core::ops::Mul;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 pub const fn inv_montgomery_form<const LIMBS: usize>(
     x: &Uint<LIMBS>,
@@ -6,9 +8,75 @@ pub const fn inv_montgomery_form<const LIMBS: usize>(
     r3: &Uint<LIMBS>,
     mod_neg_inv: Limb,
 ) -> (Uint<LIMBS>, CtChoice) {
-    let (inverse, is_some) = x.inv_odd_mod(modulus);
+    let inverse = x.inv_odd_mod(modulus);
+    let (inverse, is_some) = inverse.components_ref();
     (
         montgomery_reduction(&inverse.mul_wide(r3), modulus, mod_neg_inv),
         is_some,
     )
 }
+
+/// Inverts all nonzero `elements` in place using Montgomery's trick, leaving zero elements as zero.
+///
+/// `scratch` must have the same length as `elements`. Returns the falsy value if any of the
+/// nonzero elements was not invertible, in which case the contents of `elements` are unspecified.
+pub(crate) fn batch_invert_in_place<T>(
+    elements: &mut [T],
+    scratch: &mut [T],
+    zero: &T,
+    one: &T,
+    invert: impl FnOnce(&T) -> ConstCtOption<T>,
+) -> Choice
+where
+    T: Copy + ConditionallySelectable + ConstantTimeEq + Mul<Output = T>,
+{
+    batch_invert_in_place_with(
+        elements,
+        scratch,
+        zero,
+        one,
+        T::conditional_select,
+        |a, b| *a * *b,
+        |x| {
+            let (inverse, is_some) = invert(x).into_components();
+            (inverse, is_some.into())
+        },
+    )
+}
+
+/// Like [`batch_invert_in_place`], for types which are not [`Copy`], using the given `select`,
+/// `mul` and `invert` functions. `invert` returns a falsy [`Choice`] if its argument is not
+/// invertible.
+pub(crate) fn batch_invert_in_place_with<T>(
+    elements: &mut [T],
+    scratch: &mut [T],
+    zero: &T,
+    one: &T,
+    select: impl Fn(&T, &T, Choice) -> T,
+    mut mul: impl FnMut(&T, &T) -> T,
+    invert: impl FnOnce(&T) -> (T, Choice),
+) -> Choice
+where
+    T: Clone + ConstantTimeEq,
+{
+    debug_assert_eq!(elements.len(), scratch.len());
+
+    // Compute the prefix products, replacing zeros by one so that they don't affect the result.
+    let mut acc = one.clone();
+    for (x, s) in elements.iter().zip(scratch.iter_mut()) {
+        let product = mul(&acc, &select(x, one, x.ct_eq(zero)));
+        *s = acc;
+        acc = product;
+    }
+
+    let (mut acc_inv, is_some) = invert(&acc);
+
+    for (x, s) in elements.iter_mut().zip(scratch.iter()).rev() {
+        let is_zero = x.ct_eq(zero);
+        let inv = mul(&acc_inv, s);
+        acc_inv = mul(&acc_inv, &select(x, one, is_zero));
+        *x = select(&inv, zero, is_zero);
+    }
+
+    is_some
+}
@@ -12,6 +12,51 @@ pub(crate) const fn mul_montgomery_form<const LIMBS: usize>(
     montgomery_reduction::<LIMBS>(&product, modulus, mod_neg_inv)
 }
 
+/// Multiplies `a` by the small constant `k` with a double-and-add chain over the bits of `k`,
+/// which is cheaper than a Montgomery multiplication for the constants found in formulas.
+///
+/// Since the Montgomery form is linear, this works regardless of whether `a` is in Montgomery
+/// form or not. NOTE: this is variable-time with respect to `k`.
+pub(crate) const fn mul_by_small_montgomery_form<const LIMBS: usize>(
+    a: &Uint<LIMBS>,
+    k: u32,
+    modulus: &Uint<LIMBS>,
+) -> Uint<LIMBS> {
+    let mut ret = Uint::ZERO;
+    let mut i = u32::BITS - k.leading_zeros();
+
+    while i > 0 {
+        i -= 1;
+        ret = ret.add_mod(&ret, modulus);
+        if (k >> i) & 1 == 1 {
+            ret = ret.add_mod(a, modulus);
+        }
+    }
+
+    ret
+}
+
+/// Adds the double-width product `a * b` of two integers reduced modulo `modulus` to the unreduced
+/// sum `lower_upper`, without performing a Montgomery reduction.
+///
+/// The upper half of the sum is kept below `modulus` by subtracting `modulus * R` whenever
+/// needed, which doesn't change the sum modulo `modulus` but ensures that it never overflows and
+/// always remains a valid input for [`montgomery_reduction`].
+pub(crate) const fn mul_accumulate_montgomery_form<const LIMBS: usize>(
+    lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
+    a: &Uint<LIMBS>,
+    b: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+) -> (Uint<LIMBS>, Uint<LIMBS>) {
+    let (lo, hi) = a.mul_wide(b);
+    let (lower, carry) = lower_upper.0.adc(&lo, Limb::ZERO);
+    let upper = lower_upper
+        .1
+        .add_mod(&hi, modulus)
+        .add_mod(&Uint::from_word(carry.0), modulus);
+    (lower, upper)
+}
+
 pub(crate) const fn square_montgomery_form<const LIMBS: usize>(
     a: &Uint<LIMBS>,
     modulus: &Uint<LIMBS>,
@@ -1,4 +1,4 @@
-use crate::{CtChoice, Limb, Uint, Word};
+use crate::{Limb, Uint, Word};
 
 use super::mul::{mul_montgomery_form, square_montgomery_form};
 
@@ -159,14 +159,8 @@ const fn multi_exponentiate_montgomery_form_internal<const LIMBS: usize, const R
                     idx &= starting_window_mask;
                 }
 
-                // Constant-time lookup in the array of powers
-                let mut power = powers[0];
-                let mut j = 1;
-                while j < 1 << WINDOW {
-                    let choice = CtChoice::from_word_eq(j, idx);
-                    power = Uint::<LIMBS>::ct_select(&power, &powers[j as usize], choice);
-                    j += 1;
-                }
+                // Constant-time lookup in the array of powers, `idx` is always in bounds
+                let power = Uint::const_ct_lookup(&powers, idx as usize).unwrap();
 
                 z = mul_montgomery_form(&z, &power, modulus, mod_neg_inv);
                 i += 1;
@@ -176,3 +170,143 @@ const fn multi_exponentiate_montgomery_form_internal<const LIMBS: usize, const R
 
     z
 }
+
+/// Maximum window size of the variable-time sliding window exponentiation, for which the odd
+/// powers `x, x^3, ..., x^(2^VARTIME_WINDOW - 1)` of each base are precomputed.
+const VARTIME_WINDOW: u32 = 4;
+
+/// Number of precomputed odd powers for the variable-time sliding window exponentiation.
+pub(crate) const VARTIME_POWERS: usize = 1 << (VARTIME_WINDOW - 1);
+
+/// Performs modular exponentiation using a sliding window, skipping the leading zeros of the
+/// exponent as well as the multiplications for zero bits.
+///
+/// NOTE: this is variable-time with respect to `exponent`, which must therefore be public.
+pub const fn pow_montgomery_form_vartime<const LIMBS: usize, const RHS_LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    exponent: &Uint<RHS_LIMBS>,
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    multi_exponentiate_montgomery_form_vartime(&[(*x, *exponent)], modulus, r, mod_neg_inv)
+}
+
+/// Performs modular multi-exponentiation using interleaved sliding windows, one for each
+/// exponent, which share the squarings.
+///
+/// NOTE: this is variable-time with respect to the exponents, which must therefore be public.
+pub const fn multi_exponentiate_montgomery_form_vartime<
+    const LIMBS: usize,
+    const RHS_LIMBS: usize,
+    const N: usize,
+>(
+    bases_and_exponents: &[(Uint<LIMBS>, Uint<RHS_LIMBS>); N],
+    modulus: &Uint<LIMBS>,
+    r: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    let mut odd_powers = [[Uint::<LIMBS>::ZERO; VARTIME_POWERS]; N];
+    // The number of bits of each exponent which haven't been scanned for windows yet
+    let mut unscanned = [0u32; N];
+    // The lowest bit and the value of the window of each exponent which is being processed
+    let mut window_low = [0u32; N];
+    let mut window_value: [Word; N] = [0; N];
+    let mut max_bits = 0;
+
+    let mut i = 0;
+    while i < N {
+        let (base, exponent) = &bases_and_exponents[i];
+        odd_powers[i] = compute_odd_powers(base, modulus, mod_neg_inv);
+        unscanned[i] = exponent.bits_vartime();
+        if unscanned[i] > max_bits {
+            max_bits = unscanned[i];
+        }
+        i += 1;
+    }
+
+    let mut z = *r; // 1 in Montgomery form
+    let mut z_is_one = true;
+
+    let mut bit = max_bits;
+    while bit > 0 {
+        bit -= 1;
+
+        if !z_is_one {
+            z = square_montgomery_form(&z, modulus, mod_neg_inv);
+        }
+
+        let mut i = 0;
+        while i < N {
+            if unscanned[i] == bit + 1 {
+                let exponent = bases_and_exponents[i].1.as_limbs();
+                if limbs_bit_vartime(exponent, bit) {
+                    let (low, value) = sliding_window_vartime(exponent, bit);
+                    window_low[i] = low;
+                    window_value[i] = value;
+                    unscanned[i] = low;
+                } else {
+                    unscanned[i] = bit;
+                }
+            }
+
+            // The window is multiplied in at its lowest bit, which is set.
+            if window_value[i] != 0 && window_low[i] == bit {
+                let power = &odd_powers[i][(window_value[i] >> 1) as usize];
+                z = if z_is_one {
+                    *power
+                } else {
+                    mul_montgomery_form(&z, power, modulus, mod_neg_inv)
+                };
+                z_is_one = false;
+                window_value[i] = 0;
+            }
+
+            i += 1;
+        }
+    }
+
+    z
+}
+
+/// Computes the odd powers `x, x^3, ..., x^(2 * VARTIME_POWERS - 1)` in Montgomery form.
+const fn compute_odd_powers<const LIMBS: usize>(
+    x: &Uint<LIMBS>,
+    modulus: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> [Uint<LIMBS>; VARTIME_POWERS] {
+    let x2 = square_montgomery_form(x, modulus, mod_neg_inv);
+    let mut powers = [*x; VARTIME_POWERS];
+
+    let mut i = 1;
+    while i < VARTIME_POWERS {
+        powers[i] = mul_montgomery_form(&powers[i - 1], &x2, modulus, mod_neg_inv);
+        i += 1;
+    }
+
+    powers
+}
+
+/// Returns whether the bit at position `index` of the integer with the given limbs is set.
+pub(crate) const fn limbs_bit_vartime(limbs: &[Limb], index: u32) -> bool {
+    (limbs[(index / Limb::BITS) as usize].0 >> (index % Limb::BITS)) & 1 == 1
+}
+
+/// Returns the lowest bit and the value of the sliding window of the exponent with the given
+/// limbs whose highest bit is `top`, which must be set. The window is at most [`VARTIME_WINDOW`]
+/// bits long and its lowest bit is set as well, so the value is odd.
+pub(crate) const fn sliding_window_vartime(exponent: &[Limb], top: u32) -> (u32, Word) {
+    let mut low = (top + 1).saturating_sub(VARTIME_WINDOW);
+    while !limbs_bit_vartime(exponent, low) {
+        low += 1;
+    }
+
+    let mut value = 0;
+    let mut bit = top + 1;
+    while bit > low {
+        bit -= 1;
+        value = (value << 1) | limbs_bit_vartime(exponent, bit) as Word;
+    }
+
+    (low, value)
+}
@@ -0,0 +1,244 @@
+//! Support for implementing the [`ff`] traits for prime fields.
+//!
+//! See [`impl_field_element!`][`crate::impl_field_element`].
+
+use crate::{Limb, Uint};
+
+/// Big endian byte representation of a prime field element, used as [`ff::PrimeField::Repr`].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct PrimeFieldRepr<const BYTES: usize>(pub [u8; BYTES]);
+
+impl<const BYTES: usize> PrimeFieldRepr<BYTES> {
+    /// Encode the given integer as big endian bytes.
+    ///
+    /// Panics if `BYTES` does not match the size of `Uint<LIMBS>`.
+    pub const fn from_uint<const LIMBS: usize>(uint: &Uint<LIMBS>) -> Self {
+        assert!(BYTES == Uint::<LIMBS>::BYTES, "size mismatch");
+
+        let mut bytes = [0u8; BYTES];
+        let mut i = 0;
+
+        while i < LIMBS {
+            let limb_bytes = uint.as_limbs()[LIMBS - 1 - i].0.to_be_bytes();
+            let mut j = 0;
+
+            while j < Limb::BYTES {
+                bytes[i * Limb::BYTES + j] = limb_bytes[j];
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        Self(bytes)
+    }
+
+    /// Decode the big endian bytes as an integer.
+    ///
+    /// Panics if `BYTES` does not match the size of `Uint<LIMBS>`.
+    pub const fn to_uint<const LIMBS: usize>(&self) -> Uint<LIMBS> {
+        Uint::from_be_slice(&self.0)
+    }
+}
+
+impl<const BYTES: usize> Default for PrimeFieldRepr<BYTES> {
+    fn default() -> Self {
+        Self([0u8; BYTES])
+    }
+}
+
+impl<const BYTES: usize> AsRef<[u8]> for PrimeFieldRepr<BYTES> {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl<const BYTES: usize> AsMut<[u8]> for PrimeFieldRepr<BYTES> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl<const BYTES: usize> From<[u8; BYTES]> for PrimeFieldRepr<BYTES> {
+    fn from(bytes: [u8; BYTES]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl<const BYTES: usize> From<PrimeFieldRepr<BYTES>> for [u8; BYTES] {
+    fn from(repr: PrimeFieldRepr<BYTES>) -> [u8; BYTES] {
+        repr.0
+    }
+}
+
+/// Convert an integer into little endian `u64` words, as expected by [`ff::Field::pow`].
+///
+/// `N` must be large enough to hold the integer.
+#[doc(hidden)]
+#[allow(trivial_numeric_casts, clippy::unnecessary_cast)]
+pub const fn to_u64_words<const LIMBS: usize, const N: usize>(uint: &Uint<LIMBS>) -> [u64; N] {
+    assert!(N * 8 >= Uint::<LIMBS>::BYTES, "not enough words");
+
+    let mut words = [0u64; N];
+    let mut i = 0;
+
+    while i < LIMBS {
+        let bit = i * Limb::BITS as usize;
+        words[bit / 64] |= (uint.as_limbs()[i].0 as u64) << (bit % 64);
+        i += 1;
+    }
+
+    words
+}
+
+/// Encode an integer as `0x` followed by its full width lowercase big endian hexadecimal digits,
+/// as used for [`ff::PrimeField::MODULUS`].
+///
+/// `N` must be exactly `2 + 2 * Uint::<LIMBS>::BYTES`.
+#[doc(hidden)]
+pub const fn to_hex_string<const LIMBS: usize, const N: usize>(uint: &Uint<LIMBS>) -> [u8; N] {
+    assert!(N == 2 + 2 * Uint::<LIMBS>::BYTES, "size mismatch");
+
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut hex = [0u8; N];
+    hex[0] = b'0';
+    hex[1] = b'x';
+
+    let mut i = 0;
+
+    while i < LIMBS {
+        let limb_bytes = uint.as_limbs()[LIMBS - 1 - i].0.to_be_bytes();
+        let mut j = 0;
+
+        while j < Limb::BYTES {
+            let pos = 2 + 2 * (i * Limb::BYTES + j);
+            hex[pos] = DIGITS[(limb_bytes[j] >> 4) as usize];
+            hex[pos + 1] = DIGITS[(limb_bytes[j] & 0xf) as usize];
+            j += 1;
+        }
+
+        i += 1;
+    }
+
+    hex
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{to_hex_string, to_u64_words, PrimeFieldRepr};
+    use crate::{
+        modular::{GeneratorParams, Residue, ResidueParams},
+        U128, U256, U64,
+    };
+    use ff::{Field, PrimeField};
+    use hex_literal::hex;
+    use rand_core::OsRng;
+
+    // BLS12-381 scalar field. The generator is checked by the tests of the `prime` module, as
+    // checking it at compile time with `impl_multiplicative_generator!` takes a while.
+    crate::impl_prime_modulus!(
+        ScalarModulus,
+        U256,
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+    impl GeneratorParams<{ U256::LIMBS }> for ScalarModulus {
+        const GENERATOR: Residue<Self, { U256::LIMBS }> = Residue::new(&U256::from_u8(7));
+    }
+    crate::impl_field_element!(Scalar, ScalarModulus, U256);
+
+    // Goldilocks field, with a modulus which is not given as a hex string
+    crate::impl_prime_modulus!(GoldilocksModulus, U64, 2 ^ 64 - 2 ^ 32 + 1);
+    crate::impl_multiplicative_generator!(
+        GoldilocksModulus,
+        U64,
+        7,
+        ["2", "3", "5", "11", "101", "10001"]
+    );
+    crate::impl_field_element!(Goldilocks, GoldilocksModulus, U64);
+
+    #[test]
+    fn repr_round_trip() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        let repr = PrimeFieldRepr::<16>::from_uint(&n);
+        assert_eq!(repr.0, hex!("00112233445566778899aabbccddeeff"));
+        assert_eq!(repr.to_uint(), n);
+    }
+
+    #[test]
+    fn u64_words() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        assert_eq!(
+            to_u64_words::<{ U128::LIMBS }, 2>(&n),
+            [0x8899aabbccddeeff, 0x0011223344556677]
+        );
+    }
+
+    #[test]
+    fn hex_string() {
+        let n = U128::from_be_hex("00112233445566778899aabbccddeeff");
+        assert_eq!(
+            &to_hex_string::<{ U128::LIMBS }, 34>(&n),
+            b"0x00112233445566778899aabbccddeeff"
+        );
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(
+            Scalar::MODULUS,
+            "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+        );
+        assert_eq!(Goldilocks::MODULUS, "0xffffffff00000001");
+        assert_eq!(Goldilocks::S, 32);
+        assert_eq!(
+            Goldilocks::ROOT_OF_UNITY.pow_vartime([1u64 << (Goldilocks::S - 1)]),
+            -Goldilocks::ONE
+        );
+
+        assert_eq!(Scalar::NUM_BITS, 255);
+        assert_eq!(Scalar::CAPACITY, 254);
+        assert_eq!(Scalar::S, 32);
+        assert_eq!(Scalar::TWO_INV.double(), Scalar::ONE);
+        assert_eq!(
+            Scalar::ROOT_OF_UNITY * Scalar::ROOT_OF_UNITY_INV,
+            Scalar::ONE
+        );
+        assert_eq!(
+            Scalar::ROOT_OF_UNITY.pow_vartime([1u64 << Scalar::S]),
+            Scalar::ONE
+        );
+        assert_ne!(
+            Scalar::ROOT_OF_UNITY.pow_vartime([1u64 << (Scalar::S - 1)]),
+            Scalar::ONE
+        );
+        assert_eq!(
+            Scalar::DELTA,
+            Scalar::MULTIPLICATIVE_GENERATOR.pow_vartime([1u64 << Scalar::S])
+        );
+    }
+
+    #[test]
+    fn sqrt() {
+        for _ in 0..10 {
+            let x = Scalar::random(&mut OsRng);
+            let root = x.square().sqrt().unwrap();
+            assert!(root == x || root == -x);
+        }
+
+        // 7 is a generator, hence a non-residue
+        assert!(bool::from(
+            Scalar::MULTIPLICATIVE_GENERATOR.sqrt().is_none()
+        ));
+    }
+
+    #[test]
+    fn from_repr() {
+        let x = Scalar::random(&mut OsRng);
+        assert_eq!(Scalar::from_repr(x.to_repr()).unwrap(), x);
+        assert_eq!(Scalar::from(5u64).to_repr().0[31], 5);
+        assert!(bool::from(Scalar::from(5u64).is_odd()));
+
+        let modulus = PrimeFieldRepr::from_uint(&ScalarModulus::MODULUS);
+        assert!(bool::from(Scalar::from_repr(modulus).is_none()));
+    }
+}
@@ -1,9 +1,10 @@
 //! Modular reduction implementation.
 
-use crate::{Limb, Uint, WideWord, Word};
+use crate::{BoundedUint, Limb, Uint, WideWord, Word};
+use subtle::Choice;
 
 #[cfg(feature = "alloc")]
-use {crate::BoxedUint, subtle::Choice};
+use crate::BoxedUint;
 
 /// Returns `(hi, lo)` such that `hi * R + lo = x * y + z + w`.
 #[inline(always)]
@@ -70,6 +71,69 @@ pub const fn montgomery_reduction<const LIMBS: usize>(
     upper.sub_mod_with_carry(meta_carry, modulus, modulus)
 }
 
+/// Computes the Montgomery form `x * R mod modulus` of the double-width integer
+/// `x = lower + upper * R` in constant time.
+///
+/// Since `x * R = lower * R + upper * R^2`, this is the sum of two Montgomery reductions
+/// of `lower * R^2` and `upper * R^3`, both of which are smaller than `modulus * R`.
+pub(crate) const fn montgomery_form_wide<const LIMBS: usize>(
+    lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    r2: &Uint<LIMBS>,
+    r3: &Uint<LIMBS>,
+    mod_neg_inv: Limb,
+) -> Uint<LIMBS> {
+    let (lower, upper) = lower_upper;
+    let lower = montgomery_reduction(&lower.mul_wide(r2), modulus, mod_neg_inv);
+    let upper = montgomery_reduction(&upper.mul_wide(r3), modulus, mod_neg_inv);
+    lower.add_mod(&upper, modulus)
+}
+
+/// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
+///
+/// This version reduces a double-width [`BoundedUint`] given by its two halves, each with the
+/// precision of the modulus.
+pub(crate) fn montgomery_reduction_bounded<const MAX_LIMBS: usize>(
+    lower_upper: &(BoundedUint<MAX_LIMBS>, BoundedUint<MAX_LIMBS>),
+    modulus: &BoundedUint<MAX_LIMBS>,
+    mod_neg_inv: Limb,
+) -> BoundedUint<MAX_LIMBS> {
+    let (mut lower, mut upper) = *lower_upper;
+    debug_assert_eq!(lower.nlimbs(), modulus.nlimbs());
+    debug_assert_eq!(upper.nlimbs(), modulus.nlimbs());
+
+    let meta_carry = impl_montgomery_reduction!(
+        upper.limbs,
+        lower.limbs,
+        &modulus.limbs,
+        mod_neg_inv,
+        modulus.nlimbs()
+    );
+
+    // Division is simply taking the upper half of the limbs
+    // Final reduction (at this point, the value is at most 2 * modulus)
+    let borrow = upper.sbb_assign(modulus, Limb::ZERO);
+
+    // The new `borrow = Word::MAX` iff `carry == 0` and `borrow == Word::MAX`.
+    let borrow = Limb((!meta_carry.0.wrapping_neg()) & borrow.0);
+
+    // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+    // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+    upper.conditional_adc_assign(modulus, Choice::from((borrow.0 & 1) as u8));
+    upper
+}
+
+/// Panics unless `len` bytes of uniformly random input are enough to reduce them modulo a
+/// `modulus_bits`-bit modulus with a bias of at most `2^-128`, i.e. unless `len` is at least
+/// `ceil((modulus_bits + 128) / 8)`, as in RFC 9380 (section 5) with the security parameter
+/// `k = 128`.
+pub(crate) fn assert_uniform_bytes_len(len: usize, modulus_bits: u32) {
+    assert!(
+        len >= (modulus_bits as usize + 128 + 7) / 8,
+        "not enough uniform bytes for the modulus"
+    );
+}
+
 /// Algorithm 14.32 in Handbook of Applied Cryptography <https://cacr.uwaterloo.ca/hac/about/chap14.pdf>
 ///
 /// This version writes the result into the provided [`BoxedUint`].
@@ -112,3 +176,27 @@ pub(crate) fn montgomery_reduction_boxed(
     montgomery_reduction_boxed_mut(x, modulus, mod_neg_inv, &mut ret);
     ret
 }
+
+/// Computes the Montgomery form `x * R mod modulus` of the double-width integer `x`.
+///
+/// See [`montgomery_form_wide`] for details.
+#[cfg(feature = "alloc")]
+pub(crate) fn montgomery_form_wide_boxed(
+    x: &BoxedUint,
+    modulus: &BoxedUint,
+    r2: &BoxedUint,
+    r3: &BoxedUint,
+    mod_neg_inv: Limb,
+) -> BoxedUint {
+    debug_assert_eq!(x.nlimbs(), modulus.nlimbs() * 2);
+
+    let (lower, upper) = x.limbs.split_at(modulus.nlimbs());
+    let lower = BoxedUint::from(lower);
+    let upper = BoxedUint::from(upper);
+
+    let mut product = lower.mul(r2);
+    let lower = montgomery_reduction_boxed(&mut product, modulus, mod_neg_inv);
+    let mut product = upper.mul(r3);
+    let upper = montgomery_reduction_boxed(&mut product, modulus, mod_neg_inv);
+    lower.add_mod(&upper, modulus)
+}
@@ -1,14 +1,28 @@
 //! Implements `Residue`s, supporting modular arithmetic with a constant modulus.
 
+mod accumulator;
 mod add;
 mod inv;
 mod mul;
 mod neg;
 mod pow;
+mod prime;
 mod sub;
 
-use super::{div_by_2::div_by_2, reduction::montgomery_reduction, Retrieve};
-use crate::{Limb, Uint, ZeroConstant};
+use super::{
+    div_by_2::div_by_2,
+    reduction::{assert_uniform_bytes_len, montgomery_form_wide, montgomery_reduction},
+    Retrieve,
+};
+
+pub use self::{
+    accumulator::ResidueAccumulator,
+    prime::{is_generator_vartime, is_prime_vartime, GeneratorParams, PrimeParams},
+};
+use crate::{
+    traits::{ct_lookup_selectable, ct_select_slice_selectable},
+    ConstantTimeLookup, Limb, Uint, ZeroConstant,
+};
 use core::{fmt::Debug, marker::PhantomData};
 use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
 
@@ -48,6 +62,29 @@ pub trait ResidueParams<const LIMBS: usize>:
     /// The lowest limbs of -(MODULUS^-1) mod R
     // We only need the LSB because during reduction this value is multiplied modulo 2**Limb::BITS.
     const MOD_NEG_INV: Limb;
+
+    /// The bit length of the modulus
+    const MODULUS_BITS: u32 = Self::MODULUS.bits();
+    /// `MODULUS - 2`, the exponent used to invert via Fermat's little theorem for a prime modulus
+    ///
+    /// Using this constant panics at compile time if the modulus is smaller than 3.
+    const MODULUS_MINUS_TWO: Uint<LIMBS> = {
+        if Self::MODULUS_BITS < 2 {
+            panic!("modulus must be at least 3");
+        }
+        Self::MODULUS.wrapping_sub(&Uint::from_u8(2))
+    };
+    /// `(MODULUS + 1) / 4`, the exponent used to compute square roots for a prime modulus
+    /// congruent to 3 mod 4
+    ///
+    /// Using this constant panics at compile time if the modulus is not congruent to 3 mod 4.
+    const MODULUS_PLUS_ONE_DIV_FOUR: Uint<LIMBS> = {
+        if Self::MODULUS.as_limbs()[0].0 & 3 != 3 {
+            panic!("modulus must be congruent to 3 mod 4");
+        }
+        // `MODULUS + 1` may overflow, but `(MODULUS + 1) / 4 = (MODULUS >> 2) + 1` here.
+        Self::MODULUS.shr_vartime(2).wrapping_add(&Uint::ONE)
+    };
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -121,6 +158,42 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
         )
     }
 
+    /// Instantiates a new [`Residue`] by reducing the integer encoded by the given big endian
+    /// bytes mod `MOD`, e.g. the output of `expand_message` in RFC 9380's `hash_to_field`.
+    ///
+    /// The bytes can be at most twice as long as [`Uint::BYTES`], and shorter inputs are implicitly
+    /// padded with leading zeros. This function is constant-time with respect to the value of the
+    /// bytes.
+    ///
+    /// Panics if there are too many bytes, or if the modulus represented by `MOD` is not odd.
+    pub const fn reduce_wide_bytes(bytes: &[u8]) -> Self {
+        // A valid modulus must be odd
+        if MOD::MODULUS.ct_is_odd().to_u8() == 0 {
+            panic!("modulus must be odd");
+        }
+
+        let wide = Uint::from_be_slice_wide(bytes);
+        Self::from_montgomery(montgomery_form_wide(
+            &wide,
+            &MOD::MODULUS,
+            &MOD::R2,
+            &MOD::R3,
+            MOD::MOD_NEG_INV,
+        ))
+    }
+
+    /// Instantiates a new [`Residue`] from uniformly random big endian bytes, e.g. hash or XOF
+    /// output, such that the result is uniformly distributed mod `MOD` up to a bias of at most
+    /// `2^-128`.
+    ///
+    /// Panics if there are fewer than `ceil((MOD::MODULUS_BITS + 128) / 8)` bytes (the length
+    /// `L` from RFC 9380 with `k = 128`), and otherwise behaves like
+    /// [`reduce_wide_bytes`][`Residue::reduce_wide_bytes`].
+    pub fn from_uniform_bytes(bytes: &[u8]) -> Self {
+        assert_uniform_bytes_len(bytes.len(), MOD::MODULUS_BITS);
+        Self::reduce_wide_bytes(bytes)
+    }
+
     /// Retrieves the integer currently encoded in this [`Residue`], guaranteed to be reduced.
     pub const fn retrieve(&self) -> Uint<LIMBS> {
         montgomery_reduction::<LIMBS>(
@@ -158,7 +231,7 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
     /// - if `x` is even, returns `x / 2`,
     /// - if `x` is odd, returns `(x + p) / 2`
     ///   (since the modulus `p` in Montgomery form is always odd, this divides entirely).
-    pub fn div_by_2(&self) -> Self {
+    pub const fn div_by_2(&self) -> Self {
         Self {
             montgomery_form: div_by_2(&self.montgomery_form, &MOD::MODULUS),
             phantom: PhantomData,
@@ -181,6 +254,18 @@ impl<MOD: ResidueParams<LIMBS> + Copy, const LIMBS: usize> ConditionallySelectab
     }
 }
 
+impl<MOD: ResidueParams<LIMBS> + Copy, const LIMBS: usize> ConstantTimeLookup
+    for Residue<MOD, LIMBS>
+{
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_selectable(table, index)
+    }
+
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_selectable(out, a, b, choice)
+    }
+}
+
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> ConstantTimeEq for Residue<MOD, LIMBS> {
     fn ct_eq(&self, other: &Self) -> Choice {
         ConstantTimeEq::ct_eq(&self.montgomery_form, &other.montgomery_form)
@@ -0,0 +1,116 @@
+//! Lazily reduced sums of products of residues with a constant modulus.
+
+use super::{Residue, ResidueParams};
+use crate::{
+    modular::{mul::mul_accumulate_montgomery_form, reduction::montgomery_reduction},
+    Uint,
+};
+use core::marker::PhantomData;
+
+/// An unreduced, double-width sum of products of [`Residue`]s.
+///
+/// Each product is added without being reduced, and a single Montgomery reduction is performed by
+/// [`ResidueAccumulator::reduce`], which makes computing sums of products such as inner products
+/// cheaper than adding up the results of [`Residue::mul`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ResidueAccumulator<MOD: ResidueParams<LIMBS>, const LIMBS: usize> {
+    /// Lower and upper halves of the sum, the latter being kept below the modulus.
+    lower_upper: (Uint<LIMBS>, Uint<LIMBS>),
+    phantom: PhantomData<MOD>,
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> ResidueAccumulator<MOD, LIMBS> {
+    /// An empty sum.
+    pub const ZERO: Self = Self {
+        lower_upper: (Uint::ZERO, Uint::ZERO),
+        phantom: PhantomData,
+    };
+
+    /// Returns the sum with the product `a * b` added to it.
+    pub const fn mul_add(&self, a: &Residue<MOD, LIMBS>, b: &Residue<MOD, LIMBS>) -> Self {
+        Self {
+            lower_upper: mul_accumulate_montgomery_form(
+                &self.lower_upper,
+                &a.montgomery_form,
+                &b.montgomery_form,
+                &MOD::MODULUS,
+            ),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Adds the product `a * b` to the sum.
+    pub fn mul_add_assign(&mut self, a: &Residue<MOD, LIMBS>, b: &Residue<MOD, LIMBS>) {
+        *self = self.mul_add(a, b);
+    }
+
+    /// Reduces the sum to a [`Residue`].
+    pub const fn reduce(&self) -> Residue<MOD, LIMBS> {
+        Residue {
+            montgomery_form: montgomery_reduction(
+                &self.lower_upper,
+                &MOD::MODULUS,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
+    /// Computes the sum of products `a_1 * b_1 + ... + a_n * b_n` with a single Montgomery
+    /// reduction, see [`ResidueAccumulator`].
+    pub const fn sum_of_products(pairs: &[(Self, Self)]) -> Self {
+        let mut acc = ResidueAccumulator::ZERO;
+        let mut i = 0;
+
+        while i < pairs.len() {
+            acc = acc.mul_add(&pairs[i].0, &pairs[i].1);
+            i += 1;
+        }
+
+        acc.reduce()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> zeroize::DefaultIsZeroes
+    for ResidueAccumulator<MOD, LIMBS>
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ResidueAccumulator;
+    use crate::{impl_modulus, modular::residue::Residue, U256};
+
+    impl_modulus!(
+        Modulus,
+        U256,
+        "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551"
+    );
+
+    type R = Residue<Modulus, { U256::LIMBS }>;
+
+    #[test]
+    fn sum_of_products() {
+        let x = R::new(&U256::from_be_hex(
+            "44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56",
+        ));
+        let y = R::new(&U256::from_be_hex(
+            "d5777c45019673125ad240f83094d4252d829516fac8601ed01979ec1ec1a251",
+        ));
+        let minus_one = -R::ONE;
+
+        // Enough products of large residues to overflow a naive double-width sum
+        let products = [(x, y), (y, y), (minus_one, minus_one), (x, x)];
+        let pairs: [_; 32] = core::array::from_fn(|i| products[i % products.len()]);
+        let expected = pairs.iter().fold(R::ZERO, |acc, (a, b)| acc + a * b);
+        assert_eq!(R::sum_of_products(&pairs), expected);
+
+        let mut acc = ResidueAccumulator::ZERO;
+        assert_eq!(acc.reduce(), R::ZERO);
+        acc.mul_add_assign(&x, &y);
+        assert_eq!(acc.reduce(), x * y);
+    }
+}
@@ -1,7 +1,7 @@
 //! Additions between residues with a constant modulus.
 
 use super::{Residue, ResidueParams};
-use crate::modular::add::add_montgomery_form;
+use crate::modular::add::{add_montgomery_form, double_montgomery_form};
 use core::ops::{Add, AddAssign};
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
@@ -16,6 +16,19 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             phantom: core::marker::PhantomData,
         }
     }
+
+    /// Computes `2 * self`.
+    pub const fn double(&self) -> Self {
+        Self {
+            montgomery_form: double_montgomery_form(&self.montgomery_form, &MOD::MODULUS),
+            phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Computes `3 * self`.
+    pub const fn triple(&self) -> Self {
+        Self::add(&self.double(), self)
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Add<&Residue<MOD, LIMBS>>
@@ -95,4 +108,18 @@ mod tests {
 
         assert_eq!(expected, x_mod.retrieve());
     }
+
+    #[test]
+    fn double_and_triple() {
+        let x =
+            U256::from_be_hex("44acf6b7e36c1342c2c5897204fe09504e1e2efb1a900377dbc4e7a6a133ec56");
+        let x_mod = const_residue!(x, Modulus);
+
+        assert_eq!(x_mod.double(), x_mod + x_mod);
+        assert_eq!(x_mod.triple(), x_mod + x_mod + x_mod);
+        assert_eq!(x_mod.mul_by_small(0), x_mod - x_mod);
+        assert_eq!(x_mod.mul_by_small(1), x_mod);
+        assert_eq!(x_mod.mul_by_small(3), x_mod.triple());
+        assert_eq!(x_mod.double().div_by_2(), x_mod);
+    }
 }
@@ -1,16 +1,23 @@
 //! Multiplicative inverses of residues with a constant modulus.
 
 use super::{Residue, ResidueParams};
-use crate::{modular::inv::inv_montgomery_form, traits::Invert, CtChoice, NonZero};
+use crate::{
+    modular::inv::{batch_invert_in_place, inv_montgomery_form},
+    traits::{BatchInvert, Invert},
+    ConstCtOption, NonZero,
+};
 use core::marker::PhantomData;
 use subtle::CtOption;
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
     /// Computes the residue `self^-1` representing the multiplicative inverse of `self`.
     /// I.e. `self * self^-1 = 1`.
-    /// If the number was invertible, the second element of the tuple is the truthy value,
-    /// otherwise it is the falsy value (in which case the first element's value is unspecified).
-    pub const fn invert(&self) -> (Self, CtChoice) {
+    /// The result is none if the number was not invertible,
+    /// in which case the contained value is unspecified.
+    pub const fn invert(&self) -> ConstCtOption<Self> {
         let (montgomery_form, is_some) = inv_montgomery_form(
             &self.montgomery_form,
             &MOD::MODULUS,
@@ -23,15 +30,14 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             phantom: PhantomData,
         };
 
-        (value, is_some)
+        ConstCtOption::new(value, is_some)
     }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Invert for Residue<MOD, LIMBS> {
     type Output = CtOption<Self>;
     fn invert(&self) -> Self::Output {
-        let (value, is_some) = self.invert();
-        CtOption::new(value, is_some.into())
+        self.invert().into()
     }
 }
 
@@ -39,14 +45,51 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Invert for NonZero<Residue<M
     type Output = Self;
     fn invert(&self) -> Self::Output {
         // Always succeeds for a non-zero argument
-        let (value, _is_some) = self.as_ref().invert();
-        NonZero::new(value).unwrap()
+        let value = self.as_ref().invert();
+        NonZero::new(*value.components_ref().0).unwrap()
+    }
+}
+
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize, const N: usize> BatchInvert<[Self; N]>
+    for Residue<MOD, LIMBS>
+{
+    type Output = CtOption<[Self; N]>;
+
+    fn batch_invert(elements: &[Self; N]) -> Self::Output {
+        let mut inverses = *elements;
+        let mut scratch = [Self::ONE; N];
+        let is_some = batch_invert_in_place(
+            &mut inverses,
+            &mut scratch,
+            &Self::ZERO,
+            &Self::ONE,
+            Self::invert,
+        );
+        CtOption::new(inverses, is_some)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> BatchInvert<[Self]> for Residue<MOD, LIMBS> {
+    type Output = CtOption<Vec<Self>>;
+
+    fn batch_invert(elements: &[Self]) -> Self::Output {
+        let mut inverses = elements.to_vec();
+        let mut scratch = vec![Self::ONE; elements.len()];
+        let is_some = batch_invert_in_place(
+            &mut inverses,
+            &mut scratch,
+            &Self::ZERO,
+            &Self::ONE,
+            Self::invert,
+        );
+        CtOption::new(inverses, is_some)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{const_residue, impl_modulus, modular::residue::ResidueParams, U256};
+    use crate::{const_residue, impl_modulus, modular::residue::ResidueParams, BatchInvert, U256};
 
     impl_modulus!(
         Modulus,
@@ -60,9 +103,32 @@ mod tests {
             U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
         let x_mod = const_residue!(x, Modulus);
 
-        let (inv, _is_some) = x_mod.invert();
+        let inv = x_mod.invert().unwrap();
         let res = x_mod * inv;
 
         assert_eq!(res.retrieve(), U256::ONE);
     }
+
+    #[test]
+    fn test_batch_invert() {
+        let x =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+        let x_mod = const_residue!(x, Modulus);
+        let y_mod = x_mod.square();
+        let zero = U256::ZERO;
+        let zero_mod = const_residue!(zero, Modulus);
+
+        let elements = [x_mod, zero_mod, y_mod];
+        let inverses = super::Residue::batch_invert(&elements).unwrap();
+
+        assert_eq!(inverses[0], x_mod.invert().unwrap());
+        assert_eq!(inverses[1], zero_mod);
+        assert_eq!(inverses[2], y_mod.invert().unwrap());
+
+        #[cfg(feature = "alloc")]
+        assert_eq!(
+            super::Residue::batch_invert(&elements[..]).unwrap(),
+            inverses.to_vec()
+        );
+    }
 }
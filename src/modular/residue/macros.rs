@@ -7,12 +7,22 @@
 /// `impl_modulus!(MyModulus, U256, "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001");`
 /// implements a 256-bit modulus named `MyModulus`.
 ///
-/// The modulus _must_ be odd, or this will panic.
+/// The value can be given in any of the following forms:
+/// - a big endian hex string, which may be shorter than the integer and is then padded with
+///   leading zeros: `impl_modulus!(MyModulus, U64, "30e4b8f030ab42f3");`
+/// - a decimal string, prefixed with `dec`: `impl_modulus!(MyModulus, U64, dec "3523144150258762483");`
+/// - a sum of signed powers of two and integer literals, starting with a power of two:
+///   `impl_modulus!(Curve25519, U256, 2^255 - 19);`
+///
+/// Besides the Montgomery parameters, the modulus gets the extra constants provided by
+/// [`ResidueParams`][`crate::modular::ResidueParams`], such as its bit length.
+///
+/// The modulus _must_ be odd and fit in the given type, or this will panic.
 // TODO: Use `adt_const_params` once stabilized to make a `Residue` generic around a modulus rather
 // than having to implement a ZST + trait
 #[macro_export]
 macro_rules! impl_modulus {
-    ($name:ident, $uint_type:ty, $value:expr) => {
+    (@impl $name:ident, $uint_type:ty, $value:expr) => {
         #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
         pub struct $name {}
         impl<const DLIMBS: usize> $crate::modular::ResidueParams<{ <$uint_type>::LIMBS }> for $name
@@ -21,7 +31,7 @@ macro_rules! impl_modulus {
         {
             const LIMBS: usize = <$uint_type>::LIMBS;
             const MODULUS: $uint_type = {
-                let res = <$uint_type>::from_be_hex($value);
+                let res: $uint_type = $value;
 
                 // Check that the modulus is odd
                 if res.as_limbs()[0].0 & 1 == 0 {
@@ -32,14 +42,15 @@ macro_rules! impl_modulus {
             };
             const R: $uint_type = $crate::Uint::MAX
                 .const_rem(&Self::MODULUS)
-                .0
+                .unwrap()
                 .wrapping_add(&$crate::Uint::ONE);
             const R2: $uint_type =
-                $crate::Uint::const_rem_wide(Self::R.square_wide(), &Self::MODULUS).0;
+                $crate::Uint::const_rem_wide(Self::R.square_wide(), &Self::MODULUS).unwrap();
             const MOD_NEG_INV: $crate::Limb = $crate::Limb(
                 $crate::Word::MIN.wrapping_sub(
-                    Self::MODULUS
+                    $crate::Public::new(Self::MODULUS)
                         .inv_mod2k_vartime($crate::Word::BITS)
+                        .as_inner()
                         .as_limbs()[0]
                         .0,
                 ),
@@ -51,6 +62,119 @@ macro_rules! impl_modulus {
             );
         }
     };
+    // Powers of two are computed in double width, so that e.g. `2^256 - 2^224 + ...` fits.
+    (@pow $exponent:literal) => {{
+        let exponent: u32 = $exponent;
+        if exponent >= $crate::Uint::<DLIMBS>::BITS {
+            panic!("exponent is too large");
+        }
+        *$crate::Public::new($crate::Uint::<DLIMBS>::ONE)
+            .shl_vartime(exponent)
+            .as_inner()
+    }};
+    (@expr $acc:expr;) => {
+        $acc
+    };
+    (@expr $acc:expr; + 2 ^ $exponent:literal $($rest:tt)*) => {
+        $crate::impl_modulus!(
+            @expr $acc.wrapping_add(&$crate::impl_modulus!(@pow $exponent)); $($rest)*
+        )
+    };
+    (@expr $acc:expr; - 2 ^ $exponent:literal $($rest:tt)*) => {
+        $crate::impl_modulus!(
+            @expr $acc.wrapping_sub(&$crate::impl_modulus!(@pow $exponent)); $($rest)*
+        )
+    };
+    (@expr $acc:expr; + $value:literal $($rest:tt)*) => {
+        $crate::impl_modulus!(
+            @expr $acc.wrapping_add(&$crate::Uint::<DLIMBS>::from_u128($value)); $($rest)*
+        )
+    };
+    (@expr $acc:expr; - $value:literal $($rest:tt)*) => {
+        $crate::impl_modulus!(
+            @expr $acc.wrapping_sub(&$crate::Uint::<DLIMBS>::from_u128($value)); $($rest)*
+        )
+    };
+    ($name:ident, $uint_type:ty, dec $value:literal) => {
+        $crate::impl_modulus!(
+            @impl $name, $uint_type, <$uint_type>::const_from_str_radix($value, 10)
+        );
+    };
+    ($name:ident, $uint_type:ty, 2 ^ $exponent:literal $($rest:tt)*) => {
+        $crate::impl_modulus!(@impl $name, $uint_type, {
+            let wide = $crate::impl_modulus!(@expr $crate::impl_modulus!(@pow $exponent); $($rest)*);
+
+            // A negative value wraps around, so this also checks that the value is positive
+            if wide.bits() > <$uint_type>::BITS {
+                panic!("modulus does not fit in the integer");
+            }
+
+            wide.resize::<{ <$uint_type>::LIMBS }>()
+        });
+    };
+    ($name:ident, $uint_type:ty, $value:expr) => {
+        $crate::impl_modulus!(
+            @impl $name, $uint_type, <$uint_type>::const_from_str_radix($value, 16)
+        );
+    };
+}
+
+/// Implements a prime modulus with the given name, type, and value, in that specific order, like
+/// [`impl_modulus!`][`crate::impl_modulus`] (which accepts the same forms of values) does, and
+/// additionally implements [`PrimeParams`][`crate::modular::PrimeParams`] for it.
+///
+/// For example,
+/// `impl_prime_modulus!(P256, U256, 2^256 - 2^224 + 2^192 + 2^96 - 1);`
+/// implements the prime modulus of the P-256 base field named `P256`.
+///
+/// The modulus is checked for primality with
+/// [`is_prime_vartime`][`crate::modular::is_prime_vartime`] at compile time, so the build fails
+/// if it is composite, as well as if it is even.
+#[macro_export]
+macro_rules! impl_prime_modulus {
+    ($name:ident, $uint_type:ty, $($value:tt)+) => {
+        $crate::impl_modulus!($name, $uint_type, $($value)+);
+        impl $crate::modular::PrimeParams<{ <$uint_type>::LIMBS }> for $name {}
+
+        // The primality check can take a while for large moduli
+        #[allow(unknown_lints, long_running_const_eval)]
+        const _: () = {
+            if !$crate::modular::is_prime_vartime::<$name, { <$uint_type>::LIMBS }>() {
+                panic!("modulus must be prime");
+            }
+        };
+    };
+}
+
+/// Implements [`GeneratorParams`][`crate::modular::GeneratorParams`] for a prime modulus defined
+/// with [`impl_prime_modulus!`][`crate::impl_prime_modulus`], given the modulus name and type, the
+/// generator as a `u64`, and the distinct prime factors of `MODULUS - 1` as hexadecimal strings.
+///
+/// For example,
+/// `impl_multiplicative_generator!(Goldilocks, U64, 7, ["2", "3", "5", "11", "101", "10001"]);`
+/// declares 7 as the generator of the multiplicative group modulo `2^64 - 2^32 + 1`.
+///
+/// The generator is checked with
+/// [`is_generator_vartime`][`crate::modular::is_generator_vartime`] at compile time, so the build
+/// fails if it is not a generator, or if the factorization is wrong or incomplete.
+#[macro_export]
+macro_rules! impl_multiplicative_generator {
+    ($name:ident, $uint_type:ty, $generator:expr, [$($factor:expr),+ $(,)?]) => {
+        impl $crate::modular::GeneratorParams<{ <$uint_type>::LIMBS }> for $name {
+            const GENERATOR: $crate::modular::Residue<$name, { <$uint_type>::LIMBS }> =
+                $crate::modular::Residue::new(&<$uint_type>::from_u64($generator));
+        }
+
+        #[allow(unknown_lints, long_running_const_eval)]
+        const _: () = {
+            if !$crate::modular::is_generator_vartime::<$name, { <$uint_type>::LIMBS }>(
+                &<$uint_type>::from_u64($generator),
+                &[$(<$uint_type>::const_from_str_radix($factor, 16)),+],
+            ) {
+                panic!("not a generator of the multiplicative group");
+            }
+        };
+    };
 }
 
 /// Creates a `Residue` with the given value for a specific modulus.
@@ -65,3 +189,265 @@ macro_rules! const_residue {
         $crate::modular::Residue::<$modulus, { $modulus::LIMBS }>::new(&$variable)
     };
 }
+
+/// Implements a prime field element type with the given name, backed by a [`Residue`] with the
+/// given modulus and type, in that specific order.
+///
+/// The modulus must be defined using [`impl_prime_modulus!`][`crate::impl_prime_modulus`], which
+/// checks that it is prime, and its multiplicative generator using
+/// [`impl_multiplicative_generator!`][`crate::impl_multiplicative_generator`], which checks it
+/// against a factorization of `MODULUS - 1`. The generator becomes
+/// [`ff::PrimeField::MULTIPLICATIVE_GENERATOR`], and the two-adicity and root of unity constants
+/// are derived from [`PrimeParams`].
+///
+/// For example,
+/// ```ignore
+/// impl_prime_modulus!(GoldilocksModulus, U64, 2^64 - 2^32 + 1);
+/// impl_multiplicative_generator!(GoldilocksModulus, U64, 7, ["2", "3", "5", "11", "101", "10001"]);
+/// impl_field_element!(Goldilocks, GoldilocksModulus, U64);
+/// ```
+/// implements the field modulo `2^64 - 2^32 + 1` as `Goldilocks`.
+///
+/// The generated type implements [`ff::Field`] and [`ff::PrimeField`], as well as the arithmetic
+/// operators. The field element is serialized as big endian bytes in [`PrimeFieldRepr`], and
+/// [`ff::PrimeField::MODULUS`] is the full width lowercase hexadecimal encoding of the modulus,
+/// prefixed with `0x`.
+///
+/// [`PrimeFieldRepr`]: crate::modular::PrimeFieldRepr
+/// [`PrimeParams`]: crate::modular::PrimeParams
+/// [`Residue`]: crate::modular::Residue
+#[cfg(feature = "ff")]
+#[macro_export]
+macro_rules! impl_field_element {
+    ($name:ident, $modulus:ident, $uint_type:ty) => {
+        #[doc = concat!("Element of the prime field defined by [`", stringify!($modulus), "`].")]
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+        pub struct $name(
+            pub $crate::modular::Residue<$modulus, { <$uint_type>::LIMBS }>,
+        );
+
+        impl $name {
+            /// Number of limbs in the integer representation of a field element.
+            pub const LIMBS: usize = <$uint_type>::LIMBS;
+
+            /// Create a field element from an integer, reducing it by the modulus.
+            pub const fn new(integer: &$uint_type) -> Self {
+                Self($crate::modular::Residue::new(integer))
+            }
+
+            /// Retrieve the canonical integer representation of this field element.
+            pub const fn retrieve(&self) -> $uint_type {
+                self.0.retrieve()
+            }
+
+            /// `(T - 1) / 2`, as used by Tonelli-Shanks.
+            const T_MINUS_1_OVER_2: [u64; (<$uint_type>::BYTES + 7) / 8] =
+                $crate::modular::to_u64_words(
+                    &<$modulus as $crate::modular::PrimeParams<{ <$uint_type>::LIMBS }>>::T
+                        .wrapping_sub(&$crate::Uint::ONE)
+                        .shr(1),
+                );
+
+            /// Hexadecimal encoding of the modulus, as used by [`ff::PrimeField::MODULUS`].
+            const MODULUS_HEX: [u8; 2 + 2 * <$uint_type>::BYTES] = $crate::modular::to_hex_string(
+                &<$modulus as $crate::modular::ResidueParams<{ <$uint_type>::LIMBS }>>::MODULUS,
+            );
+        }
+
+        impl From<u64> for $name {
+            fn from(n: u64) -> Self {
+                Self::new(&<$uint_type>::from_u64(n))
+            }
+        }
+
+        impl From<$name> for $uint_type {
+            fn from(elem: $name) -> $uint_type {
+                elem.retrieve()
+            }
+        }
+
+        impl $crate::subtle::ConditionallySelectable for $name {
+            fn conditional_select(a: &Self, b: &Self, choice: $crate::subtle::Choice) -> Self {
+                Self($crate::subtle::ConditionallySelectable::conditional_select(
+                    &a.0, &b.0, choice,
+                ))
+            }
+        }
+
+        impl $crate::subtle::ConstantTimeEq for $name {
+            fn ct_eq(&self, other: &Self) -> $crate::subtle::Choice {
+                $crate::subtle::ConstantTimeEq::ct_eq(&self.0, &other.0)
+            }
+        }
+
+        $crate::impl_field_element!(@op $name, Add, add, AddAssign, add_assign);
+        $crate::impl_field_element!(@op $name, Sub, sub, SubAssign, sub_assign);
+        $crate::impl_field_element!(@op $name, Mul, mul, MulAssign, mul_assign);
+
+        impl ::core::ops::Neg for $name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                Self(self.0.neg())
+            }
+        }
+
+        impl ::core::ops::Neg for &$name {
+            type Output = $name;
+
+            fn neg(self) -> $name {
+                $name(self.0.neg())
+            }
+        }
+
+        impl ::core::iter::Sum for $name {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(<Self as $crate::ff::Field>::ZERO, ::core::ops::Add::add)
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $name> for $name {
+            fn sum<I: Iterator<Item = &'a $name>>(iter: I) -> Self {
+                iter.copied().sum()
+            }
+        }
+
+        impl ::core::iter::Product for $name {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(<Self as $crate::ff::Field>::ONE, ::core::ops::Mul::mul)
+            }
+        }
+
+        impl<'a> ::core::iter::Product<&'a $name> for $name {
+            fn product<I: Iterator<Item = &'a $name>>(iter: I) -> Self {
+                iter.copied().product()
+            }
+        }
+
+        impl $crate::ff::Field for $name {
+            const ZERO: Self = Self($crate::modular::Residue::ZERO);
+            const ONE: Self = Self($crate::modular::Residue::ONE);
+
+            fn random(mut rng: impl $crate::rand_core::RngCore) -> Self {
+                // Reduce a uniformly random integer twice the size of the modulus, which makes
+                // the bias negligible.
+                let mut lo = <$uint_type>::ZERO;
+                let mut hi = <$uint_type>::ZERO;
+
+                for limb in lo.as_limbs_mut().iter_mut().chain(hi.as_limbs_mut()) {
+                    let mut bytes = $crate::Encoding::to_le_bytes(&$crate::Limb::ZERO);
+                    rng.fill_bytes(bytes.as_mut());
+                    *limb = $crate::Encoding::from_le_bytes(bytes);
+                }
+
+                let modulus = <$modulus as $crate::modular::ResidueParams<
+                    { <$uint_type>::LIMBS },
+                >>::MODULUS;
+                Self::new(&<$uint_type>::const_rem_wide((lo, hi), &modulus).unwrap())
+            }
+
+            fn square(&self) -> Self {
+                Self(self.0.square())
+            }
+
+            fn double(&self) -> Self {
+                Self(self.0.add(&self.0))
+            }
+
+            fn invert(&self) -> $crate::subtle::CtOption<Self> {
+                $crate::subtle::CtOption::from(self.0.invert().map(Self))
+            }
+
+            fn sqrt(&self) -> $crate::subtle::CtOption<Self> {
+                $crate::ff::helpers::sqrt_tonelli_shanks(self, Self::T_MINUS_1_OVER_2)
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> ($crate::subtle::Choice, Self) {
+                $crate::ff::helpers::sqrt_ratio_generic(num, div)
+            }
+        }
+
+        impl $crate::ff::PrimeField for $name {
+            type Repr = $crate::modular::PrimeFieldRepr<{ <$uint_type>::BYTES }>;
+
+            const MODULUS: &'static str = match ::core::str::from_utf8(&Self::MODULUS_HEX) {
+                Ok(modulus) => modulus,
+                Err(_) => panic!("invalid hexadecimal encoding"),
+            };
+            const NUM_BITS: u32 = <$modulus as $crate::modular::ResidueParams<
+                { <$uint_type>::LIMBS },
+            >>::MODULUS_BITS;
+            const CAPACITY: u32 = Self::NUM_BITS - 1;
+            const TWO_INV: Self = Self($crate::modular::Residue::new(&<$uint_type>::from_u8(2)).invert().unwrap());
+            const MULTIPLICATIVE_GENERATOR: Self = Self(
+                <$modulus as $crate::modular::GeneratorParams<{ <$uint_type>::LIMBS }>>::GENERATOR,
+            );
+            const S: u32 = <$modulus as $crate::modular::PrimeParams<{ <$uint_type>::LIMBS }>>::S;
+            const ROOT_OF_UNITY: Self = Self(
+                Self::MULTIPLICATIVE_GENERATOR
+                    .0
+                    .pow(&<$modulus as $crate::modular::PrimeParams<{ <$uint_type>::LIMBS }>>::T),
+            );
+            const ROOT_OF_UNITY_INV: Self = Self(Self::ROOT_OF_UNITY.0.invert().unwrap());
+            const DELTA: Self = Self(
+                Self::MULTIPLICATIVE_GENERATOR
+                    .0
+                    .pow(&$crate::Uint::<{ <$uint_type>::LIMBS }>::ONE.shl(Self::S)),
+            );
+
+            fn from_repr(repr: Self::Repr) -> $crate::subtle::CtOption<Self> {
+                let integer: $uint_type = repr.to_uint();
+                let modulus = <$modulus as $crate::modular::ResidueParams<
+                    { <$uint_type>::LIMBS },
+                >>::MODULUS;
+                let is_canonical = $crate::subtle::ConstantTimeLess::ct_lt(&integer, &modulus);
+                $crate::subtle::CtOption::new(Self::new(&integer), is_canonical)
+            }
+
+            fn to_repr(&self) -> Self::Repr {
+                $crate::modular::PrimeFieldRepr::from_uint(&self.retrieve())
+            }
+
+            fn is_odd(&self) -> $crate::subtle::Choice {
+                $crate::Integer::is_odd(&self.retrieve())
+            }
+        }
+    };
+    (@op $name:ident, $op:ident, $op_fn:ident, $op_assign:ident, $op_assign_fn:ident) => {
+        impl ::core::ops::$op for $name {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: Self) -> Self {
+                Self(self.0.$op_fn(&rhs.0))
+            }
+        }
+
+        impl ::core::ops::$op<&$name> for $name {
+            type Output = Self;
+
+            fn $op_fn(self, rhs: &Self) -> Self {
+                Self(self.0.$op_fn(&rhs.0))
+            }
+        }
+
+        impl ::core::ops::$op<&$name> for &$name {
+            type Output = $name;
+
+            fn $op_fn(self, rhs: &$name) -> $name {
+                $name(self.0.$op_fn(&rhs.0))
+            }
+        }
+
+        impl ::core::ops::$op_assign for $name {
+            fn $op_assign_fn(&mut self, rhs: Self) {
+                *self = Self(self.0.$op_fn(&rhs.0));
+            }
+        }
+
+        impl ::core::ops::$op_assign<&$name> for $name {
+            fn $op_assign_fn(&mut self, rhs: &Self) {
+                *self = Self(self.0.$op_fn(&rhs.0));
+            }
+        }
+    };
+}
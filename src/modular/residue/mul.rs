@@ -6,7 +6,7 @@ use core::{
 };
 
 use crate::{
-    modular::mul::{mul_montgomery_form, square_montgomery_form},
+    modular::mul::{mul_by_small_montgomery_form, mul_montgomery_form, square_montgomery_form},
     traits::Square,
 };
 
@@ -37,6 +37,17 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             phantom: PhantomData,
         }
     }
+
+    /// Multiplies by the small constant `k`, which only takes modular additions and is
+    /// cheaper than a full Montgomery multiplication for the constants found in formulas.
+    ///
+    /// NOTE: this is variable-time with respect to `k`.
+    pub const fn mul_by_small(&self, k: u32) -> Self {
+        Self {
+            montgomery_form: mul_by_small_montgomery_form(&self.montgomery_form, k, &MOD::MODULUS),
+            phantom: PhantomData,
+        }
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Mul<&Residue<MOD, LIMBS>>
@@ -2,8 +2,11 @@
 
 use super::{Residue, ResidueParams};
 use crate::{
-    modular::pow::{multi_exponentiate_montgomery_form_array, pow_montgomery_form},
-    MultiExponentiateBoundedExp, PowBoundedExp, Uint,
+    modular::pow::{
+        multi_exponentiate_montgomery_form_array, multi_exponentiate_montgomery_form_vartime,
+        pow_montgomery_form, pow_montgomery_form_vartime,
+    },
+    MultiExponentiateBoundedExp, PowBoundedExp, Public, Uint,
 };
 
 #[cfg(feature = "alloc")]
@@ -40,6 +43,61 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize> Residue<MOD, LIMBS> {
             phantom: core::marker::PhantomData,
         }
     }
+
+    /// Raises to the `exponent` power using a sliding window which skips the leading zeros of
+    /// the exponent.
+    ///
+    /// NOTE: this is variable-time with respect to `exponent`, which is therefore required to be
+    /// [`Public`], e.g. an RSA public exponent or a scalar in signature verification. Use
+    /// [`Residue::pow`] for secret exponents.
+    pub const fn pow_vartime<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Public<Uint<RHS_LIMBS>>,
+    ) -> Residue<MOD, LIMBS> {
+        Self {
+            montgomery_form: pow_montgomery_form_vartime(
+                &self.montgomery_form,
+                exponent.as_inner(),
+                &MOD::MODULUS,
+                &MOD::R,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: core::marker::PhantomData,
+        }
+    }
+
+    /// Computes `x1 ^ k1 * ... * xn ^ kn` with interleaved sliding windows sharing the
+    /// squarings, which is faster than computing each power separately.
+    ///
+    /// At least one base must be given.
+    ///
+    /// NOTE: this is variable-time with respect to the exponents, which are therefore required to
+    /// be [`Public`]. Use [`MultiExponentiate`](crate::MultiExponentiate) for secret exponents.
+    pub const fn multi_exponentiate_vartime<const N: usize, const RHS_LIMBS: usize>(
+        bases_and_exponents: &[(Self, Public<Uint<RHS_LIMBS>>); N],
+    ) -> Self {
+        const_assert_ne!(N, 0, "bases_and_exponents must not be empty");
+
+        let mut bases_and_exponents_montgomery_form =
+            [(Uint::<LIMBS>::ZERO, Uint::<RHS_LIMBS>::ZERO); N];
+
+        let mut i = 0;
+        while i < N {
+            let (base, exponent) = &bases_and_exponents[i];
+            bases_and_exponents_montgomery_form[i] = (base.montgomery_form, *exponent.as_inner());
+            i += 1;
+        }
+
+        Self {
+            montgomery_form: multi_exponentiate_montgomery_form_vartime(
+                &bases_and_exponents_montgomery_form,
+                &MOD::MODULUS,
+                &MOD::R,
+                MOD::MOD_NEG_INV,
+            ),
+            phantom: core::marker::PhantomData,
+        }
+    }
 }
 
 impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize, const RHS_LIMBS: usize>
@@ -110,7 +168,7 @@ impl<MOD: ResidueParams<LIMBS>, const LIMBS: usize, const RHS_LIMBS: usize>
 #[cfg(test)]
 mod tests {
     use crate::traits::MultiExponentiate;
-    use crate::{const_residue, impl_modulus, modular::residue::ResidueParams, U256};
+    use crate::{const_residue, impl_modulus, modular::residue::ResidueParams, Public, U256};
 
     impl_modulus!(
         Modulus,
@@ -196,6 +254,60 @@ mod tests {
         assert_eq!(res, expected);
     }
 
+    #[test]
+    fn test_pow_vartime() {
+        let base =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base_mod = const_residue!(base, Modulus);
+
+        for exponent in [
+            U256::ZERO,
+            U256::ONE,
+            U256::from(105u64),
+            U256::from(65537u64),
+            U256::MAX,
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685"),
+        ] {
+            assert_eq!(
+                base_mod.pow_vartime(&Public::new(exponent)),
+                base_mod.pow(&exponent)
+            );
+        }
+
+        let expected =
+            U256::from_be_hex("89E2A4E99F649A5AE2C18068148C355CA927B34A3245C938178ED00D6EF218AA");
+        assert_eq!(
+            base_mod
+                .pow_vartime(&Public::new(U256::from(105u64)))
+                .retrieve(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_multi_exp_vartime() {
+        let base = U256::from(2u8);
+        let base_mod = const_residue!(base, Modulus);
+        let exponent = U256::from(33u8);
+
+        let base2 =
+            U256::from_be_hex("3435D18AA8313EBBE4D20002922225B53F75DC4453BB3EEC0378646F79B524A4");
+        let base2_mod = const_residue!(base2, Modulus);
+        let exponent2 =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+
+        let expected = base_mod.pow(&exponent) * base2_mod.pow(&exponent2);
+        let res = crate::modular::residue::Residue::<Modulus, { U256::LIMBS }>::multi_exponentiate_vartime(
+            &[(base_mod, Public::new(exponent)), (base2_mod, Public::new(exponent2))],
+        );
+        assert_eq!(res, expected);
+
+        let res = crate::modular::residue::Residue::<Modulus, { U256::LIMBS }>::multi_exponentiate_vartime(
+            &[(base_mod, Public::new(U256::ZERO)), (base2_mod, Public::new(U256::ZERO))],
+        );
+        assert_eq!(res.retrieve(), U256::ONE);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn test_multi_exp_slice() {
@@ -0,0 +1,500 @@
+//! Validation and derived constants for prime constant moduli.
+
+use super::{Residue, ResidueParams};
+use crate::{
+    modular::{DynResidue, DynResidueParams},
+    Public, Uint,
+};
+
+/// Small primes used for trial division.
+const SMALL_PRIMES: [u8; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// The number of candidates for the Lucas test parameter `D` to try before checking whether the
+/// tested number is a perfect square, for which no suitable `D` exists.
+const SQUARE_CHECK_CANDIDATES: u32 = 10;
+
+/// The parameters of a constant prime modulus, on top of the Montgomery parameters in
+/// [`ResidueParams`]. An easy way to generate these parameters is using the
+/// [`impl_prime_modulus!`][`crate::impl_prime_modulus`] macro, which also checks that the modulus
+/// is prime at compile time.
+///
+/// All the constants are computed from the modulus by default.
+pub trait PrimeParams<const LIMBS: usize>: ResidueParams<LIMBS> {
+    /// The two-adicity of the modulus, i.e. the largest `S` such that `2^S` divides `MODULUS - 1`
+    const S: u32 = Self::MODULUS.wrapping_sub(&Uint::ONE).trailing_zeros();
+    /// `(MODULUS - 1) >> S`, the odd part of `MODULUS - 1`
+    const T: Uint<LIMBS> = Self::MODULUS.wrapping_sub(&Uint::ONE).shr_vartime(Self::S);
+    /// The smallest quadratic non-residue modulo `MODULUS`
+    ///
+    /// It generates the Sylow 2-subgroup of the multiplicative group when raised to the power `T`,
+    /// which is what square root algorithms and FFTs need. A generator of the whole
+    /// multiplicative group can't be found without factoring `MODULUS - 1`, see
+    /// [`GeneratorParams`] for that.
+    const NON_RESIDUE: Residue<Self, LIMBS> = smallest_non_residue::<Self, LIMBS>();
+    /// A primitive `2^S`-th root of unity, equal to `NON_RESIDUE^T`
+    const ROOT_OF_UNITY: Residue<Self, LIMBS> = Self::NON_RESIDUE.pow(&Self::T);
+}
+
+/// A generator of the multiplicative group modulo a constant prime modulus. An easy way to
+/// implement this trait is using the
+/// [`impl_multiplicative_generator!`][`crate::impl_multiplicative_generator`] macro, which checks
+/// the generator at compile time with [`is_generator_vartime`].
+pub trait GeneratorParams<const LIMBS: usize>: PrimeParams<LIMBS> {
+    /// A generator of the multiplicative group modulo `MODULUS`
+    const GENERATOR: Residue<Self, LIMBS>;
+}
+
+/// Checks whether `MOD::MODULUS` is prime, using trial division by small primes followed by the
+/// Baillie-PSW test, i.e. a strong Fermat test to base 2 and a strong Lucas test with Selfridge's
+/// parameters.
+///
+/// No composite passing the Baillie-PSW test is known, and none exists below `2^64`.
+///
+/// NOTE: this operation is variable time, and is intended to be used at compile time.
+pub const fn is_prime_vartime<MOD: ResidueParams<LIMBS>, const LIMBS: usize>() -> bool {
+    is_prime_uint_vartime(&MOD::MODULUS)
+}
+
+/// Checks whether `generator` generates the multiplicative group modulo the prime
+/// `MOD::MODULUS`, given the distinct prime `factors` of `MODULUS - 1`.
+///
+/// Returns `false` if `generator` is not in `[1, MODULUS)`, if one of the factors is not a prime
+/// dividing `MODULUS - 1`, or if `MODULUS - 1` has prime factors missing from `factors`, so a
+/// wrong factorization can't make an element which is not a generator pass the check.
+///
+/// NOTE: this operation is variable time, and is intended to be used at compile time.
+pub const fn is_generator_vartime<MOD: PrimeParams<LIMBS>, const LIMBS: usize>(
+    generator: &Uint<LIMBS>,
+    factors: &[Uint<LIMBS>],
+) -> bool {
+    if !generator.ct_is_nonzero().is_true_vartime() || !generator.cmp_vartime(&MOD::MODULUS).is_lt()
+    {
+        return false;
+    }
+
+    let order = MOD::MODULUS.wrapping_sub(&Uint::ONE);
+    let generator = Residue::<MOD, LIMBS>::new(generator);
+    let mut cofactor = order;
+
+    let mut i = 0;
+    while i < factors.len() {
+        let factor = &factors[i];
+        if !is_prime_uint_vartime(factor) {
+            return false;
+        }
+
+        let (quotient, remainder, _) = order.const_div_rem_vartime(factor);
+        if remainder.ct_is_nonzero().is_true_vartime() {
+            return false;
+        }
+
+        // `generator` generates the group iff `generator^((MODULUS - 1) / q) != 1` for all the
+        // prime factors `q` of `MODULUS - 1`
+        let power = generator.pow_vartime(&Public::new(quotient));
+        if Uint::ct_eq(
+            power.as_montgomery(),
+            Residue::<MOD, LIMBS>::ONE.as_montgomery(),
+        )
+        .is_true_vartime()
+        {
+            return false;
+        }
+
+        loop {
+            let (quotient, remainder, _) = cofactor.const_div_rem_vartime(factor);
+            if remainder.ct_is_nonzero().is_true_vartime() {
+                break;
+            }
+            cofactor = quotient;
+        }
+        i += 1;
+    }
+
+    // All the prime factors of `MODULUS - 1` must have been checked
+    Uint::ct_eq(&cofactor, &Uint::ONE).is_true_vartime()
+}
+
+/// Checks whether `n` is prime, using trial division by small primes followed by the Baillie-PSW
+/// test, i.e. a strong Fermat test to base 2 and a strong Lucas test with Selfridge's parameters.
+///
+/// No composite passing the Baillie-PSW test is known, and none exists below `2^64`.
+const fn is_prime_uint_vartime<const LIMBS: usize>(n: &Uint<LIMBS>) -> bool {
+    let mut i = 0;
+    while i < SMALL_PRIMES.len() {
+        let prime = Uint::<LIMBS>::from_u8(SMALL_PRIMES[i]);
+        if Uint::ct_eq(n, &prime).is_true_vartime() {
+            return true;
+        }
+
+        let rem = n.const_rem(&prime).unwrap();
+        if !rem.ct_is_nonzero().is_true_vartime() {
+            return false;
+        }
+        i += 1;
+    }
+
+    // `n` is odd and greater than 37 at this point
+    let params = DynResidueParams::new_unchecked(n);
+    is_strong_probable_prime_base_2(&params) && is_strong_lucas_probable_prime(&params)
+}
+
+/// The strong Fermat test, i.e. the Miller-Rabin test, to base 2.
+const fn is_strong_probable_prime_base_2<const LIMBS: usize>(
+    params: &DynResidueParams<LIMBS>,
+) -> bool {
+    // `n - 1 = 2^s * t` with `t` odd
+    let minus_one = DynResidue::one(*params).neg();
+    let n_minus_one = params.modulus.wrapping_sub(&Uint::ONE);
+    let s = n_minus_one.trailing_zeros();
+    let t = n_minus_one.shr_vartime(s);
+
+    let base = DynResidue::new(&Uint::from_u8(2), *params);
+    let mut x = base.pow_vartime(&Public::new(t));
+    if eq_vartime(&x, &DynResidue::one(*params)) || eq_vartime(&x, &minus_one) {
+        return true;
+    }
+
+    let mut i = 1;
+    while i < s {
+        x = x.square();
+        if eq_vartime(&x, &minus_one) {
+            return true;
+        }
+        i += 1;
+    }
+
+    false
+}
+
+/// The strong Lucas test with the parameters `P = 1` and `Q = (1 - D) / 4`, where `D` is the first
+/// of `5, -7, 9, -11, ...` with the Jacobi symbol `(D/n) = -1` (Selfridge's method A).
+const fn is_strong_lucas_probable_prime<const LIMBS: usize>(
+    params: &DynResidueParams<LIMBS>,
+) -> bool {
+    let n = &params.modulus;
+
+    let mut d_abs = 5u32;
+    let mut d_is_negative = false;
+    let mut candidates = 0;
+    loop {
+        match jacobi_symbol_vartime(d_abs, d_is_negative, n) {
+            -1 => break,
+            // `|D|` shares a factor with `n`
+            0 => return Uint::ct_eq(n, &Uint::from_u32(d_abs)).is_true_vartime(),
+            _ => {}
+        }
+
+        candidates += 1;
+        if candidates == SQUARE_CHECK_CANDIDATES && is_square_vartime(n) {
+            return false;
+        }
+
+        d_abs += 2;
+        d_is_negative = !d_is_negative;
+    }
+
+    // `Q = (1 - D) / 4`, which is negative for positive `D`
+    let q_abs = if d_is_negative {
+        (d_abs + 1) / 4
+    } else {
+        (d_abs - 1) / 4
+    };
+    if gcd_vartime(rem_u32_vartime(n, q_abs), q_abs) != 1 {
+        return false;
+    }
+
+    let d = small_residue(d_abs, d_is_negative, params);
+    let q = small_residue(q_abs, !d_is_negative, params);
+    let zero = DynResidue::zero(*params);
+
+    // `n + 1 = 2^s * k` with `k` odd; `n + 1` doesn't overflow since `2^BITS - 1` is divisible
+    // by 3 for an even number of bits, and has been rejected by the trial division
+    let n_plus_one = n.wrapping_add(&Uint::ONE);
+    let s = n_plus_one.trailing_zeros();
+    let k = n_plus_one.shr_vartime(s);
+
+    // Compute `U_k`, `V_k` and `Q^k` scanning `k` from the most significant bit, starting from
+    // `U_1 = 1`, `V_1 = P = 1` and `Q^1`.
+    let mut u = DynResidue::one(*params);
+    let mut v = DynResidue::one(*params);
+    let mut q_k = q;
+
+    let mut i = k.bits_vartime() - 1;
+    while i > 0 {
+        i -= 1;
+
+        // Doubling: `U_2j = U_j * V_j`, `V_2j = V_j^2 - 2 * Q^j`
+        u = u.mul(&v);
+        v = v.square().sub(&q_k.double());
+        q_k = q_k.square();
+
+        if k.bit_vartime(i) {
+            // Incrementing: `U_j+1 = (P * U_j + V_j) / 2`, `V_j+1 = (D * U_j + P * V_j) / 2`
+            let u_next = u.add(&v).div_by_2();
+            v = d.mul(&u).add(&v).div_by_2();
+            u = u_next;
+            q_k = q_k.mul(&q);
+        }
+    }
+
+    if eq_vartime(&u, &zero) || eq_vartime(&v, &zero) {
+        return true;
+    }
+
+    // Check `V_(k * 2^r) = 0` for `0 < r < s`
+    let mut r = 1;
+    while r < s {
+        v = v.square().sub(&q_k.double());
+        if eq_vartime(&v, &zero) {
+            return true;
+        }
+        q_k = q_k.square();
+        r += 1;
+    }
+
+    false
+}
+
+/// Computes the Jacobi symbol `(D/n)` for `D = ±d_abs` and an odd `n`.
+const fn jacobi_symbol_vartime<const LIMBS: usize>(
+    d_abs: u32,
+    d_is_negative: bool,
+    n: &Uint<LIMBS>,
+) -> i8 {
+    let n_mod_4 = n.as_limbs()[0].0 & 3;
+
+    // `(-1/n) = (-1)^((n - 1) / 2)`
+    let mut result = if d_is_negative && n_mod_4 == 3 { -1 } else { 1 };
+
+    // Quadratic reciprocity: `(d/n) = (n/d) * (-1)^((d - 1) / 2 * (n - 1) / 2)` for odd `d`
+    if d_abs & 3 == 3 && n_mod_4 == 3 {
+        result = -result;
+    }
+
+    let mut a = rem_u32_vartime(n, d_abs);
+    let mut m = d_abs;
+    while a != 0 {
+        while a & 1 == 0 {
+            a >>= 1;
+            if m & 7 == 3 || m & 7 == 5 {
+                result = -result;
+            }
+        }
+
+        let tmp = a;
+        a = m;
+        m = tmp;
+        if a & 3 == 3 && m & 3 == 3 {
+            result = -result;
+        }
+        a %= m;
+    }
+
+    if m == 1 {
+        result
+    } else {
+        0
+    }
+}
+
+/// Computes `n mod m` for a small nonzero `m`.
+const fn rem_u32_vartime<const LIMBS: usize>(n: &Uint<LIMBS>, m: u32) -> u32 {
+    let rem = n.const_rem(&Uint::from_u32(m)).unwrap();
+    #[allow(trivial_numeric_casts)]
+    let rem = rem.as_limbs()[0].0 as u32;
+    rem
+}
+
+/// Computes the greatest common divisor of `a` and `b`.
+const fn gcd_vartime(mut a: u32, mut b: u32) -> u32 {
+    while b != 0 {
+        let tmp = a % b;
+        a = b;
+        b = tmp;
+    }
+    a
+}
+
+/// Checks whether `n` is a perfect square.
+const fn is_square_vartime<const LIMBS: usize>(n: &Uint<LIMBS>) -> bool {
+    let root = n.sqrt_vartime();
+    Uint::ct_eq(&root.wrapping_mul(&root), n).is_true_vartime()
+}
+
+/// Returns `value` or `-value` as a residue.
+const fn small_residue<const LIMBS: usize>(
+    value: u32,
+    is_negative: bool,
+    params: &DynResidueParams<LIMBS>,
+) -> DynResidue<LIMBS> {
+    let residue = DynResidue::new(&Uint::from_u32(value), *params);
+    if is_negative {
+        residue.neg()
+    } else {
+        residue
+    }
+}
+
+/// Returns the smallest quadratic non-residue modulo the prime `MOD::MODULUS`, found using
+/// Euler's criterion.
+const fn smallest_non_residue<MOD: ResidueParams<LIMBS>, const LIMBS: usize>() -> Residue<MOD, LIMBS>
+{
+    let minus_one = Residue::<MOD, LIMBS>::ONE.neg();
+    let exponent = MOD::MODULUS.shr_vartime(1);
+
+    let mut candidate = Residue::<MOD, LIMBS>::ONE;
+    let mut i = 0;
+    while i < u16::MAX {
+        candidate = candidate.add(&Residue::ONE);
+        if Uint::ct_eq(
+            candidate.pow(&exponent).as_montgomery(),
+            minus_one.as_montgomery(),
+        )
+        .is_true_vartime()
+        {
+            return candidate;
+        }
+        i += 1;
+    }
+
+    panic!("no quadratic non-residue found, the modulus must be prime");
+}
+
+/// Compares two residues in variable time.
+const fn eq_vartime<const LIMBS: usize>(lhs: &DynResidue<LIMBS>, rhs: &DynResidue<LIMBS>) -> bool {
+    Uint::ct_eq(lhs.as_montgomery(), rhs.as_montgomery()).is_true_vartime()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        is_generator_vartime, is_prime_uint_vartime, is_prime_vartime,
+        is_strong_lucas_probable_prime, PrimeParams,
+    };
+    use crate::{
+        impl_modulus,
+        modular::{DynResidueParams, ResidueParams},
+        U256, U64,
+    };
+
+    impl_modulus!(P256, U256, 2 ^ 256 - 2 ^ 224 + 2 ^ 192 + 2 ^ 96 - 1);
+    impl PrimeParams<{ U256::LIMBS }> for P256 {}
+
+    impl_modulus!(
+        Bls12381Scalar,
+        U256,
+        "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001"
+    );
+    impl PrimeParams<{ U256::LIMBS }> for Bls12381Scalar {}
+
+    // 1000000007 * 998244353, which has no small factors
+    impl_modulus!(Composite, U64, dec "998244359987710471");
+    impl_modulus!(Seven, U64, "7");
+    impl_modulus!(Carmichael, U64, dec "561");
+
+    #[test]
+    fn primality() {
+        assert!(is_prime_vartime::<P256, { U256::LIMBS }>());
+        assert!(is_prime_vartime::<Bls12381Scalar, { U256::LIMBS }>());
+        assert!(is_prime_vartime::<Seven, { U64::LIMBS }>());
+        assert!(!is_prime_vartime::<Composite, { U64::LIMBS }>());
+        assert!(!is_prime_vartime::<Carmichael, { U64::LIMBS }>());
+    }
+
+    #[test]
+    fn primality_small() {
+        for n in 0u64..5000 {
+            let expected = n > 1 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+            assert_eq!(is_prime_uint_vartime(&U64::from_u64(n)), expected, "{n}");
+        }
+    }
+
+    #[test]
+    fn pseudoprimes() {
+        // Strong pseudoprimes to base 2, the second one also to bases 3, 5 and 7
+        assert!(!is_prime_uint_vartime(&U64::from_u64(8321)));
+        assert!(!is_prime_uint_vartime(&U64::from_u64(3215031751)));
+        // Squares of primes, for which no Lucas parameter `D` exists
+        assert!(!is_prime_uint_vartime(&U64::from_u64(1681)));
+        assert!(!is_prime_uint_vartime(&U64::from_u64(
+            1000000007 * 1000000007
+        )));
+        assert!(is_prime_uint_vartime(&U64::from_u64(2305843009213693951)));
+
+        // Strong Lucas pseudoprimes pass the Lucas part of the test on their own
+        for n in [5459u64, 5777, 10877, 16109, 18971] {
+            let params = DynResidueParams::new_unchecked(&U64::from_u64(n));
+            assert!(is_strong_lucas_probable_prime(&params));
+            assert!(!is_prime_uint_vartime(&U64::from_u64(n)));
+        }
+    }
+
+    #[test]
+    fn generator() {
+        const FACTORS: [U256; 12] = [
+            U256::from_u32(2),
+            U256::from_u32(3),
+            U256::from_u32(11),
+            U256::from_u32(19),
+            U256::from_u32(10177),
+            U256::from_u32(125527),
+            U256::from_u32(859267),
+            U256::from_u32(906349),
+            U256::from_u32(2508409),
+            U256::from_u32(2529403),
+            U256::from_u32(52437899),
+            U256::from_u32(254760293),
+        ];
+        type M = Bls12381Scalar;
+
+        assert!(is_generator_vartime::<M, { U256::LIMBS }>(
+            &U256::from_u8(7),
+            &FACTORS
+        ));
+        // 5 is a quadratic non-residue, but not a generator
+        assert!(!is_generator_vartime::<M, { U256::LIMBS }>(
+            &U256::from_u8(5),
+            &FACTORS
+        ));
+        // Incomplete factorization
+        assert!(!is_generator_vartime::<M, { U256::LIMBS }>(
+            &U256::from_u8(7),
+            &FACTORS[..11]
+        ));
+        // Composite factor
+        let mut factors = FACTORS;
+        factors[1] = U256::from_u32(33);
+        assert!(!is_generator_vartime::<M, { U256::LIMBS }>(
+            &U256::from_u8(7),
+            &factors
+        ));
+        assert!(!is_generator_vartime::<M, { U256::LIMBS }>(
+            &M::MODULUS.wrapping_add(&U256::from_u8(7)),
+            &FACTORS
+        ));
+    }
+
+    #[test]
+    fn derived_constants() {
+        assert_eq!(P256::S, 1);
+        assert_eq!(P256::T, P256::MODULUS.shr_vartime(1));
+        assert_eq!(P256::NON_RESIDUE.retrieve(), U256::from_u8(3));
+        assert_eq!(
+            P256::ROOT_OF_UNITY.retrieve(),
+            P256::MODULUS.wrapping_sub(&U256::ONE)
+        );
+
+        assert_eq!(Bls12381Scalar::S, 32);
+        assert_eq!(Bls12381Scalar::NON_RESIDUE.retrieve(), U256::from_u8(5));
+
+        // The root of unity has order exactly `2^S`
+        let mut x = Bls12381Scalar::ROOT_OF_UNITY;
+        for _ in 0..Bls12381Scalar::S - 1 {
+            x = x.square();
+        }
+        assert_eq!(
+            x.retrieve(),
+            Bls12381Scalar::MODULUS.wrapping_sub(&U256::ONE)
+        );
+    }
+}
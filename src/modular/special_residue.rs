@@ -0,0 +1,312 @@
+//! Implements `SpecialResidue`s, supporting modular arithmetic with a constant special-form
+//! modulus.
+
+mod add;
+mod inv;
+mod mul;
+mod neg;
+mod pow;
+mod reduction;
+mod sub;
+
+use self::reduction::{solinas_carry_folds, special_reduction};
+use super::Retrieve;
+use crate::{
+    traits::{ct_lookup_selectable, ct_select_slice_selectable},
+    ConstantTimeLookup, Limb, Uint, ZeroConstant,
+};
+use core::{fmt::Debug, marker::PhantomData};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+#[cfg(feature = "rand_core")]
+use crate::{rand_core::CryptoRngCore, NonZero, Random, RandomMod};
+
+/// Macros to remove the boilerplate code when dealing with special-form constant moduli.
+#[macro_use]
+mod macros;
+
+/// A signed power of two `±2^e`, used to describe generalized Mersenne (Solinas) moduli.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SolinasTerm {
+    /// The term `+2^e`.
+    Plus(u32),
+    /// The term `-2^e`.
+    Minus(u32),
+}
+
+/// The reduction algorithm used for a special-form modulus.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpecialReduction {
+    /// Reduction modulo a pseudo-Mersenne prime `2^bits - c`, where `bits` falls within the last
+    /// limb and `c` is small: `c * 2^(Uint::<LIMBS>::BITS - bits)` must fit in a single [`Limb`]
+    /// and be smaller than `2^(Uint::<LIMBS>::BITS / 2)`.
+    PseudoMersenne {
+        /// The exponent of the leading power of two.
+        bits: u32,
+        /// The small constant subtracted from `2^bits`.
+        c: Limb,
+    },
+    /// Reduction modulo a generalized Mersenne (Solinas) prime `2^bits - f`, where `f` is the sum
+    /// of a few signed powers of two `terms` and `0 < f < 2^(bits - 2)`.
+    ///
+    /// `bits` and the exponents of all the terms must be multiples of 32, as is the case for the
+    /// NIST primes and the Ed448 prime, and `bits` must fall within the last limb.
+    Solinas {
+        /// The exponent of the leading power of two.
+        bits: u32,
+        /// The signed powers of two which sum up to `2^bits - modulus`.
+        terms: &'static [SolinasTerm],
+    },
+}
+
+/// The parameters of a constant special-form modulus, which allows for a dedicated reduction
+/// instead of the generic Montgomery reduction used by [`Residue`][`super::Residue`].
+///
+/// An easy way to generate these parameters is using the
+/// [`impl_pseudo_mersenne_modulus!`][`crate::impl_pseudo_mersenne_modulus`] and
+/// [`impl_solinas_modulus!`][`crate::impl_solinas_modulus`] macros, which also check the
+/// requirements on the modulus at compile time.
+pub trait SpecialResidueParams<const LIMBS: usize>:
+    Copy + Debug + Default + Eq + Send + Sync + 'static
+{
+    /// Number of limbs required to encode a residue
+    const LIMBS: usize;
+
+    /// The constant modulus, which must be odd
+    const MODULUS: Uint<LIMBS>;
+    /// The reduction algorithm for the modulus
+    const REDUCTION: SpecialReduction;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// A residue mod `MOD`, where `MOD` is a special-form modulus, represented using `LIMBS` limbs.
+/// The modulus of this residue is constant, so it cannot be set at runtime.
+///
+/// Internally, the value is stored in its canonical (fully reduced) form.
+pub struct SpecialResidue<MOD, const LIMBS: usize>
+where
+    MOD: SpecialResidueParams<LIMBS>,
+{
+    value: Uint<LIMBS>,
+    phantom: PhantomData<MOD>,
+}
+
+#[cfg(feature = "zeroize")]
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> zeroize::DefaultIsZeroes
+    for SpecialResidue<MOD, LIMBS>
+{
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SpecialResidue<MOD, LIMBS> {
+    /// The representation of 0 mod `MOD`.
+    pub const ZERO: Self = Self {
+        value: Uint::<LIMBS>::ZERO,
+        phantom: PhantomData,
+    };
+
+    /// The representation of 1 mod `MOD`.
+    pub const ONE: Self = Self {
+        value: Uint::<LIMBS>::ONE,
+        phantom: PhantomData,
+    };
+
+    /// Instantiates a new [`SpecialResidue`] that represents this `integer` mod `MOD`.
+    pub const fn new(integer: &Uint<LIMBS>) -> Self {
+        Self::from_wide(&(*integer, Uint::ZERO))
+    }
+
+    /// Number of folds of the top carry performed by the Solinas reduction for `MOD`.
+    const CARRY_FOLDS: u32 = solinas_carry_folds::<LIMBS>(&MOD::REDUCTION);
+
+    /// Internal helper function to reduce a wide integer below `MOD::MODULUS^2`.
+    const fn from_wide(lower_upper: &(Uint<LIMBS>, Uint<LIMBS>)) -> Self {
+        Self {
+            value: special_reduction(
+                lower_upper,
+                &MOD::MODULUS,
+                &MOD::REDUCTION,
+                Self::CARRY_FOLDS,
+            ),
+            phantom: PhantomData,
+        }
+    }
+
+    /// Retrieves the integer currently encoded in this [`SpecialResidue`], guaranteed to be
+    /// reduced.
+    pub const fn retrieve(&self) -> Uint<LIMBS> {
+        self.value
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> ConditionallySelectable
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        SpecialResidue {
+            value: Uint::conditional_select(&a.value, &b.value, choice),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> ConstantTimeLookup
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_selectable(table, index)
+    }
+
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_selectable(out, a, b, choice)
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> ConstantTimeEq
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn ct_eq(&self, other: &Self) -> Choice {
+        ConstantTimeEq::ct_eq(&self.value, &other.value)
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Default for SpecialResidue<MOD, LIMBS> {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> ZeroConstant
+    for SpecialResidue<MOD, LIMBS>
+{
+    const ZERO: Self = Self::ZERO;
+}
+
+#[cfg(feature = "rand_core")]
+impl<MOD, const LIMBS: usize> Random for SpecialResidue<MOD, LIMBS>
+where
+    MOD: SpecialResidueParams<LIMBS>,
+{
+    #[inline]
+    fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self::new(&Uint::random_mod(rng, &NonZero::from_uint(MOD::MODULUS)))
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Retrieve for SpecialResidue<MOD, LIMBS> {
+    type Output = Uint<LIMBS>;
+    fn retrieve(&self) -> Self::Output {
+        self.retrieve()
+    }
+}
+
+#[cfg(all(test, feature = "rand"))]
+mod tests {
+    use crate::{
+        modular::{DynResidue, DynResidueParams, SpecialResidue, SpecialResidueParams},
+        Random, Uint, U192, U256, U384, U448,
+    };
+    use rand_core::SeedableRng;
+
+    impl_pseudo_mersenne_modulus!(Curve25519, U256, 255, 19);
+    #[cfg(target_pointer_width = "64")]
+    impl_pseudo_mersenne_modulus!(Secp256k1, U256, 256, 0x1000003d1);
+    impl_solinas_modulus!(P192, U192, 192, [+64, +0]);
+    #[cfg(target_pointer_width = "64")]
+    impl_solinas_modulus!(P224, U256, 224, [+96, -0]);
+    impl_solinas_modulus!(P256, U256, 256, [+224, -192, -96, +0]);
+    impl_solinas_modulus!(P384, U384, 384, [+128, +96, -32, +0]);
+    impl_solinas_modulus!(Ed448, U448, 448, [+224, +0]);
+
+    #[test]
+    fn moduli() {
+        assert_eq!(
+            Curve25519::MODULUS,
+            U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed")
+        );
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            Secp256k1::MODULUS,
+            U256::from_be_hex("fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f")
+        );
+        assert_eq!(
+            P192::MODULUS,
+            U192::from_be_hex("fffffffffffffffffffffffffffffffeffffffffffffffff")
+        );
+        #[cfg(target_pointer_width = "64")]
+        assert_eq!(
+            P224::MODULUS,
+            U256::from_be_hex("00000000ffffffffffffffffffffffffffffffff000000000000000000000001")
+        );
+        assert_eq!(
+            P256::MODULUS,
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff")
+        );
+        assert_eq!(
+            P384::MODULUS,
+            U384::from_be_hex(concat![
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+                "ffffffff0000000000000000ffffffff"
+            ])
+        );
+        assert_eq!(
+            Ed448::MODULUS,
+            U448::from_be_hex(concat![
+                "fffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+                "ffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+            ])
+        );
+    }
+
+    /// Checks the arithmetic against the generic Montgomery backend.
+    fn check_arithmetic<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize>()
+    where
+        Uint<LIMBS>: Random,
+    {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let params = DynResidueParams::new(&MOD::MODULUS).unwrap();
+        let minus_one = MOD::MODULUS.wrapping_sub(&Uint::ONE);
+        let edge_cases = [Uint::ZERO, Uint::ONE, minus_one, Uint::MAX];
+
+        let check = |a: Uint<LIMBS>, b: Uint<LIMBS>| {
+            let x = SpecialResidue::<MOD, LIMBS>::new(&a);
+            let y = SpecialResidue::<MOD, LIMBS>::new(&b);
+            let x_mont = DynResidue::new(&a, params);
+            let y_mont = DynResidue::new(&b, params);
+
+            assert_eq!(x.retrieve(), x_mont.retrieve());
+            assert_eq!((x + y).retrieve(), (x_mont + y_mont).retrieve());
+            assert_eq!((x - y).retrieve(), (x_mont - y_mont).retrieve());
+            assert_eq!((x * y).retrieve(), (x_mont * y_mont).retrieve());
+            assert_eq!(x.square().retrieve(), x_mont.square().retrieve());
+            assert_eq!((-x).retrieve(), (-x_mont).retrieve());
+            assert_eq!(x.pow(&b).retrieve(), x_mont.pow(&b).retrieve());
+        };
+
+        for a in edge_cases {
+            for b in edge_cases {
+                check(a, b);
+            }
+        }
+
+        for _ in 0..100 {
+            check(Uint::random(&mut rng), Uint::random(&mut rng));
+        }
+    }
+
+    #[test]
+    fn pseudo_mersenne_arithmetic() {
+        check_arithmetic::<Curve25519, { U256::LIMBS }>();
+        #[cfg(target_pointer_width = "64")]
+        check_arithmetic::<Secp256k1, { U256::LIMBS }>();
+    }
+
+    #[test]
+    fn solinas_arithmetic() {
+        check_arithmetic::<P192, { U192::LIMBS }>();
+        #[cfg(target_pointer_width = "64")]
+        check_arithmetic::<P224, { U256::LIMBS }>();
+        check_arithmetic::<P256, { U256::LIMBS }>();
+        check_arithmetic::<P384, { U384::LIMBS }>();
+        check_arithmetic::<Ed448, { U448::LIMBS }>();
+    }
+}
@@ -0,0 +1,71 @@
+//! Additions between residues with a constant special-form modulus.
+
+use super::{SpecialResidue, SpecialResidueParams};
+use core::{
+    marker::PhantomData,
+    ops::{Add, AddAssign},
+};
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SpecialResidue<MOD, LIMBS> {
+    /// Adds `rhs`.
+    pub const fn add(&self, rhs: &Self) -> Self {
+        Self {
+            value: self.value.add_mod(&rhs.value, &MOD::MODULUS),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Add<&SpecialResidue<MOD, LIMBS>>
+    for &SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    fn add(self, rhs: &SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        self.add(rhs)
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Add<SpecialResidue<MOD, LIMBS>>
+    for &SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn add(self, rhs: SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        self + &rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Add<&SpecialResidue<MOD, LIMBS>>
+    for SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn add(self, rhs: &SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        &self + rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Add<SpecialResidue<MOD, LIMBS>>
+    for SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    fn add(self, rhs: SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        &self + &rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> AddAssign<&Self>
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn add_assign(&mut self, rhs: &Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> AddAssign<Self>
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self += &rhs;
+    }
+}
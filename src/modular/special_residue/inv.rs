@@ -0,0 +1,53 @@
+//! Multiplicative inverses of residues with a constant special-form modulus.
+
+use super::{SpecialResidue, SpecialResidueParams};
+use crate::{traits::Invert, ConstCtOption};
+use core::marker::PhantomData;
+use subtle::CtOption;
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SpecialResidue<MOD, LIMBS> {
+    /// Computes the residue `self^-1` representing the multiplicative inverse of `self`.
+    /// I.e. `self * self^-1 = 1`.
+    /// The result is none if the number was not invertible,
+    /// in which case the contained value is unspecified.
+    pub const fn invert(&self) -> ConstCtOption<Self> {
+        let inverse = self.value.inv_odd_mod(&MOD::MODULUS);
+        let (value, is_some) = inverse.components_ref();
+
+        ConstCtOption::new(
+            Self {
+                value: *value,
+                phantom: PhantomData,
+            },
+            is_some,
+        )
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Invert for SpecialResidue<MOD, LIMBS> {
+    type Output = CtOption<Self>;
+    fn invert(&self) -> Self::Output {
+        self.invert().into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{impl_solinas_modulus, modular::SpecialResidue, U256};
+
+    impl_solinas_modulus!(P256, U256, 256, [+224, -192, -96, +0]);
+
+    #[test]
+    fn test_self_inverse() {
+        let x =
+            U256::from_be_hex("77117F1273373C26C700D076B3F780074D03339F56DD0EFB60E7F58441FD3685");
+        let x_mod = SpecialResidue::<P256, { U256::LIMBS }>::new(&x);
+
+        let inv = x_mod.invert().unwrap();
+        assert_eq!((x_mod * inv).retrieve(), U256::ONE);
+        assert!(SpecialResidue::<P256, { U256::LIMBS }>::ZERO
+            .invert()
+            .is_none()
+            .is_true_vartime());
+    }
+}
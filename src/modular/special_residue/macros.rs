@@ -0,0 +1,152 @@
+//! Macro support.
+
+/// Implements a pseudo-Mersenne modulus `2^bits - c` with the given name, type, number of bits
+/// and single-limb constant `c`, in that specific order.
+///
+/// For example,
+/// `impl_pseudo_mersenne_modulus!(Curve25519, U256, 255, 19);`
+/// implements the modulus `2^255 - 19` named `Curve25519`, to be used with
+/// [`SpecialResidue`][`crate::modular::SpecialResidue`].
+///
+/// The modulus _must_ be odd, `bits` must fit in the last limb of the type, and `c` must be small
+/// enough that `c * 2^(BITS - bits)` fits in a single limb and is smaller than `2^(BITS / 2)`,
+/// where `BITS` is the size of the type, or this will panic at compile time.
+#[macro_export]
+macro_rules! impl_pseudo_mersenne_modulus {
+    ($name:ident, $uint_type:ty, $bits:expr, $c:expr) => {
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+        pub struct $name {}
+        impl<const DLIMBS: usize> $crate::modular::SpecialResidueParams<{ <$uint_type>::LIMBS }>
+            for $name
+        where
+            $uint_type: $crate::ConcatMixed<MixedOutput = $crate::Uint<DLIMBS>>,
+        {
+            const LIMBS: usize = <$uint_type>::LIMBS;
+            const MODULUS: $uint_type = {
+                let bits: u32 = $bits;
+                let c = $crate::Limb($c);
+
+                if bits > <$uint_type>::BITS || bits <= <$uint_type>::BITS - $crate::Limb::BITS {
+                    panic!("`bits` must fit in the last limb of the modulus");
+                }
+
+                // Check that the modulus is odd
+                if c.0 & 1 == 0 {
+                    panic!("modulus must be odd");
+                }
+
+                // Check that `c * 2^(BITS - bits)` fits in a limb, with room to square it
+                let c_bits = $crate::Limb::BITS - c.0.leading_zeros();
+                let c_full_bits = c_bits + <$uint_type>::BITS - bits;
+                if c_full_bits > $crate::Limb::BITS || 2 * c_full_bits >= <$uint_type>::BITS {
+                    panic!("`c` is too large");
+                }
+
+                $crate::Public::new(<$uint_type>::ONE)
+                    .shl_vartime(bits)
+                    .as_inner()
+                    .wrapping_sub(&<$uint_type>::from_word(c.0))
+            };
+            const REDUCTION: $crate::modular::SpecialReduction =
+                $crate::modular::SpecialReduction::PseudoMersenne {
+                    bits: $bits,
+                    c: $crate::Limb($c),
+                };
+        }
+    };
+}
+
+/// Implements a generalized Mersenne (Solinas) modulus with the given name, type, number of bits
+/// and signed power of two terms, in that specific order. The modulus is `2^bits` minus the sum
+/// of the terms, each of which is written as its sign followed by the exponent.
+///
+/// For example,
+/// `impl_solinas_modulus!(P256, U256, 256, [+224, -192, -96, +0]);`
+/// implements the modulus `2^256 - 2^224 + 2^192 + 2^96 - 1` named `P256`, to be used with
+/// [`SpecialResidue`][`crate::modular::SpecialResidue`].
+///
+/// The modulus _must_ be odd, `bits` must fit in the last limb of the type, `bits` and all the
+/// exponents must be multiples of 32, and the terms must sum up to a positive value smaller than
+/// `2^(bits - 2)`, or this will panic at compile time.
+#[macro_export]
+macro_rules! impl_solinas_modulus {
+    (@term + $exponent:literal) => {
+        $crate::modular::SolinasTerm::Plus($exponent)
+    };
+    (@term - $exponent:literal) => {
+        $crate::modular::SolinasTerm::Minus($exponent)
+    };
+    ($name:ident, $uint_type:ty, $bits:expr, [$($sign:tt $exponent:literal),+ $(,)?]) => {
+        #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+        pub struct $name {}
+        impl<const DLIMBS: usize> $crate::modular::SpecialResidueParams<{ <$uint_type>::LIMBS }>
+            for $name
+        where
+            $uint_type: $crate::ConcatMixed<MixedOutput = $crate::Uint<DLIMBS>>,
+        {
+            const LIMBS: usize = <$uint_type>::LIMBS;
+            const MODULUS: $uint_type = {
+                let bits: u32 = $bits;
+                let terms: &[$crate::modular::SolinasTerm] =
+                    &[$($crate::impl_solinas_modulus!(@term $sign $exponent)),+];
+
+                if bits > <$uint_type>::BITS || bits <= <$uint_type>::BITS - $crate::Limb::BITS {
+                    panic!("`bits` must fit in the last limb of the modulus");
+                }
+
+                if bits % 32 != 0 {
+                    panic!("`bits` must be a multiple of 32");
+                }
+
+                let top = *$crate::Public::new(<$uint_type>::ONE)
+                    .shl_vartime(bits)
+                    .as_inner();
+                let mut res = top;
+                let mut i = 0;
+                while i < terms.len() {
+                    res = match terms[i] {
+                        $crate::modular::SolinasTerm::Plus(exponent) => {
+                            if exponent >= bits || exponent % 32 != 0 {
+                                panic!("exponents must be multiples of 32 smaller than `bits`");
+                            }
+                            res.wrapping_sub(
+                                $crate::Public::new(<$uint_type>::ONE)
+                                    .shl_vartime(exponent)
+                                    .as_inner(),
+                            )
+                        }
+                        $crate::modular::SolinasTerm::Minus(exponent) => {
+                            if exponent >= bits || exponent % 32 != 0 {
+                                panic!("exponents must be multiples of 32 smaller than `bits`");
+                            }
+                            res.wrapping_add(
+                                $crate::Public::new(<$uint_type>::ONE)
+                                    .shl_vartime(exponent)
+                                    .as_inner(),
+                            )
+                        }
+                    };
+                    i += 1;
+                }
+
+                // Check that the terms sum up to a positive value below `2^(bits - 2)`
+                let sum = top.wrapping_sub(&res);
+                if res.bits() != bits || sum.bits() + 2 > bits {
+                    panic!("terms must sum up to a positive value smaller than `2^(bits - 2)`");
+                }
+
+                // Check that the modulus is odd
+                if res.as_limbs()[0].0 & 1 == 0 {
+                    panic!("modulus must be odd");
+                }
+
+                res
+            };
+            const REDUCTION: $crate::modular::SpecialReduction =
+                $crate::modular::SpecialReduction::Solinas {
+                    bits: $bits,
+                    terms: &[$($crate::impl_solinas_modulus!(@term $sign $exponent)),+],
+                };
+        }
+    };
+}
@@ -0,0 +1,77 @@
+//! Multiplications between residues with a constant special-form modulus.
+
+use super::{SpecialResidue, SpecialResidueParams};
+use crate::traits::Square;
+use core::ops::{Mul, MulAssign};
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SpecialResidue<MOD, LIMBS> {
+    /// Multiplies by `rhs`.
+    pub const fn mul(&self, rhs: &Self) -> Self {
+        Self::from_wide(&self.value.mul_wide(&rhs.value))
+    }
+
+    /// Computes the (reduced) square of a residue.
+    pub const fn square(&self) -> Self {
+        Self::from_wide(&self.value.square_wide())
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Mul<&SpecialResidue<MOD, LIMBS>>
+    for &SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    fn mul(self, rhs: &SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        self.mul(rhs)
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Mul<SpecialResidue<MOD, LIMBS>>
+    for &SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn mul(self, rhs: SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        self * &rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Mul<&SpecialResidue<MOD, LIMBS>>
+    for SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn mul(self, rhs: &SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        &self * rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Mul<SpecialResidue<MOD, LIMBS>>
+    for SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    fn mul(self, rhs: SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        &self * &rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> MulAssign<&Self>
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn mul_assign(&mut self, rhs: &Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> MulAssign<Self>
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn mul_assign(&mut self, rhs: Self) {
+        *self *= &rhs;
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Square for SpecialResidue<MOD, LIMBS> {
+    fn square(&self) -> Self {
+        SpecialResidue::square(self)
+    }
+}
@@ -0,0 +1,25 @@
+//! Negations of residues with a constant special-form modulus.
+
+use super::{SpecialResidue, SpecialResidueParams};
+use core::ops::Neg;
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SpecialResidue<MOD, LIMBS> {
+    /// Negates the number.
+    pub const fn neg(&self) -> Self {
+        Self::ZERO.sub(self)
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Neg for SpecialResidue<MOD, LIMBS> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        SpecialResidue::neg(&self)
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Neg for &SpecialResidue<MOD, LIMBS> {
+    type Output = SpecialResidue<MOD, LIMBS>;
+    fn neg(self) -> SpecialResidue<MOD, LIMBS> {
+        SpecialResidue::neg(self)
+    }
+}
@@ -0,0 +1,42 @@
+//! Exponentiation of residues with a constant special-form modulus.
+
+use super::{SpecialResidue, SpecialResidueParams};
+use crate::{PowBoundedExp, Uint};
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SpecialResidue<MOD, LIMBS> {
+    /// Raises to the `exponent` power.
+    pub const fn pow<const RHS_LIMBS: usize>(&self, exponent: &Uint<RHS_LIMBS>) -> Self {
+        self.pow_bounded_exp(exponent, Uint::<RHS_LIMBS>::BITS)
+    }
+
+    /// Raises to the `exponent` power,
+    /// with `exponent_bits` representing the number of (least significant) bits
+    /// to take into account for the exponent.
+    ///
+    /// NOTE: `exponent_bits` may be leaked in the time pattern.
+    pub const fn pow_bounded_exp<const RHS_LIMBS: usize>(
+        &self,
+        exponent: &Uint<RHS_LIMBS>,
+        exponent_bits: u32,
+    ) -> Self {
+        let mut acc = Self::ONE;
+        let mut i = exponent_bits;
+
+        while i > 0 {
+            i -= 1;
+            acc = acc.square();
+            let product = acc.mul(self);
+            acc.value = Uint::ct_select(&acc.value, &product.value, exponent.bit(i));
+        }
+
+        acc
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize, const RHS_LIMBS: usize>
+    PowBoundedExp<Uint<RHS_LIMBS>> for SpecialResidue<MOD, LIMBS>
+{
+    fn pow_bounded_exp(&self, exponent: &Uint<RHS_LIMBS>, exponent_bits: u32) -> Self {
+        self.pow_bounded_exp(exponent, exponent_bits)
+    }
+}
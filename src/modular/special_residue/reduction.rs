@@ -0,0 +1,284 @@
+//! Reduction algorithms for special-form moduli.
+
+use super::{SolinasTerm, SpecialReduction};
+use crate::{uint::mul_mod::mac_by_limb, CtChoice, Limb, Uint, Word};
+
+/// Number of bits in the digits used by the Solinas reduction.
+const DIGIT_BITS: u32 = 32;
+
+/// Mask selecting the lower [`DIGIT_BITS`] bits of a digit.
+const DIGIT_MASK: i64 = (1 << DIGIT_BITS) - 1;
+
+/// Upper bound on the magnitude of the digits after folding, in units of `2^DIGIT_BITS`, which
+/// guarantees that the `i64` accumulators cannot overflow.
+const MAX_DIGIT_BOUND: u64 = 1 << 29;
+
+/// Signed digits of a double-width integer, stored as `[digit / 4][digit % 4]` so that the array
+/// length does not depend on an expression of `LIMBS`.
+type Digits<const LIMBS: usize> = [[i64; 4]; LIMBS];
+
+/// Reduces the wide integer `lower_upper` modulo `modulus`, which must be of the form described
+/// by `reduction`. `carry_folds` is the number of folds of the top carry required by a Solinas
+/// reduction, as computed by [`solinas_carry_folds`].
+///
+/// Assumes `lower_upper < modulus^2`.
+#[inline(always)]
+pub(crate) const fn special_reduction<const LIMBS: usize>(
+    lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    reduction: &SpecialReduction,
+    carry_folds: u32,
+) -> Uint<LIMBS> {
+    match reduction {
+        SpecialReduction::PseudoMersenne { bits, c } => {
+            pseudo_mersenne_reduction(lower_upper, modulus, *bits, *c)
+        }
+        SpecialReduction::Solinas { bits, terms } => {
+            solinas_reduction(lower_upper, modulus, *bits, terms, carry_folds)
+        }
+    }
+}
+
+/// Reduces the wide integer `lower_upper` modulo the pseudo-Mersenne prime
+/// `modulus = 2^bits - c`, where `c * 2^(Uint::<LIMBS>::BITS - bits)` fits in a single [`Limb`]
+/// and is smaller than `2^(Uint::<LIMBS>::BITS / 2)`.
+///
+/// NOTE: this operation is variable time with respect to `bits` and `c` *ONLY*.
+pub(crate) const fn pseudo_mersenne_reduction<const LIMBS: usize>(
+    lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    bits: u32,
+    c: Limb,
+) -> Uint<LIMBS> {
+    // Folding at the full width avoids shifting the wide integer: since `2^bits = c mod modulus`,
+    // `2^BITS = c_full mod modulus`, where `c_full = c * 2^(BITS - bits)`.
+    let top_bits = Uint::<LIMBS>::BITS - bits;
+    let c_full = Limb(c.0 << top_bits);
+
+    // `lo + 2^BITS * hi = lo + c_full * hi`, leaving a carry which is at most `c_full`...
+    let (lo, hi) = lower_upper;
+    let (lo, carry) = mac_by_limb(lo, hi, c_full, Limb::ZERO);
+
+    // ...and folding it once more leaves a carry of at most one, in which case `lo < c_full^2`.
+    let (lo, carry) = mac_by_limb(&lo, &Uint::from_word(carry.0), c_full, Limb::ZERO);
+    let lo = lo.wrapping_add(&Uint::from_word(c_full.0 & carry.0.wrapping_neg()));
+
+    // Now fold the bits of `lo` above `bits`, which all reside in the top limb, leaving a value
+    // below `2^bits + c_full < 2 * modulus`.
+    let lo = if top_bits == 0 {
+        lo
+    } else {
+        let shift = Limb::BITS - top_bits;
+        let mut limbs = lo.limbs;
+        let top = limbs[LIMBS - 1].0 >> shift;
+        limbs[LIMBS - 1].0 &= Word::MAX >> top_bits;
+        Uint::new(limbs).wrapping_add(&Uint::from_word(top * c.0))
+    };
+
+    lo.sub_mod_with_carry(Limb::ZERO, modulus, modulus)
+}
+
+/// Reduces the wide integer `lower_upper` modulo the generalized Mersenne (Solinas) prime
+/// `modulus = 2^bits - f`, where `f` is the sum of the given signed powers of two `terms`.
+///
+/// Assumes `lower_upper < 2^(2 * bits)`, that `bits` and all the exponents in `terms` are
+/// multiples of 32, and that `0 < f < 2^(bits - 2)`.
+///
+/// NOTE: this operation is variable time with respect to `bits` and `terms` *ONLY*.
+#[inline(always)]
+pub(crate) const fn solinas_reduction<const LIMBS: usize>(
+    lower_upper: &(Uint<LIMBS>, Uint<LIMBS>),
+    modulus: &Uint<LIMBS>,
+    bits: u32,
+    terms: &[SolinasTerm],
+    carry_folds: u32,
+) -> Uint<LIMBS> {
+    let n = (bits / DIGIT_BITS) as usize;
+    let mut digits: Digits<LIMBS> = [[0; 4]; LIMBS];
+
+    let mut k = 0;
+    while k < 2 * n {
+        digits[k / 4][k % 4] = wide_digit(lower_upper, k);
+        k += 1;
+    }
+
+    // Fold the digits above `bits` from the top down, replacing `d * 2^bits` with `d * f`.
+    // Since the exponents of `f` are multiples of the digit size, each term only affects a single
+    // digit, and the signed accumulators absorb the carries until the end.
+    let mut k = 2 * n;
+    while k > n {
+        k -= 1;
+        let d = digits[k / 4][k % 4];
+        digits[k / 4][k % 4] = 0;
+        digits = add_terms(digits, k - n, d, terms);
+    }
+
+    let (mut digits, mut carry) = propagate_carries(digits, n);
+
+    // The value is now `digits + carry * 2^bits`, which is folded until `carry` is in `{-1, 0, 1}`
+    // and the value lies in `(-2^(bits - 2), 2^bits + 2^(bits - 2))`. The number of folds only
+    // depends on the (public) shape of the modulus.
+    let mut i = 0;
+    while i < carry_folds {
+        digits = add_terms(digits, 0, carry, terms);
+        (digits, carry) = propagate_carries(digits, n);
+        i += 1;
+    }
+
+    let mut res = Uint::<LIMBS>::ZERO;
+    let mut k = 0;
+    while k < n {
+        let bit = k * DIGIT_BITS as usize;
+        res.limbs[bit / Limb::BITS as usize].0 |=
+            (digits[k / 4][k % 4] as Word) << (bit % Limb::BITS as usize);
+        k += 1;
+    }
+
+    // Bring the value into `[0, modulus)` depending on the remaining carry.
+    let f = Uint::<LIMBS>::ONE.shl_vartime(bits).wrapping_sub(modulus);
+    let carry_is_nonzero = CtChoice::from_word_lsb((carry & 1) as Word);
+    let carry_is_negative = CtChoice::from_word_lsb(((carry >> 1) & 1) as Word);
+
+    let res_zero = res.sub_mod_with_carry(Limb::ZERO, modulus, modulus);
+    let res_positive = res.wrapping_add(&f);
+    let res_negative = res.wrapping_sub(&f);
+
+    let res_nonzero = Uint::ct_select(&res_positive, &res_negative, carry_is_negative);
+    Uint::ct_select(&res_zero, &res_nonzero, carry_is_nonzero)
+}
+
+/// Returns the number of folds of the top carry required by the Solinas reduction for the
+/// given modulus shape.
+///
+/// Panics if the digits can grow too large during the reduction, which can only happen if `f`
+/// has many terms.
+pub(crate) const fn solinas_carry_folds<const LIMBS: usize>(reduction: &SpecialReduction) -> u32 {
+    let (bits, terms) = match reduction {
+        SpecialReduction::PseudoMersenne { .. } => return 0,
+        SpecialReduction::Solinas { bits, terms } => (*bits, *terms),
+    };
+
+    // Bound the digits after the initial folding, in units of `2^DIGIT_BITS`, by simulating it.
+    let n = (bits / DIGIT_BITS) as usize;
+    let mut bounds = [[0u64; 4]; LIMBS];
+
+    let mut k = 0;
+    while k < 2 * n {
+        bounds[k / 4][k % 4] = 1;
+        k += 1;
+    }
+
+    let mut max = 1;
+    let mut k = 2 * n;
+    while k > n {
+        k -= 1;
+        let bound = bounds[k / 4][k % 4];
+
+        let mut i = 0;
+        while i < terms.len() {
+            let j = k - n + (term_exponent(terms[i]) / DIGIT_BITS) as usize;
+            bounds[j / 4][j % 4] = bounds[j / 4][j % 4].saturating_add(bound);
+            if bounds[j / 4][j % 4] > max {
+                max = bounds[j / 4][j % 4];
+            }
+            i += 1;
+        }
+    }
+
+    assert!(
+        max <= MAX_DIGIT_BOUND,
+        "too many terms in the Solinas modulus"
+    );
+
+    // The first carry is at most `top + 2`, where `top` bounds the top digit, and each fold of a
+    // carry `c` leaves a carry of at most `c * f / 2^bits + 1`. One more fold then shrinks the
+    // value to the range expected by the final correction.
+    let top = bounds[(n - 1) / 4][(n - 1) % 4];
+    let f_shift = bits - max_exponent(terms);
+    let mut carry = top + 2;
+    let mut folds = 1;
+    while carry > 1 {
+        let scaled = carry.saturating_mul(terms.len() as u64);
+        carry = if f_shift < u64::BITS {
+            scaled >> f_shift
+        } else {
+            0
+        } + 1;
+        folds += 1;
+    }
+
+    folds
+}
+
+/// Returns the `k`-th [`DIGIT_BITS`]-bit digit of the wide integer `lower_upper`.
+#[inline(always)]
+const fn wide_digit<const LIMBS: usize>(lower_upper: &(Uint<LIMBS>, Uint<LIMBS>), k: usize) -> i64 {
+    let bit = k * DIGIT_BITS as usize;
+    let limb = bit / Limb::BITS as usize;
+    let limb = if limb < LIMBS {
+        lower_upper.0.limbs[limb]
+    } else {
+        lower_upper.1.limbs[limb - LIMBS]
+    };
+    let digit = (limb.0 >> (bit % Limb::BITS as usize)) as i64;
+    digit & DIGIT_MASK
+}
+
+/// Adds `d * 2^(offset * DIGIT_BITS) * f` to `digits`, where `f` is the sum of `terms`.
+#[inline(always)]
+const fn add_terms<const LIMBS: usize>(
+    mut digits: Digits<LIMBS>,
+    offset: usize,
+    d: i64,
+    terms: &[SolinasTerm],
+) -> Digits<LIMBS> {
+    let mut i = 0;
+    while i < terms.len() {
+        let j = offset + (term_exponent(terms[i]) / DIGIT_BITS) as usize;
+        digits[j / 4][j % 4] = match terms[i] {
+            SolinasTerm::Plus(_) => digits[j / 4][j % 4] + d,
+            SolinasTerm::Minus(_) => digits[j / 4][j % 4] - d,
+        };
+        i += 1;
+    }
+    digits
+}
+
+/// Normalizes the lowest `n` digits to `[0, 2^DIGIT_BITS)`, returning the signed carry out of
+/// the top one. The digits above `n` are assumed to be zero.
+#[inline(always)]
+const fn propagate_carries<const LIMBS: usize>(
+    mut digits: Digits<LIMBS>,
+    n: usize,
+) -> (Digits<LIMBS>, i64) {
+    let mut carry = 0;
+    let mut k = 0;
+    while k < n {
+        let value = digits[k / 4][k % 4] + carry;
+        digits[k / 4][k % 4] = value & DIGIT_MASK;
+        carry = value >> DIGIT_BITS;
+        k += 1;
+    }
+    (digits, carry)
+}
+
+/// Returns the largest exponent among `terms`.
+const fn max_exponent(terms: &[SolinasTerm]) -> u32 {
+    let mut max = 0;
+    let mut i = 0;
+    while i < terms.len() {
+        let exponent = term_exponent(terms[i]);
+        if exponent > max {
+            max = exponent;
+        }
+        i += 1;
+    }
+    max
+}
+
+/// Returns the exponent of the given term.
+const fn term_exponent(term: SolinasTerm) -> u32 {
+    match term {
+        SolinasTerm::Plus(exponent) | SolinasTerm::Minus(exponent) => exponent,
+    }
+}
@@ -0,0 +1,71 @@
+//! Subtractions between residues with a constant special-form modulus.
+
+use super::{SpecialResidue, SpecialResidueParams};
+use core::{
+    marker::PhantomData,
+    ops::{Sub, SubAssign},
+};
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SpecialResidue<MOD, LIMBS> {
+    /// Subtracts `rhs`.
+    pub const fn sub(&self, rhs: &Self) -> Self {
+        Self {
+            value: self.value.sub_mod(&rhs.value, &MOD::MODULUS),
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Sub<&SpecialResidue<MOD, LIMBS>>
+    for &SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    fn sub(self, rhs: &SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        self.sub(rhs)
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Sub<SpecialResidue<MOD, LIMBS>>
+    for &SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn sub(self, rhs: SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        self - &rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Sub<&SpecialResidue<MOD, LIMBS>>
+    for SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    #[allow(clippy::op_ref)]
+    fn sub(self, rhs: &SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        &self - rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> Sub<SpecialResidue<MOD, LIMBS>>
+    for SpecialResidue<MOD, LIMBS>
+{
+    type Output = SpecialResidue<MOD, LIMBS>;
+    fn sub(self, rhs: SpecialResidue<MOD, LIMBS>) -> SpecialResidue<MOD, LIMBS> {
+        &self - &rhs
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SubAssign<&Self>
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn sub_assign(&mut self, rhs: &Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<MOD: SpecialResidueParams<LIMBS>, const LIMBS: usize> SubAssign<Self>
+    for SpecialResidue<MOD, LIMBS>
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self -= &rhs;
+    }
+}
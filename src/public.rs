@@ -0,0 +1,99 @@
+//! Wrapper type for public values.
+
+use core::fmt;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+/// Wrapper type for values which are explicitly declared public, i.e. not secret.
+///
+/// The variable-time operations of this crate leak their inputs through the time they take to
+/// execute. `Public<T>` makes that distinction visible in the type system: variable-time
+/// operations are only available on `Public<Uint>`, `Public<BoxedUint>` and `Public<BoundedUint>`,
+/// and take `Public` operands where only one of the inputs has to be public, such as the divisor
+/// of a division or the exponent of a modular exponentiation. Secret values can thus only reach
+/// them through an explicit call to [`Public::new`].
+///
+/// Constant-time operations are reached through [`Public::as_inner`], and [`Public::into_inner`]
+/// converts back to the wrapped value at no cost.
+///
+/// ```
+/// use crypto_bigint::{NonZero, Public, U64};
+///
+/// let x = Public::new(U64::from_u8(40));
+/// assert_eq!(x.bits_vartime(), 6);
+/// assert_eq!(x.shr_vartime(3).into_inner(), U64::from_u8(5));
+///
+/// // Dividing a secret value by a public divisor
+/// let secret = U64::from_u8(45);
+/// let divisor = Public::new(NonZero::new(U64::from_u8(10)).unwrap());
+/// assert_eq!(secret % &divisor, U64::from_u8(5));
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, PartialEq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct Public<T>(T);
+
+impl<T> Public<T> {
+    /// Declares `value` public, allowing it to be used with variable-time operations.
+    pub const fn new(value: T) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the wrapped value.
+    pub const fn as_inner(&self) -> &T {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the wrapped value.
+    ///
+    /// Crate-private so that secret data can't be written into a `Public` without [`Public::new`].
+    #[cfg(feature = "alloc")]
+    pub(crate) fn as_inner_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+
+    /// Returns the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> AsRef<T> for Public<T> {
+    fn as_ref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Public<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::Binary> fmt::Binary for Public<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::LowerHex> fmt::LowerHex for Public<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: fmt::UpperHex> fmt::UpperHex for Public<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: ConditionallySelectable> ConditionallySelectable for Public<T> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self(T::conditional_select(&a.0, &b.0, choice))
+    }
+}
+
+impl<T: ConstantTimeEq> ConstantTimeEq for Public<T> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
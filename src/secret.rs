@@ -0,0 +1,197 @@
+//! Wrapper types for secret integers.
+
+use crate::Uint;
+use core::fmt;
+use subtle::{Choice, ConstantTimeEq};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+#[cfg(feature = "alloc")]
+use crate::BoxedUint;
+
+#[cfg(feature = "rand_core")]
+use {crate::Random, rand_core::CryptoRngCore};
+
+/// Wrapper type for secret [`Uint`]s, e.g. private keys or nonces.
+///
+/// The wrapped value is zeroized when dropped, and redacted from [`Debug`](fmt::Debug) output.
+/// It can only be accessed through the explicit [`SecretUint::expose_secret`] and
+/// [`SecretUint::expose_secret_mut`] accessors, which makes its uses easy to audit.
+///
+/// ```
+/// use crypto_bigint::{SecretUint, U64};
+///
+/// let key = SecretUint::new(U64::from_u64(0x1234_5678));
+/// assert_eq!(format!("{:?}", key), "SecretUint([REDACTED])");
+/// assert_eq!(key.expose_secret(), &U64::from_u64(0x1234_5678));
+/// ```
+#[derive(Clone, Default)]
+pub struct SecretUint<const LIMBS: usize>(Uint<LIMBS>);
+
+impl<const LIMBS: usize> SecretUint<LIMBS> {
+    /// Wraps the secret `value`.
+    pub const fn new(value: Uint<LIMBS>) -> Self {
+        Self(value)
+    }
+
+    /// Returns a reference to the secret value.
+    pub fn expose_secret(&self) -> &Uint<LIMBS> {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the secret value.
+    pub fn expose_secret_mut(&mut self) -> &mut Uint<LIMBS> {
+        &mut self.0
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for SecretUint<LIMBS> {
+    fn from(value: Uint<LIMBS>) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<const LIMBS: usize> ConstantTimeEq for SecretUint<LIMBS> {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Debug for SecretUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretUint([REDACTED])")
+    }
+}
+
+#[cfg(feature = "rand_core")]
+impl<const LIMBS: usize> Random for SecretUint<LIMBS> {
+    fn random(rng: &mut impl CryptoRngCore) -> Self {
+        Self(Uint::random(rng))
+    }
+}
+
+impl<const LIMBS: usize> Zeroize for SecretUint<LIMBS> {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+impl<const LIMBS: usize> Drop for SecretUint<LIMBS> {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+impl<const LIMBS: usize> ZeroizeOnDrop for SecretUint<LIMBS> {}
+
+/// Wrapper type for secret [`BoxedUint`]s, e.g. private keys or nonces.
+///
+/// The wrapped value is zeroized when dropped, and redacted from [`Debug`](fmt::Debug) output.
+/// It can only be accessed through the explicit [`SecretBoxedUint::expose_secret`] and
+/// [`SecretBoxedUint::expose_secret_mut`] accessors, which makes its uses easy to audit.
+#[cfg(feature = "alloc")]
+#[derive(Clone)]
+pub struct SecretBoxedUint(BoxedUint);
+
+#[cfg(feature = "alloc")]
+impl SecretBoxedUint {
+    /// Wraps the secret `value`.
+    pub fn new(value: BoxedUint) -> Self {
+        Self(value)
+    }
+
+    /// Generates a random secret with the given precision. See [`BoxedUint::random`].
+    #[cfg(feature = "rand_core")]
+    pub fn random(rng: &mut impl CryptoRngCore, bits_precision: u32) -> Self {
+        Self(BoxedUint::random(rng, bits_precision))
+    }
+
+    /// Returns a reference to the secret value.
+    pub fn expose_secret(&self) -> &BoxedUint {
+        &self.0
+    }
+
+    /// Returns a mutable reference to the secret value.
+    pub fn expose_secret_mut(&mut self) -> &mut BoxedUint {
+        &mut self.0
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<BoxedUint> for SecretBoxedUint {
+    fn from(value: BoxedUint) -> Self {
+        Self::new(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ConstantTimeEq for SecretBoxedUint {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.0.ct_eq(&other.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for SecretBoxedUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SecretBoxedUint([REDACTED])")
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Zeroize for SecretBoxedUint {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Drop for SecretBoxedUint {
+    fn drop(&mut self) {
+        self.zeroize();
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl ZeroizeOnDrop for SecretBoxedUint {}
+
+#[cfg(test)]
+mod tests {
+    use super::SecretUint;
+    use crate::U128;
+    use subtle::ConstantTimeEq;
+    use zeroize::Zeroize;
+
+    #[cfg(feature = "alloc")]
+    use {super::SecretBoxedUint, crate::BoxedUint};
+
+    #[test]
+    fn secret_uint() {
+        let n = U128::from_u64(0x1234_5678);
+        let mut secret = SecretUint::new(n);
+        assert!(bool::from(secret.ct_eq(&SecretUint::from(n))));
+        assert_eq!(secret.expose_secret(), &n);
+
+        *secret.expose_secret_mut() = U128::ONE;
+        assert_eq!(secret.expose_secret(), &U128::ONE);
+
+        secret.zeroize();
+        assert_eq!(secret.expose_secret(), &U128::ZERO);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn secret_boxed_uint() {
+        let n = BoxedUint::from(0x1234_5678u64);
+        let mut secret = SecretBoxedUint::new(n.clone());
+        assert!(bool::from(secret.ct_eq(&SecretBoxedUint::from(n.clone()))));
+        assert_eq!(secret.expose_secret(), &n);
+        assert_eq!(
+            alloc::format!("{:?}", secret),
+            "SecretBoxedUint([REDACTED])"
+        );
+
+        secret.zeroize();
+        assert!(bool::from(secret.expose_secret().is_zero()));
+        assert_eq!(secret.expose_secret().bits_precision(), 64);
+    }
+}
@@ -79,10 +79,6 @@ pub trait Integer:
     /// Calculate the number of bits required to represent a given number.
     fn bits(&self) -> u32;
 
-    /// Calculate the number of bits required to represent a given number in variable-time with
-    /// respect to `self`.
-    fn bits_vartime(&self) -> u32;
-
     /// Precision of this integer in bits.
     fn bits_precision(&self) -> u32;
 
@@ -417,6 +413,89 @@ pub trait Invert: Sized {
     fn invert(&self) -> Self::Output;
 }
 
+/// Constant-time table lookup and selection.
+///
+/// These scan the entire table regardless of the index or choice, and are suitable for e.g.
+/// fixed-window scalar multiplication or S-box style lookups.
+pub trait ConstantTimeLookup: Sized {
+    /// Returns `table[index]`, scanning the full table in constant time.
+    ///
+    /// The result is none if `index` is out of bounds.
+    ///
+    /// Panics if `table` is empty.
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self>;
+
+    /// Assigns `b[i]` to `out[i]` if `choice` is truthy, or `a[i]` otherwise.
+    ///
+    /// Panics if the lengths of `out`, `a` and `b` don't match.
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice);
+}
+
+/// [`ConstantTimeLookup::ct_lookup`] for [`ConditionallySelectable`] types.
+pub(crate) fn ct_lookup_selectable<T: ConditionallySelectable>(
+    table: &[T],
+    index: usize,
+) -> CtOption<T> {
+    ct_lookup_with(table, index, T::conditional_assign)
+}
+
+/// [`ConstantTimeLookup::ct_select_slice`] for [`ConditionallySelectable`] types.
+pub(crate) fn ct_select_slice_selectable<T: ConditionallySelectable>(
+    out: &mut [T],
+    a: &[T],
+    b: &[T],
+    choice: Choice,
+) {
+    ct_select_slice_with(out, a, b, choice, T::conditional_select)
+}
+
+/// [`ConstantTimeLookup::ct_lookup`] for types which can't be [`ConditionallySelectable`] because
+/// they aren't [`Copy`], given their equivalent of [`ConditionallySelectable::conditional_assign`].
+pub(crate) fn ct_lookup_with<T: Clone>(
+    table: &[T],
+    index: usize,
+    conditional_assign: impl Fn(&mut T, &T, Choice),
+) -> CtOption<T> {
+    let mut ret = table[0].clone();
+    let index = index as u64;
+
+    for (i, item) in table.iter().enumerate().skip(1) {
+        conditional_assign(&mut ret, item, (i as u64).ct_eq(&index));
+    }
+
+    CtOption::new(ret, index.ct_lt(&(table.len() as u64)))
+}
+
+/// [`ConstantTimeLookup::ct_select_slice`] for types which can't be [`ConditionallySelectable`]
+/// because they aren't [`Copy`], given their equivalent of
+/// [`ConditionallySelectable::conditional_select`].
+pub(crate) fn ct_select_slice_with<T>(
+    out: &mut [T],
+    a: &[T],
+    b: &[T],
+    choice: Choice,
+    conditional_select: impl Fn(&T, &T, Choice) -> T,
+) {
+    assert_eq!(out.len(), a.len(), "length mismatch");
+    assert_eq!(out.len(), b.len(), "length mismatch");
+
+    for ((out, a), b) in out.iter_mut().zip(a).zip(b) {
+        *out = conditional_select(a, b, choice);
+    }
+}
+
+/// Constant-time batch inversion using Montgomery's trick, which computes the inverses of `n`
+/// elements using a single inversion and `3(n - 1)` multiplications.
+///
+/// Zero elements are mapped to zero, without leaking which of the elements were zero.
+pub trait BatchInvert<Elements: ?Sized>: Sized {
+    /// Output of the batch inversion.
+    type Output;
+
+    /// Computes the inverses of all of the given `elements`.
+    fn batch_invert(elements: &Elements) -> Self::Output;
+}
+
 /// Widening multiply: returns a value with a number of limbs equal to the sum of the inputs.
 pub trait WideningMul<Rhs = Self>: Sized {
     /// Output of the widening multiplication.
@@ -12,18 +12,23 @@ mod bit_not;
 mod bit_or;
 mod bit_xor;
 mod bits;
+mod bounded;
 mod cmp;
 mod concat;
+pub(crate) mod digits;
 mod div;
 pub(crate) mod div_limb;
+pub(crate) mod div_reciprocal;
 mod encoding;
 mod from;
 mod inv_mod;
 pub(crate) mod mul;
-mod mul_mod;
+pub(crate) mod mul_mod;
 mod neg;
 mod neg_mod;
+mod public;
 mod resize;
+mod rfc6979;
 mod shl;
 mod shr;
 mod split;
@@ -31,6 +36,9 @@ mod sqrt;
 mod sub;
 mod sub_mod;
 
+#[cfg(feature = "num-traits")]
+mod num_traits;
+
 #[cfg(feature = "generic-array")]
 mod array;
 #[cfg(feature = "alloc")]
@@ -38,9 +46,14 @@ pub(crate) mod boxed;
 #[cfg(feature = "rand_core")]
 mod rand;
 
-use crate::{Bounded, Constants, Encoding, FixedInteger, Integer, Limb, Word, ZeroConstant};
+pub use self::{bounded::BoundedUint, encoding::DecodeError};
+
+use crate::{
+    traits::ct_select_slice_selectable, Bounded, ConstantTimeLookup, Constants, Encoding,
+    FixedInteger, Integer, Limb, Word, ZeroConstant,
+};
 use core::fmt;
-use subtle::{Choice, ConditionallySelectable};
+use subtle::{Choice, ConditionallySelectable, CtOption};
 
 #[cfg(feature = "serde")]
 use serdect::serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -203,6 +216,16 @@ impl<const LIMBS: usize> ConditionallySelectable for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> ConstantTimeLookup for Uint<LIMBS> {
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        Self::const_ct_lookup(table, index).into()
+    }
+
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_selectable(out, a, b, choice)
+    }
+}
+
 impl<const LIMBS: usize> Bounded for Uint<LIMBS> {
     const BITS: u32 = Self::BITS;
     const BYTES: usize = Self::BYTES;
@@ -232,10 +255,6 @@ impl<const LIMBS: usize> Integer for Uint<LIMBS> {
         self.bits()
     }
 
-    fn bits_vartime(&self) -> u32 {
-        self.bits_vartime()
-    }
-
     fn bits_precision(&self) -> u32 {
         Self::BITS
     }
@@ -411,11 +430,14 @@ mod extra_sizes;
 #[cfg(feature = "extra-sizes")]
 pub use extra_sizes::*;
 
+#[cfg(feature = "num-traits")]
+pub use self::num_traits::FromStrRadixError;
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
-    use crate::{Encoding, U128};
-    use subtle::ConditionallySelectable;
+    use crate::{ConstantTimeLookup, Encoding, U128};
+    use subtle::{Choice, ConditionallySelectable};
 
     #[cfg(feature = "alloc")]
     use alloc::format;
@@ -485,6 +507,39 @@ mod tests {
         assert_eq!(b, select_1);
     }
 
+    #[test]
+    fn ct_lookup() {
+        let table = [U128::ZERO, U128::ONE, U128::MAX];
+
+        for (i, expected) in table.iter().enumerate() {
+            assert_eq!(&U128::ct_lookup(&table, i).unwrap(), expected);
+        }
+
+        assert!(bool::from(U128::ct_lookup(&table, 3).is_none()));
+    }
+
+    #[test]
+    fn const_ct_lookup() {
+        const TABLE: [U128; 3] = [U128::ZERO, U128::ONE, U128::MAX];
+        const MAX: U128 = U128::const_ct_lookup(&TABLE, 2).unwrap();
+
+        assert_eq!(MAX, U128::MAX);
+        assert!(!U128::const_ct_lookup(&TABLE, 3).is_some().is_true_vartime());
+    }
+
+    #[test]
+    fn ct_select_slice() {
+        let a = [U128::ZERO, U128::ONE];
+        let b = [U128::MAX, U128::from_u8(2)];
+        let mut out = [U128::ZERO; 2];
+
+        U128::ct_select_slice(&mut out, &a, &b, Choice::from(0));
+        assert_eq!(out, a);
+
+        U128::ct_select_slice(&mut out, &a, &b, Choice::from(1));
+        assert_eq!(out, b);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn serde() {
@@ -27,7 +27,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// # Remarks
     /// This operation is variable time with respect to `index` only.
     #[inline(always)]
-    pub const fn bit_vartime(&self, index: u32) -> bool {
+    pub(crate) const fn bit_vartime(&self, index: u32) -> bool {
         if index >= Self::BITS {
             false
         } else {
@@ -43,7 +43,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Calculate the number of bits needed to represent this number in variable-time with respect
     /// to `self`.
-    pub const fn bits_vartime(&self) -> u32 {
+    pub(crate) const fn bits_vartime(&self) -> u32 {
         let mut i = LIMBS - 1;
         while i > 0 && self.limbs[i].0 == 0 {
             i -= 1;
@@ -74,7 +74,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Calculate the number of leading zeros in the binary representation of this number in
     /// variable-time with respect to `self`.
-    pub const fn leading_zeros_vartime(&self) -> u32 {
+    pub(crate) const fn leading_zeros_vartime(&self) -> u32 {
         let limbs = self.as_limbs();
 
         let mut count = 0;
@@ -113,7 +113,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Calculate the number of trailing zeros in the binary representation of this number in
     /// variable-time with respect to `self`.
-    pub const fn trailing_zeros_vartime(&self) -> u32 {
+    pub(crate) const fn trailing_zeros_vartime(&self) -> u32 {
         let limbs = self.as_limbs();
 
         let mut count = 0;
@@ -152,7 +152,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Calculate the number of trailing ones in the binary representation of this number,
     /// variable time in `self`.
-    pub const fn trailing_ones_vartime(&self) -> u32 {
+    pub(crate) const fn trailing_ones_vartime(&self) -> u32 {
         let limbs = self.as_limbs();
 
         let mut count = 0;
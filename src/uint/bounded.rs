@@ -0,0 +1,401 @@
+//! Stack-allocated big unsigned integers with a precision chosen at runtime.
+
+mod add;
+mod bits;
+mod cmp;
+mod ct;
+mod div;
+mod encoding;
+mod inv_mod;
+mod mul;
+mod public;
+mod shl;
+mod shr;
+mod sub;
+
+use crate::{Limb, Uint, Word, Zero, U128, U64};
+use core::fmt;
+use subtle::{Choice, ConstantTimeEq};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+/// Fixed-precision stack-allocated big unsigned integer, with a precision chosen at runtime.
+///
+/// Alternative to `BoxedUint` for targets without a heap: the limbs are stored inline, in an array
+/// of `MAX_LIMBS` limbs of which only the first [`BoundedUint::nlimbs`] are in use. This makes it
+/// possible to pick the precision at runtime, e.g. from the size of an RSA modulus, up to a
+/// maximum chosen at compile time.
+///
+/// Like `BoxedUint`, this type is not arbitrary precision and will wrap at its fixed precision
+/// rather than automatically growing. Operations on integers with different precisions produce
+/// results with the larger of the two precisions, unless documented otherwise.
+///
+/// Functions which create a [`BoundedUint`] with a given precision panic if it exceeds
+/// [`BoundedUint::MAX_BITS`].
+///
+/// It only provides the subset of the `BoxedUint` API needed for modular arithmetic with
+/// `BoundedResidue`, such as RSA signature verification: arithmetic, comparisons, shifts, division,
+/// encoding and inversion modulo an odd modulus. Random generation, square roots and inversion
+/// modulo an even modulus are not provided.
+// Our PartialEq impl only differs from the default one by being constant-time, so this is safe
+#[allow(clippy::derived_hash_with_manual_eq)]
+#[derive(Copy, Clone, Hash)]
+pub struct BoundedUint<const MAX_LIMBS: usize> {
+    /// Inner limb array. Stored from least significant to most significant.
+    ///
+    /// Limbs past `nlimbs` are always zero.
+    pub(crate) limbs: [Limb; MAX_LIMBS],
+
+    /// Number of limbs in use, i.e. the precision in limbs.
+    pub(crate) nlimbs: usize,
+}
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Maximum precision of this type in bits.
+    pub const MAX_BITS: u32 = MAX_LIMBS as u32 * Limb::BITS;
+
+    fn limbs_for_precision(at_least_bits_precision: u32) -> usize {
+        assert!(
+            at_least_bits_precision <= Self::MAX_BITS,
+            "precision exceeds the maximum precision"
+        );
+        ((at_least_bits_precision + Limb::BITS - 1) / Limb::BITS) as usize
+    }
+
+    /// Get the value `0` represented as succinctly as possible.
+    pub fn zero() -> Self {
+        Self::zero_with_precision(Limb::BITS)
+    }
+
+    /// Get the value `0` with the given number of bits of precision.
+    ///
+    /// `at_least_bits_precision` is rounded up to a multiple of [`Limb::BITS`].
+    pub fn zero_with_precision(at_least_bits_precision: u32) -> Self {
+        Self {
+            limbs: [Limb::ZERO; MAX_LIMBS],
+            nlimbs: Self::limbs_for_precision(at_least_bits_precision),
+        }
+    }
+
+    /// Get the value `1`, represented as succinctly as possible.
+    pub fn one() -> Self {
+        Self::one_with_precision(Limb::BITS)
+    }
+
+    /// Get the value `1` with the given number of bits of precision.
+    ///
+    /// `at_least_bits_precision` is rounded up to a multiple of [`Limb::BITS`].
+    pub fn one_with_precision(at_least_bits_precision: u32) -> Self {
+        let mut ret = Self::zero_with_precision(at_least_bits_precision);
+        ret.limbs[0] = Limb::ONE;
+        ret
+    }
+
+    /// Get the maximum value for a [`BoundedUint`] created with `at_least_bits_precision`
+    /// precision bits requested.
+    ///
+    /// That is, returns the value `2^self.bits_precision() - 1`.
+    pub fn max(at_least_bits_precision: u32) -> Self {
+        let mut ret = Self::zero_with_precision(at_least_bits_precision);
+        ret.as_limbs_mut().fill(Limb::MAX);
+        ret
+    }
+
+    /// Is this [`BoundedUint`] equal to zero?
+    pub fn is_zero(&self) -> Choice {
+        self.as_limbs()
+            .iter()
+            .fold(Choice::from(1), |acc, limb| acc & limb.is_zero())
+    }
+
+    /// Is this [`BoundedUint`] equal to one?
+    pub fn is_one(&self) -> Choice {
+        let mut iter = self.as_limbs().iter();
+        let choice = iter.next().copied().unwrap_or(Limb::ZERO).ct_eq(&Limb::ONE);
+        iter.fold(choice, |acc, limb| acc & limb.is_zero())
+    }
+
+    /// Is this integer value an odd number?
+    ///
+    /// # Returns
+    ///
+    /// If odd, returns `Choice(1)`. Otherwise, returns `Choice(0)`.
+    pub fn is_odd(&self) -> Choice {
+        self.limbs
+            .first()
+            .map(|limb| limb.is_odd())
+            .unwrap_or_else(|| Choice::from(0))
+    }
+
+    /// Is this integer value an even number?
+    ///
+    /// # Returns
+    ///
+    /// If even, returns `Choice(1)`. Otherwise, returns `Choice(0)`.
+    pub fn is_even(&self) -> Choice {
+        !self.is_odd()
+    }
+
+    /// Create a [`BoundedUint`] from a slice of [`Word`]s (i.e. word-sized unsigned integers),
+    /// with a precision of `words.len()` limbs.
+    pub fn from_words(words: &[Word]) -> Self {
+        let mut ret = Self::zero_with_precision(words.len() as u32 * Limb::BITS);
+
+        for (limb, &word) in ret.limbs.iter_mut().zip(words) {
+            *limb = Limb(word);
+        }
+
+        ret
+    }
+
+    /// Borrow the inner limbs as a slice of [`Word`]s.
+    pub fn as_words(&self) -> &[Word] {
+        // SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
+        #[allow(trivial_casts, unsafe_code)]
+        unsafe {
+            &*((self.as_limbs() as *const _) as *const [Word])
+        }
+    }
+
+    /// Borrow the inner limbs as a mutable slice of [`Word`]s.
+    pub fn as_words_mut(&mut self) -> &mut [Word] {
+        // SAFETY: `Limb` is a `repr(transparent)` newtype for `Word`
+        #[allow(trivial_casts, unsafe_code)]
+        unsafe {
+            &mut *((self.as_limbs_mut() as *mut _) as *mut [Word])
+        }
+    }
+
+    /// Borrow the limbs of this [`BoundedUint`].
+    pub fn as_limbs(&self) -> &[Limb] {
+        &self.limbs[..self.nlimbs]
+    }
+
+    /// Borrow the limbs of this [`BoundedUint`] mutably.
+    pub fn as_limbs_mut(&mut self) -> &mut [Limb] {
+        &mut self.limbs[..self.nlimbs]
+    }
+
+    /// Get the number of limbs in this [`BoundedUint`].
+    pub fn nlimbs(&self) -> usize {
+        self.nlimbs
+    }
+
+    /// Widen this type's precision to the given number of bits.
+    ///
+    /// Panics if `at_least_bits_precision` is smaller than the current precision.
+    pub fn widen(&self, at_least_bits_precision: u32) -> Self {
+        assert!(at_least_bits_precision >= self.bits_precision());
+
+        let mut ret = *self;
+        ret.nlimbs = Self::limbs_for_precision(at_least_bits_precision);
+        ret
+    }
+
+    /// Shortens this type's precision to the given number of bits.
+    ///
+    /// Panics if `at_least_bits_precision` is larger than the current precision.
+    pub fn shorten(&self, at_least_bits_precision: u32) -> Self {
+        assert!(at_least_bits_precision <= self.bits_precision());
+
+        let mut ret = Self::zero_with_precision(at_least_bits_precision);
+        let nlimbs = ret.nlimbs;
+        ret.limbs[..nlimbs].copy_from_slice(&self.limbs[..nlimbs]);
+        ret
+    }
+
+    /// Perform a carry chain-like operation over the limbs of the inputs,
+    /// constructing a result from the returned limbs and carry which is
+    /// widened to the same width as the widest input.
+    #[inline]
+    fn fold_limbs<F>(lhs: &Self, rhs: &Self, mut carry: Limb, f: F) -> (Self, Limb)
+    where
+        F: Fn(Limb, Limb, Limb) -> (Limb, Limb),
+    {
+        // Limbs past `nlimbs` are zero, which pads the narrower input
+        let mut ret = if lhs.nlimbs >= rhs.nlimbs { *lhs } else { *rhs };
+
+        for i in 0..ret.nlimbs {
+            let (limb, c) = f(lhs.limbs[i], rhs.limbs[i], carry);
+            ret.limbs[i] = limb;
+            carry = c;
+        }
+
+        (ret, carry)
+    }
+}
+
+impl<const MAX_LIMBS: usize> AsRef<[Word]> for BoundedUint<MAX_LIMBS> {
+    fn as_ref(&self) -> &[Word] {
+        self.as_words()
+    }
+}
+
+impl<const MAX_LIMBS: usize> AsMut<[Word]> for BoundedUint<MAX_LIMBS> {
+    fn as_mut(&mut self) -> &mut [Word] {
+        self.as_words_mut()
+    }
+}
+
+impl<const MAX_LIMBS: usize> AsRef<[Limb]> for BoundedUint<MAX_LIMBS> {
+    fn as_ref(&self) -> &[Limb] {
+        self.as_limbs()
+    }
+}
+
+impl<const MAX_LIMBS: usize> AsMut<[Limb]> for BoundedUint<MAX_LIMBS> {
+    fn as_mut(&mut self) -> &mut [Limb] {
+        self.as_limbs_mut()
+    }
+}
+
+impl<const MAX_LIMBS: usize> Default for BoundedUint<MAX_LIMBS> {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+impl<const MAX_LIMBS: usize> From<u8> for BoundedUint<MAX_LIMBS> {
+    fn from(n: u8) -> Self {
+        Limb::from(n).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> From<u16> for BoundedUint<MAX_LIMBS> {
+    fn from(n: u16) -> Self {
+        Limb::from(n).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> From<u32> for BoundedUint<MAX_LIMBS> {
+    fn from(n: u32) -> Self {
+        Limb::from(n).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> From<u64> for BoundedUint<MAX_LIMBS> {
+    fn from(n: u64) -> Self {
+        U64::from(n).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> From<u128> for BoundedUint<MAX_LIMBS> {
+    fn from(n: u128) -> Self {
+        U128::from(n).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> From<Limb> for BoundedUint<MAX_LIMBS> {
+    fn from(limb: Limb) -> Self {
+        let mut ret = Self::zero();
+        ret.limbs[0] = limb;
+        ret
+    }
+}
+
+impl<const MAX_LIMBS: usize, const LIMBS: usize> From<Uint<LIMBS>> for BoundedUint<MAX_LIMBS> {
+    /// Converts a [`Uint`], keeping its precision.
+    ///
+    /// Panics if `LIMBS` exceeds `MAX_LIMBS`.
+    fn from(uint: Uint<LIMBS>) -> Self {
+        Self::from(&uint)
+    }
+}
+
+impl<const MAX_LIMBS: usize, const LIMBS: usize> From<&Uint<LIMBS>> for BoundedUint<MAX_LIMBS> {
+    /// Converts a [`Uint`], keeping its precision.
+    ///
+    /// Panics if `LIMBS` exceeds `MAX_LIMBS`.
+    fn from(uint: &Uint<LIMBS>) -> Self {
+        let mut ret = Self::zero_with_precision(Uint::<LIMBS>::BITS);
+        ret.limbs[..LIMBS].copy_from_slice(uint.as_limbs());
+        ret
+    }
+}
+
+impl<const MAX_LIMBS: usize> Zero for BoundedUint<MAX_LIMBS> {
+    fn zero() -> Self {
+        Self::zero()
+    }
+
+    fn is_zero(&self) -> Choice {
+        self.is_zero()
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl<const MAX_LIMBS: usize> Zeroize for BoundedUint<MAX_LIMBS> {
+    fn zeroize(&mut self) {
+        self.limbs.zeroize();
+    }
+}
+
+impl<const MAX_LIMBS: usize> fmt::Debug for BoundedUint<MAX_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BoundedUint(0x{self:X})")
+    }
+}
+
+impl<const MAX_LIMBS: usize> fmt::Display for BoundedUint<MAX_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::UpperHex::fmt(self, f)
+    }
+}
+
+impl<const MAX_LIMBS: usize> fmt::LowerHex for BoundedUint<MAX_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.nlimbs == 0 {
+            return fmt::LowerHex::fmt(&Limb::ZERO, f);
+        }
+
+        for limb in self.as_limbs().iter().rev() {
+            fmt::LowerHex::fmt(limb, f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<const MAX_LIMBS: usize> fmt::UpperHex for BoundedUint<MAX_LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.nlimbs == 0 {
+            return fmt::UpperHex::fmt(&Limb::ZERO, f);
+        }
+
+        for limb in self.as_limbs().iter().rev() {
+            fmt::UpperHex::fmt(limb, f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, Limb, U128};
+
+    type B = BoundedUint<8>;
+
+    #[test]
+    fn precision() {
+        let x = B::zero_with_precision(130);
+        assert_eq!(x.bits_precision(), 3 * Limb::BITS);
+        assert_eq!(x.nlimbs(), 3);
+        assert_eq!(B::max(Limb::BITS * 2).bits(), Limb::BITS * 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "precision exceeds the maximum precision")]
+    fn precision_too_large() {
+        B::zero_with_precision(B::MAX_BITS + 1);
+    }
+
+    #[test]
+    fn widen_and_shorten() {
+        let x = B::from(U128::MAX);
+        let wide = x.widen(x.bits_precision() * 2);
+        assert_eq!(wide.bits_precision(), x.bits_precision() * 2);
+        assert_eq!(wide, x);
+        assert_eq!(wide.shorten(x.bits_precision()), x);
+        assert_eq!(x.shorten(Limb::BITS), B::from(Limb::MAX));
+    }
+}
@@ -0,0 +1,125 @@
+//! [`BoundedUint`] addition operations.
+
+use super::BoundedUint;
+use crate::{AddMod, CheckedAdd, Limb, Zero};
+use subtle::{Choice, ConditionallySelectable, CtOption};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Computes `a + b + carry`, returning the result along with the new carry.
+    #[inline(always)]
+    pub fn adc(&self, rhs: &Self, carry: Limb) -> (Self, Limb) {
+        Self::fold_limbs(self, rhs, carry, |a, b, c| a.adc(b, c))
+    }
+
+    /// Computes `a + b + carry` in-place, returning the new carry.
+    ///
+    /// Panics if `rhs` has a larger precision than `self`.
+    #[inline]
+    pub fn adc_assign(&mut self, rhs: &Self, mut carry: Limb) -> Limb {
+        assert!(rhs.nlimbs <= self.nlimbs, "rhs precision exceeds self");
+
+        for i in 0..self.nlimbs {
+            let (limb, c) = self.limbs[i].adc(rhs.limbs[i], carry);
+            self.limbs[i] = limb;
+            carry = c;
+        }
+
+        carry
+    }
+
+    /// Perform wrapping addition, discarding overflow.
+    pub fn wrapping_add(&self, rhs: &Self) -> Self {
+        self.adc(rhs, Limb::ZERO).0
+    }
+
+    /// Computes `self + rhs mod p`.
+    ///
+    /// Assumes `self + rhs` as unbounded integer is `< 2p`.
+    pub fn add_mod(&self, rhs: &Self, p: &Self) -> Self {
+        debug_assert_eq!(self.bits_precision(), p.bits_precision());
+        debug_assert_eq!(rhs.bits_precision(), p.bits_precision());
+        debug_assert!(self < p);
+        debug_assert!(rhs < p);
+
+        let (mut w, carry) = self.adc(rhs, Limb::ZERO);
+
+        // Attempt to subtract the modulus, to ensure the result is in the field.
+        let borrow = w.sbb_assign(p, Limb::ZERO);
+        let (_, mask) = carry.sbb(Limb::ZERO, borrow);
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the
+        // modulus.
+        w.conditional_adc_assign(p, Choice::from((mask.0 & 1) as u8));
+        w
+    }
+
+    /// Perform in-place wrapping addition of `rhs` if `choice` is truthy, returning the truthy
+    /// value if an overflow has occurred.
+    pub(crate) fn conditional_adc_assign(&mut self, rhs: &Self, choice: Choice) -> Choice {
+        debug_assert!(rhs.nlimbs <= self.nlimbs);
+        let mask = Limb::conditional_select(&Limb::ZERO, &Limb::MAX, choice);
+        let mut carry = Limb::ZERO;
+
+        for i in 0..self.nlimbs {
+            let (limb, c) = self.limbs[i].adc(rhs.limbs[i] & mask, carry);
+            self.limbs[i] = limb;
+            carry = c;
+        }
+
+        Choice::from((carry.0 & 1) as u8)
+    }
+}
+
+impl<const MAX_LIMBS: usize> CheckedAdd<&BoundedUint<MAX_LIMBS>> for BoundedUint<MAX_LIMBS> {
+    type Output = Self;
+
+    fn checked_add(&self, rhs: &Self) -> CtOption<Self> {
+        let (result, carry) = self.adc(rhs, Limb::ZERO);
+        CtOption::new(result, carry.is_zero())
+    }
+}
+
+impl<const MAX_LIMBS: usize> AddMod for BoundedUint<MAX_LIMBS> {
+    type Output = Self;
+
+    fn add_mod(&self, rhs: &Self, p: &Self) -> Self {
+        self.add_mod(rhs, p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, CheckedAdd, Limb, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn adc_with_carry() {
+        let (res, carry) = B::max(128).adc(&B::one(), Limb::ZERO);
+        assert_eq!(res.bits_precision(), 128);
+        assert!(bool::from(res.is_zero()));
+        assert_eq!(carry, Limb::ONE);
+    }
+
+    #[test]
+    fn checked_add_ok() {
+        let result = B::from(1u64).checked_add(&B::from(2u64));
+        assert_eq!(result.unwrap(), B::from(3u64));
+    }
+
+    #[test]
+    fn checked_add_overflow() {
+        let result = B::max(128).checked_add(&B::one());
+        assert!(!bool::from(result.is_some()));
+    }
+
+    #[test]
+    fn add_mod() {
+        let p = B::from(U128::MAX.wrapping_sub(&U128::from(58u8)));
+        let a = p.wrapping_sub(&B::from(2u8).widen(128));
+        let b = B::from(5u8).widen(128);
+        assert_eq!(a.add_mod(&b, &p), B::from(3u8).widen(128));
+        assert_eq!(b.add_mod(&b, &p), B::from(10u8).widen(128));
+    }
+}
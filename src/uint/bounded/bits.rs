@@ -0,0 +1,104 @@
+//! Bit manipulation functions.
+
+use super::BoundedUint;
+use crate::{Limb, Zero};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Calculate the number of bits needed to represent this number, i.e. the index of the highest
+    /// set bit.
+    ///
+    /// Use [`BoundedUint::bits_precision`] to get the total capacity of this integer.
+    pub fn bits(&self) -> u32 {
+        // Use `u32` because `subtle` can't select on `usize` and it matches what `core` uses for
+        // the return value of `leading_zeros`
+        let mut leading_zeros = 0u32;
+        let mut n = 0u32;
+
+        for limb in self.as_limbs().iter().rev() {
+            n.conditional_assign(&(n + 1), !limb.is_zero() | !n.ct_eq(&0));
+
+            // Set `leading_zeros` for the first nonzero limb we encounter
+            leading_zeros.conditional_assign(&limb.leading_zeros(), n.ct_eq(&1));
+        }
+
+        Limb::BITS * n - leading_zeros
+    }
+
+    /// Calculate the number of bits needed to represent this number in variable-time with respect
+    /// to `self`.
+    pub(crate) fn bits_vartime(&self) -> u32 {
+        let mut i = self.nlimbs;
+        while i > 0 && self.limbs[i - 1].0 == 0 {
+            i -= 1;
+        }
+
+        match i {
+            0 => 0,
+            _ => Limb::BITS * i as u32 - self.limbs[i - 1].leading_zeros(),
+        }
+    }
+
+    /// Get the precision of this [`BoundedUint`] in bits.
+    pub fn bits_precision(&self) -> u32 {
+        self.nlimbs as u32 * Limb::BITS
+    }
+
+    /// Get the value of the bit at position `index`, as a truthy or falsy [`Choice`].
+    /// Returns the falsy value for indices out of range.
+    pub fn bit(&self, index: u32) -> Choice {
+        let limb_num = index / Limb::BITS;
+        let index_in_limb = index % Limb::BITS;
+        let mut result = Limb::ZERO;
+
+        for (i, limb) in self.as_limbs().iter().enumerate() {
+            result.conditional_assign(limb, (i as u32).ct_eq(&limb_num));
+        }
+
+        Choice::from(((result.0 >> index_in_limb) & 1) as u8)
+    }
+
+    /// Returns `true` if the bit at position `index` is set, `false` otherwise.
+    ///
+    /// # Remarks
+    /// This operation is variable time with respect to `index` only.
+    pub(crate) fn bit_vartime(&self, index: u32) -> bool {
+        let limb_num = (index / Limb::BITS) as usize;
+
+        if limb_num >= self.nlimbs {
+            false
+        } else {
+            (self.limbs[limb_num].0 >> (index % Limb::BITS)) & 1 == 1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, Limb, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn bits() {
+        assert_eq!(0, B::zero().bits());
+        assert_eq!(0, B::zero().bits_vartime());
+        assert_eq!(128, B::max(128).bits());
+        assert_eq!(128, B::max(128).bits_vartime());
+
+        let n = B::from(U128::from_be_hex("000000000029ffffffffffffffffffff"));
+        assert_eq!(86, n.bits());
+        assert_eq!(86, n.bits_vartime());
+    }
+
+    #[test]
+    fn bit() {
+        let n = B::from(U128::from_be_hex("00000000004000000000000000000001"));
+        assert!(bool::from(n.bit(0)));
+        assert!(bool::from(n.bit(86)));
+        assert!(!bool::from(n.bit(85)));
+        assert!(!bool::from(n.bit(4 * Limb::BITS)));
+        assert!(n.bit_vartime(86));
+        assert!(!n.bit_vartime(4 * Limb::BITS));
+    }
+}
@@ -0,0 +1,138 @@
+//! [`BoundedUint`] comparisons.
+//!
+//! By default these are all constant-time and use the `subtle` crate.
+
+use super::BoundedUint;
+use crate::{CtChoice, Limb};
+use core::cmp::{max, Ordering};
+use subtle::{
+    Choice, ConditionallySelectable, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess,
+};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Returns the Ordering between `self` and `rhs` in variable time.
+    pub(crate) fn cmp_vartime(&self, rhs: &Self) -> Ordering {
+        let mut i = max(self.nlimbs, rhs.nlimbs);
+        while i > 0 {
+            i -= 1;
+            match self.limbs[i].0.cmp(&rhs.limbs[i].0) {
+                Ordering::Equal => continue,
+                ordering => return ordering,
+            }
+        }
+
+        Ordering::Equal
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConstantTimeEq for BoundedUint<MAX_LIMBS> {
+    #[inline]
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Limbs past `nlimbs` are zero, which pads the narrower input
+        let limbs = max(self.nlimbs, other.nlimbs);
+        let mut ret = Choice::from(1u8);
+
+        for i in 0..limbs {
+            ret &= self.limbs[i].ct_eq(&other.limbs[i]);
+        }
+
+        ret
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConstantTimeGreater for BoundedUint<MAX_LIMBS> {
+    #[inline]
+    fn ct_gt(&self, other: &Self) -> Choice {
+        let (_, borrow) = other.sbb(self, Limb::ZERO);
+        CtChoice::from_word_mask(borrow.0).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConstantTimeLess for BoundedUint<MAX_LIMBS> {
+    #[inline]
+    fn ct_lt(&self, other: &Self) -> Choice {
+        let (_, borrow) = self.sbb(other, Limb::ZERO);
+        CtChoice::from_word_mask(borrow.0).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> Eq for BoundedUint<MAX_LIMBS> {}
+impl<const MAX_LIMBS: usize> PartialEq for BoundedUint<MAX_LIMBS> {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl<const MAX_LIMBS: usize> Ord for BoundedUint<MAX_LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let mut ret = Ordering::Equal;
+        ret.conditional_assign(&Ordering::Greater, self.ct_gt(other));
+        ret.conditional_assign(&Ordering::Less, self.ct_lt(other));
+
+        #[cfg(debug_assertions)]
+        if ret == Ordering::Equal {
+            debug_assert_eq!(self, other);
+        }
+
+        ret
+    }
+}
+
+impl<const MAX_LIMBS: usize> PartialOrd for BoundedUint<MAX_LIMBS> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoundedUint;
+    use core::cmp::Ordering;
+    use subtle::{ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn ct_eq() {
+        let a = B::zero();
+        let b = B::one();
+
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+        assert!(!bool::from(b.ct_eq(&a)));
+        assert!(bool::from(b.ct_eq(&b)));
+        assert!(bool::from(b.ct_eq(&b.widen(256))));
+    }
+
+    #[test]
+    fn ct_gt_lt() {
+        let a = B::zero();
+        let b = B::one();
+        let c = B::max(128);
+
+        assert!(bool::from(b.ct_gt(&a)));
+        assert!(bool::from(c.ct_gt(&b)));
+        assert!(!bool::from(b.ct_gt(&b)));
+        assert!(!bool::from(a.ct_gt(&c)));
+
+        assert!(bool::from(a.ct_lt(&b)));
+        assert!(bool::from(b.ct_lt(&c)));
+        assert!(!bool::from(b.ct_lt(&b)));
+        assert!(!bool::from(c.ct_lt(&a)));
+    }
+
+    #[test]
+    fn cmp() {
+        let a = B::zero();
+        let b = B::one();
+        let c = B::max(128);
+
+        assert_eq!(a.cmp(&b), Ordering::Less);
+        assert_eq!(c.cmp(&b), Ordering::Greater);
+        assert_eq!(b.cmp(&b.widen(256)), Ordering::Equal);
+
+        assert_eq!(a.cmp_vartime(&b), Ordering::Less);
+        assert_eq!(c.cmp_vartime(&b), Ordering::Greater);
+        assert_eq!(b.cmp_vartime(&b.widen(256)), Ordering::Equal);
+    }
+}
@@ -0,0 +1,71 @@
+//! Constant-time helper functions.
+
+use super::BoundedUint;
+use crate::{
+    traits::{ct_lookup_selectable, ct_select_slice_selectable},
+    ConstantTimeLookup, Limb,
+};
+use core::cmp::max;
+use subtle::{Choice, ConditionallySelectable, CtOption};
+
+impl<const MAX_LIMBS: usize> ConditionallySelectable for BoundedUint<MAX_LIMBS> {
+    /// Selects `a` or `b` in constant time, with the larger of their two precisions.
+    ///
+    /// The precisions themselves are not secret, and are meant to be equal.
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        debug_assert_eq!(a.bits_precision(), b.bits_precision());
+        let mut ret = Self::zero_with_precision(0);
+        ret.nlimbs = max(a.nlimbs, b.nlimbs);
+
+        for i in 0..ret.nlimbs {
+            ret.limbs[i] = Limb::conditional_select(&a.limbs[i], &b.limbs[i], choice);
+        }
+
+        ret
+    }
+}
+
+impl<const MAX_LIMBS: usize> ConstantTimeLookup for BoundedUint<MAX_LIMBS> {
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_selectable(table, index)
+    }
+
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_selectable(out, a, b, choice)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoundedUint;
+    use subtle::{Choice, ConditionallySelectable};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn conditional_select() {
+        let a = B::zero_with_precision(128);
+        let b = B::max(128);
+
+        assert_eq!(a, B::conditional_select(&a, &b, Choice::from(0)));
+        assert_eq!(b, B::conditional_select(&a, &b, Choice::from(1)));
+
+        let mut c = a;
+        c.conditional_assign(&b, Choice::from(1));
+        assert_eq!(c, b);
+    }
+
+    #[test]
+    fn conditional_swap() {
+        let mut a = B::zero_with_precision(128);
+        let mut b = B::max(128);
+
+        B::conditional_swap(&mut a, &mut b, Choice::from(0));
+        assert_eq!(a, B::zero());
+        assert_eq!(b, B::max(128));
+
+        B::conditional_swap(&mut a, &mut b, Choice::from(1));
+        assert_eq!(a, B::max(128));
+        assert_eq!(b, B::zero());
+    }
+}
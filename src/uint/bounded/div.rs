@@ -0,0 +1,82 @@
+//! [`BoundedUint`] division operations.
+
+use super::BoundedUint;
+use crate::{Limb, NonZero, Word};
+use subtle::{Choice, ConditionallySelectable};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Computes `self / rhs`, returning the quotient and the remainder.
+    ///
+    /// The quotient has the precision of `self`, and the remainder the precision of `rhs`.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`, using a bit-serial
+    /// long division which doesn't require any double-width intermediate values.
+    pub fn div_rem(&self, rhs: &NonZero<Self>) -> (Self, Self) {
+        let rhs = rhs.as_ref();
+        let mut quotient = Self::zero_with_precision(self.bits_precision());
+        let mut remainder = Self::zero_with_precision(rhs.bits_precision());
+
+        for i in (0..self.bits_precision()).rev() {
+            let limb_num = (i / Limb::BITS) as usize;
+            let index_in_limb = i % Limb::BITS;
+
+            // Shift the next bit of `self` into the remainder
+            let (shifted, carry) = remainder.shl1_with_carry();
+            remainder = shifted;
+            remainder.limbs[0].0 |= (self.limbs[limb_num].0 >> index_in_limb) & 1;
+
+            // The shifted remainder is smaller than `2 * rhs`, so one subtraction is enough
+            let (difference, borrow) = remainder.sbb(rhs, Limb::ZERO);
+            let subtract = carry | !Choice::from((borrow.0 & 1) as u8);
+            remainder.conditional_assign(&difference, subtract);
+            quotient.limbs[limb_num].0 |= Word::from(subtract.unwrap_u8()) << index_in_limb;
+        }
+
+        (quotient, remainder)
+    }
+
+    /// Computes `self % rhs`, with the precision of `rhs`.
+    ///
+    /// See [`BoundedUint::div_rem`] for more information.
+    pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
+        self.div_rem(rhs).1
+    }
+
+    /// Perform wrapping division, with the precision of `self`.
+    ///
+    /// There's no way wrapping could ever happen. This function exists so that all operations are
+    /// accounted for in the wrapping operations.
+    pub fn wrapping_div(&self, rhs: &NonZero<Self>) -> Self {
+        self.div_rem(rhs).0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, Limb, NonZero, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn div_rem() {
+        let x = U128::from_be_hex("ffffffffffffffff0123456789abcdef");
+        let y = U128::from_be_hex("0000000000000000000000012345678f");
+        let (expected_q, expected_r) = x.div_rem(&NonZero::new(y).unwrap());
+
+        let (q, r) = B::from(x).div_rem(&NonZero::new(B::from(y)).unwrap());
+        assert_eq!(q, B::from(expected_q));
+        assert_eq!(r, B::from(expected_r));
+    }
+
+    #[test]
+    fn div_rem_different_precisions() {
+        let x = B::max(Limb::BITS * 3);
+        let y = NonZero::new(B::from(Limb::MAX)).unwrap();
+        let (q, r) = x.div_rem(&y);
+        assert_eq!(q.bits_precision(), Limb::BITS * 3);
+        assert_eq!(r.bits_precision(), Limb::BITS);
+        assert_eq!(q.wrapping_mul(y.as_ref()).wrapping_add(&r), x);
+        assert_eq!(x.rem(&y), r);
+        assert_eq!(x.wrapping_div(&y), q);
+    }
+}
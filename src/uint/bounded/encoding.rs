@@ -0,0 +1,140 @@
+//! Decoding and encoding operations for [`BoundedUint`].
+
+use super::BoundedUint;
+use crate::{DecodeError, Limb};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Create a new [`BoundedUint`] from the provided big endian bytes.
+    ///
+    /// The `bits_precision` argument represents the precision of the resulting integer, which is
+    /// fixed as this type is not arbitrary-precision.
+    /// The new [`BoundedUint`] will be created with `bits_precision`
+    /// rounded up to a multiple of [`Limb::BITS`].
+    ///
+    /// If the length of `bytes` is larger than `bits_precision` (rounded up to a multiple of 8)
+    /// this function will return [`DecodeError::InputSize`].
+    /// If the size of the decoded integer is larger than `bits_precision`,
+    /// this function will return [`DecodeError::Precision`].
+    ///
+    /// Panics if `bits_precision` exceeds [`BoundedUint::MAX_BITS`].
+    pub fn from_be_slice(bytes: &[u8], bits_precision: u32) -> Result<Self, DecodeError> {
+        if bytes.len() > (bits_precision as usize + 7) / 8 {
+            return Err(DecodeError::InputSize);
+        }
+
+        let mut ret = Self::zero_with_precision(bits_precision);
+
+        for (chunk, limb) in bytes.rchunks(Limb::BYTES).zip(ret.as_limbs_mut()) {
+            *limb = Limb::from_be_slice(chunk);
+        }
+
+        if bits_precision < ret.bits() {
+            return Err(DecodeError::Precision);
+        }
+
+        Ok(ret)
+    }
+
+    /// Create a new [`BoundedUint`] from the provided little endian bytes.
+    ///
+    /// The `bits_precision` argument represents the precision of the resulting integer, which is
+    /// fixed as this type is not arbitrary-precision.
+    /// The new [`BoundedUint`] will be created with `bits_precision`
+    /// rounded up to a multiple of [`Limb::BITS`].
+    ///
+    /// If the length of `bytes` is larger than `bits_precision` (rounded up to a multiple of 8)
+    /// this function will return [`DecodeError::InputSize`].
+    /// If the size of the decoded integer is larger than `bits_precision`,
+    /// this function will return [`DecodeError::Precision`].
+    ///
+    /// Panics if `bits_precision` exceeds [`BoundedUint::MAX_BITS`].
+    pub fn from_le_slice(bytes: &[u8], bits_precision: u32) -> Result<Self, DecodeError> {
+        if bytes.len() > (bits_precision as usize + 7) / 8 {
+            return Err(DecodeError::InputSize);
+        }
+
+        let mut ret = Self::zero_with_precision(bits_precision);
+
+        for (chunk, limb) in bytes.chunks(Limb::BYTES).zip(ret.as_limbs_mut()) {
+            *limb = Limb::from_le_slice(chunk);
+        }
+
+        if bits_precision < ret.bits() {
+            return Err(DecodeError::Precision);
+        }
+
+        Ok(ret)
+    }
+
+    /// Serialize this [`BoundedUint`] as big-endian into `out`.
+    ///
+    /// Panics if the length of `out` isn't `self.nlimbs() * Limb::BYTES`.
+    pub fn write_be_bytes(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), self.nlimbs * Limb::BYTES, "output size mismatch");
+
+        for (src, dst) in self
+            .as_limbs()
+            .iter()
+            .rev()
+            .zip(out.chunks_exact_mut(Limb::BYTES))
+        {
+            dst.copy_from_slice(&src.0.to_be_bytes());
+        }
+    }
+
+    /// Serialize this [`BoundedUint`] as little-endian into `out`.
+    ///
+    /// Panics if the length of `out` isn't `self.nlimbs() * Limb::BYTES`.
+    pub fn write_le_bytes(&self, out: &mut [u8]) {
+        assert_eq!(out.len(), self.nlimbs * Limb::BYTES, "output size mismatch");
+
+        for (src, dst) in self
+            .as_limbs()
+            .iter()
+            .zip(out.chunks_exact_mut(Limb::BYTES))
+        {
+            dst.copy_from_slice(&src.0.to_le_bytes());
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{BoundedUint, DecodeError};
+    use hex_literal::hex;
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn from_be_slice() {
+        let bytes = hex!("00112233445566778899aabbccddeeff");
+        let n = B::from_be_slice(&bytes, 128).unwrap();
+        assert_eq!(n.bits_precision(), 128);
+
+        let mut out = [0u8; 16];
+        n.write_be_bytes(&mut out);
+        assert_eq!(out, bytes);
+
+        assert_eq!(B::from_be_slice(&bytes[1..], 120).unwrap(), n);
+        assert_eq!(B::from_be_slice(&bytes, 64), Err(DecodeError::InputSize));
+        assert_eq!(
+            B::from_be_slice(&bytes[1..], 116),
+            Err(DecodeError::Precision)
+        );
+    }
+
+    #[test]
+    fn from_le_slice() {
+        let bytes = hex!("ffeeddccbbaa99887766554433221100");
+        let n = B::from_le_slice(&bytes, 128).unwrap();
+        assert_eq!(
+            n,
+            B::from_be_slice(&hex!("00112233445566778899aabbccddeeff"), 128).unwrap()
+        );
+
+        let mut out = [0u8; 16];
+        n.write_le_bytes(&mut out);
+        assert_eq!(out, bytes);
+    }
+}
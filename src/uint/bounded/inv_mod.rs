@@ -0,0 +1,135 @@
+//! [`BoundedUint`] modular inverse (i.e. reciprocal) operations.
+
+use super::BoundedUint;
+use crate::Limb;
+use subtle::{Choice, ConditionallySelectable, CtOption};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Computes the multiplicative inverse of `self` mod `modulus`, where `modulus` is odd.
+    /// Returns `None` if an inverse does not exist, including when `modulus` is even.
+    ///
+    /// This is constant-time with respect to both `self` and `modulus`.
+    pub fn inv_odd_mod(&self, modulus: &Self) -> CtOption<Self> {
+        let (inverse, is_some) = self.inv_odd_mod_inner(modulus);
+        CtOption::new(inverse, is_some)
+    }
+
+    /// Computes the multiplicative inverse of `self` mod `modulus`, returning an unspecified value
+    /// along with a falsy [`Choice`] if it does not exist or if `modulus` is even.
+    ///
+    /// The algorithm is the same as in GMP 6.2.1's `mpn_sec_invert`.
+    pub(crate) fn inv_odd_mod_inner(&self, modulus: &Self) -> (Self, Choice) {
+        debug_assert_eq!(self.bits_precision(), modulus.bits_precision());
+
+        let bits_precision = modulus.bits_precision();
+        let one = Self::one_with_precision(bits_precision);
+        let modulus_is_odd = modulus.is_odd();
+
+        // Use a surrogate odd modulus in case an even one is passed, which is then rejected by
+        // the `modulus_is_odd` check.
+        let modulus = Self::conditional_select(&Self::max(bits_precision), modulus, modulus_is_odd);
+
+        let mut a = *self;
+        let mut u = one;
+        let mut v = Self::zero_with_precision(bits_precision);
+        let mut b = modulus;
+
+        // `(modulus + 1) / 2`, which is the inverse of 2
+        let (m1hp, carry) = modulus.shr1_with_overflow();
+        debug_assert!(bool::from(carry));
+        let m1hp = m1hp.wrapping_add(&one);
+
+        // `bit_size` can be anything >= `self.bits()` + `modulus.bits()`, setting to the maximum.
+        for _ in 0..(2 * bits_precision) {
+            debug_assert!(bool::from(b.is_odd()));
+
+            let self_odd = a.is_odd();
+
+            // Set `self -= b` if `self` is odd.
+            let swap = a.conditional_sbb_assign(&b, self_odd);
+            // Set `b += self` if `swap` is true.
+            b = Self::conditional_select(&b, &b.wrapping_add(&a), swap);
+            // Negate `self` if `swap` is true.
+            a = Self::conditional_select(&a, &a.wrapping_neg(), swap);
+
+            let mut new_u = u;
+            let mut new_v = v;
+            Self::conditional_swap(&mut new_u, &mut new_v, swap);
+            let cy = new_u.conditional_sbb_assign(&new_v, self_odd);
+            let cyy = new_u.conditional_adc_assign(&modulus, cy);
+            debug_assert_eq!(bool::from(cy), bool::from(cyy));
+
+            let (new_a, overflow) = a.shr1_with_overflow();
+            debug_assert!(!bool::from(overflow));
+            let (mut new_u, cy) = new_u.shr1_with_overflow();
+            let cy = new_u.conditional_adc_assign(&m1hp, cy);
+            debug_assert!(!bool::from(cy));
+
+            a = new_a;
+            u = new_u;
+            v = new_v;
+        }
+
+        debug_assert!(bool::from(a.is_zero()));
+        (v, b.is_one() & modulus_is_odd)
+    }
+
+    /// Perform in-place wrapping subtraction of `rhs` if `choice` is truthy, returning the truthy
+    /// value if an underflow has occurred.
+    fn conditional_sbb_assign(&mut self, rhs: &Self, choice: Choice) -> Choice {
+        debug_assert!(rhs.nlimbs <= self.nlimbs);
+        let mask = Limb::conditional_select(&Limb::ZERO, &Limb::MAX, choice);
+        let mut borrow = Limb::ZERO;
+
+        for i in 0..self.nlimbs {
+            let (limb, b) = self.limbs[i].sbb(rhs.limbs[i] & mask, borrow);
+            self.limbs[i] = limb;
+            borrow = b;
+        }
+
+        Choice::from((borrow.0 & 1) as u8)
+    }
+
+    /// Computes `-self mod 2^bits_precision`.
+    fn wrapping_neg(&self) -> Self {
+        Self::zero_with_precision(self.bits_precision()).wrapping_sub(self)
+    }
+
+    /// Computes `self >> 1`, returning the shifted out bit as a [`Choice`].
+    fn shr1_with_overflow(&self) -> (Self, Choice) {
+        let carry = self.is_odd();
+        (self.shr_vartime(1), carry)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn inv_odd_mod() {
+        let modulus = B::from(U128::from_be_hex("ffffffffffffffffffffffffffffff61"));
+        let x = B::from(U128::from_be_hex("00112233445566778899aabbccddeeff"));
+        let expected = U128::from_be_hex("00112233445566778899aabbccddeeff")
+            .inv_odd_mod(&U128::from_be_hex("ffffffffffffffffffffffffffffff61"))
+            .unwrap();
+
+        assert_eq!(x.inv_odd_mod(&modulus).unwrap(), B::from(expected));
+    }
+
+    #[test]
+    fn inv_odd_mod_none() {
+        // 3 divides 15
+        let x = B::from(3u64).widen(128);
+        assert!(bool::from(
+            x.inv_odd_mod(&B::from(15u64).widen(128)).is_none()
+        ));
+
+        // Even modulus
+        assert!(bool::from(
+            x.inv_odd_mod(&B::from(16u64).widen(128)).is_none()
+        ));
+    }
+}
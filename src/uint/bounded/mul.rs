@@ -0,0 +1,110 @@
+//! [`BoundedUint`] multiplication operations.
+
+use super::BoundedUint;
+use crate::{CheckedMul, Limb};
+use subtle::CtOption;
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Multiply `self` by `rhs`, returning the lower and upper halves of the widened product.
+    ///
+    /// The lower half has the precision of `self`, and the upper half the precision of `rhs`, i.e.
+    /// together they hold the full product even if it exceeds [`BoundedUint::MAX_BITS`].
+    pub fn mul_wide(&self, rhs: &Self) -> (Self, Self) {
+        let nlimbs = self.nlimbs;
+        let mut lo = Self::zero_with_precision(self.bits_precision());
+        let mut hi = Self::zero_with_precision(rhs.bits_precision());
+
+        for i in 0..nlimbs {
+            let mut carry = Limb::ZERO;
+
+            for j in 0..rhs.nlimbs {
+                let limb = wide_limb_mut(&mut lo, &mut hi, i + j);
+                (*limb, carry) = limb.mac(self.limbs[i], rhs.limbs[j], carry);
+            }
+
+            *wide_limb_mut(&mut lo, &mut hi, i + rhs.nlimbs) = carry;
+        }
+
+        (lo, hi)
+    }
+
+    /// Perform wrapping multiplication, wrapping to the width of `self`.
+    pub fn wrapping_mul(&self, rhs: &Self) -> Self {
+        self.mul_wide(rhs).0
+    }
+
+    /// Multiply `self` by itself, returning the lower and upper halves of the widened product.
+    pub fn square_wide(&self) -> (Self, Self) {
+        self.mul_wide(self)
+    }
+}
+
+/// Get the limb at index `k` of the double-width integer given by its two halves.
+#[inline(always)]
+fn wide_limb_mut<'a, const MAX_LIMBS: usize>(
+    lo: &'a mut BoundedUint<MAX_LIMBS>,
+    hi: &'a mut BoundedUint<MAX_LIMBS>,
+    k: usize,
+) -> &'a mut Limb {
+    if k < lo.nlimbs {
+        &mut lo.limbs[k]
+    } else {
+        &mut hi.limbs[k - lo.nlimbs]
+    }
+}
+
+impl<const MAX_LIMBS: usize> CheckedMul<&BoundedUint<MAX_LIMBS>> for BoundedUint<MAX_LIMBS> {
+    type Output = Self;
+
+    fn checked_mul(&self, rhs: &BoundedUint<MAX_LIMBS>) -> CtOption<Self> {
+        let (lo, hi) = self.mul_wide(rhs);
+        CtOption::new(lo, hi.is_zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, CheckedMul, Limb, U128, U256};
+
+    type B = BoundedUint<{ U256::LIMBS }>;
+
+    #[test]
+    fn mul_wide() {
+        let x = U128::from_be_hex("ffffffffffffffff0123456789abcdef");
+        let y = U128::from_be_hex("00000000000000001111111111111111");
+        let (lo, hi) = B::from(x).mul_wide(&B::from(y));
+        let (expected_lo, expected_hi) = x.mul_wide(&y);
+        assert_eq!(lo, B::from(expected_lo));
+        assert_eq!(hi, B::from(expected_hi));
+
+        // The product of two integers of the maximum precision doesn't fit in one of them
+        let (lo, hi) = B::from(U256::MAX).square_wide();
+        let (expected_lo, expected_hi) = U256::MAX.square_wide();
+        assert_eq!(lo, B::from(expected_lo));
+        assert_eq!(hi, B::from(expected_hi));
+    }
+
+    #[test]
+    fn mul_wide_different_precisions() {
+        let (lo, hi) = B::max(Limb::BITS * 3).mul_wide(&B::from(Limb::MAX));
+        assert_eq!(lo.bits_precision(), Limb::BITS * 3);
+        assert_eq!(hi.bits_precision(), Limb::BITS);
+        assert_eq!(
+            lo,
+            B::max(Limb::BITS * 3)
+                .shl_vartime(Limb::BITS)
+                .wrapping_add(&B::one())
+        );
+        assert_eq!(hi, B::from(Limb::MAX.wrapping_sub(Limb::ONE)));
+    }
+
+    #[test]
+    fn checked_mul() {
+        let x = B::from(u64::MAX).widen(128);
+        assert_eq!(
+            x.checked_mul(&x).unwrap(),
+            B::from(U128::from(u64::MAX).square())
+        );
+        assert!(bool::from(B::max(128).checked_mul(&x).is_none()));
+    }
+}
@@ -0,0 +1,50 @@
+//! Variable-time operations on [`Public`] bounded integers.
+
+use super::BoundedUint;
+use crate::Public;
+use core::cmp::Ordering;
+
+impl<const MAX_LIMBS: usize> Public<BoundedUint<MAX_LIMBS>> {
+    /// Returns `true` if the bit at position `index` is set, `false` otherwise.
+    pub fn bit_vartime(&self, index: u32) -> bool {
+        self.as_inner().bit_vartime(index)
+    }
+
+    /// Calculate the number of bits needed to represent this number in variable time.
+    pub fn bits_vartime(&self) -> u32 {
+        self.as_inner().bits_vartime()
+    }
+
+    /// Returns the Ordering between `self` and `rhs` in variable time.
+    pub fn cmp_vartime(&self, rhs: &Self) -> Ordering {
+        self.as_inner().cmp_vartime(rhs.as_inner())
+    }
+
+    /// Computes `self << shift` in variable time.
+    pub fn shl_vartime(&self, shift: u32) -> Self {
+        Self::new(self.as_inner().shl_vartime(shift))
+    }
+
+    /// Computes `self >> shift` in variable time.
+    pub fn shr_vartime(&self, shift: u32) -> Self {
+        Self::new(self.as_inner().shr_vartime(shift))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, Public};
+    use core::cmp::Ordering;
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn vartime_ops() {
+        let x = Public::new(B::from(0x1234_5678u64));
+        assert_eq!(x.bits_vartime(), 29);
+        assert!(x.bit_vartime(3));
+        assert_eq!(x.cmp_vartime(&x.shr_vartime(1)), Ordering::Greater);
+        assert_eq!(x.shl_vartime(4).into_inner(), B::from(0x1_2345_6780u64));
+        assert_eq!(x.shr_vartime(4).into_inner(), B::from(0x123_4567u64));
+    }
+}
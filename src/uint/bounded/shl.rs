@@ -0,0 +1,128 @@
+//! [`BoundedUint`] bitwise left shift operations.
+
+use super::BoundedUint;
+use crate::Limb;
+use core::ops::{Shl, ShlAssign};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Computes `self << shift`.
+    /// Returns zero if `shift >= self.bits_precision()`.
+    pub fn shl(&self, shift: u32) -> Self {
+        let overflow = !shift.ct_lt(&self.bits_precision());
+        let shift = shift % self.bits_precision();
+        let log2_bits = u32::BITS - self.bits_precision().leading_zeros();
+        let mut result = *self;
+
+        for i in 0..log2_bits {
+            let bit = Choice::from(((shift >> i) & 1) as u8);
+            result.conditional_shl_vartime_assign(1 << i, bit);
+        }
+
+        for limb in result.as_limbs_mut() {
+            limb.conditional_assign(&Limb::ZERO, overflow);
+        }
+
+        result
+    }
+
+    /// Computes `self << shift`.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect to `self`.
+    #[inline(always)]
+    pub(crate) fn shl_vartime(&self, shift: u32) -> Self {
+        let mut result = *self;
+        result.conditional_shl_vartime_assign(shift, Choice::from(1));
+        result
+    }
+
+    /// Computes `self << 1`, returning the result along with the bit shifted out as a [`Choice`].
+    pub(crate) fn shl1_with_carry(&self) -> (Self, Choice) {
+        let carry = match self.nlimbs {
+            0 => Choice::from(0),
+            n => Choice::from((self.limbs[n - 1].0 >> (Limb::BITS - 1)) as u8),
+        };
+
+        (self.shl_vartime(1), carry)
+    }
+
+    /// Computes `self <<= shift` in place if `choice` is truthy, and leaves `self` unchanged
+    /// otherwise.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    fn conditional_shl_vartime_assign(&mut self, shift: u32, choice: Choice) {
+        let nlimbs = self.nlimbs;
+        let shift_num = (shift / Limb::BITS) as usize;
+        let rem = shift % Limb::BITS;
+
+        // Each limb of the result only depends on the limbs at the same or lower positions, so the
+        // limbs can be updated in place starting from the most significant one.
+        for i in (0..nlimbs).rev() {
+            let mut limb = Limb::ZERO;
+
+            if i >= shift_num {
+                limb.0 = self.limbs[i - shift_num].0 << rem;
+
+                if rem > 0 && i > shift_num {
+                    limb.0 |= self.limbs[i - shift_num - 1].0 >> (Limb::BITS - rem);
+                }
+            }
+
+            self.limbs[i].conditional_assign(&limb, choice);
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize> Shl<u32> for BoundedUint<MAX_LIMBS> {
+    type Output = Self;
+
+    fn shl(self, shift: u32) -> Self {
+        BoundedUint::shl(&self, shift)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Shl<u32> for &BoundedUint<MAX_LIMBS> {
+    type Output = BoundedUint<MAX_LIMBS>;
+
+    fn shl(self, shift: u32) -> BoundedUint<MAX_LIMBS> {
+        BoundedUint::shl(self, shift)
+    }
+}
+
+impl<const MAX_LIMBS: usize> ShlAssign<u32> for BoundedUint<MAX_LIMBS> {
+    fn shl_assign(&mut self, shift: u32) {
+        *self = BoundedUint::shl(self, shift);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, Limb, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn shl() {
+        let one = B::one_with_precision(128);
+
+        assert_eq!(one << 0, one);
+        assert_eq!(one.shl_vartime(67), B::from(U128::ONE.shl_vartime(67)));
+        assert_eq!(one << 127, B::from(U128::ONE.shl_vartime(127)));
+        assert_eq!(one << 128, B::zero_with_precision(128));
+        assert_eq!(one.shl_vartime(128), B::zero_with_precision(128));
+
+        let mut x = B::max(128);
+        x <<= 4;
+        assert_eq!(x, B::from(U128::MAX.shl_vartime(4)));
+        assert_eq!(x.bits_precision(), 128);
+    }
+
+    #[test]
+    fn shl1_with_carry() {
+        let (x, carry) = B::max(2 * Limb::BITS).shl1_with_carry();
+        assert!(bool::from(carry));
+        assert_eq!(x, B::max(2 * Limb::BITS).wrapping_sub(&B::one()));
+    }
+}
@@ -0,0 +1,111 @@
+//! [`BoundedUint`] bitwise right shift operations.
+
+use super::BoundedUint;
+use crate::Limb;
+use core::ops::{Shr, ShrAssign};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Computes `self >> shift`.
+    /// Returns zero if `shift >= self.bits_precision()`.
+    pub fn shr(&self, shift: u32) -> Self {
+        let overflow = !shift.ct_lt(&self.bits_precision());
+        let shift = shift % self.bits_precision();
+        let log2_bits = u32::BITS - self.bits_precision().leading_zeros();
+        let mut result = *self;
+
+        for i in 0..log2_bits {
+            let bit = Choice::from(((shift >> i) & 1) as u8);
+            result.conditional_shr_vartime_assign(1 << i, bit);
+        }
+
+        for limb in result.as_limbs_mut() {
+            limb.conditional_assign(&Limb::ZERO, overflow);
+        }
+
+        result
+    }
+
+    /// Computes `self >> shift`.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect to `self`.
+    #[inline(always)]
+    pub(crate) fn shr_vartime(&self, shift: u32) -> Self {
+        let mut result = *self;
+        result.conditional_shr_vartime_assign(shift, Choice::from(1));
+        result
+    }
+
+    /// Computes `self >>= shift` in place if `choice` is truthy, and leaves `self` unchanged
+    /// otherwise.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    fn conditional_shr_vartime_assign(&mut self, shift: u32, choice: Choice) {
+        let nlimbs = self.nlimbs;
+        let shift_num = (shift / Limb::BITS) as usize;
+        let rem = shift % Limb::BITS;
+
+        // Each limb of the result only depends on the limbs at the same or higher positions, so
+        // the limbs can be updated in place starting from the least significant one.
+        for i in 0..nlimbs {
+            let mut limb = Limb::ZERO;
+
+            if shift_num < nlimbs - i {
+                limb.0 = self.limbs[i + shift_num].0 >> rem;
+
+                if rem > 0 && shift_num < nlimbs - i - 1 {
+                    limb.0 |= self.limbs[i + shift_num + 1].0 << (Limb::BITS - rem);
+                }
+            }
+
+            self.limbs[i].conditional_assign(&limb, choice);
+        }
+    }
+}
+
+impl<const MAX_LIMBS: usize> Shr<u32> for BoundedUint<MAX_LIMBS> {
+    type Output = Self;
+
+    fn shr(self, shift: u32) -> Self {
+        BoundedUint::shr(&self, shift)
+    }
+}
+
+impl<const MAX_LIMBS: usize> Shr<u32> for &BoundedUint<MAX_LIMBS> {
+    type Output = BoundedUint<MAX_LIMBS>;
+
+    fn shr(self, shift: u32) -> BoundedUint<MAX_LIMBS> {
+        BoundedUint::shr(self, shift)
+    }
+}
+
+impl<const MAX_LIMBS: usize> ShrAssign<u32> for BoundedUint<MAX_LIMBS> {
+    fn shr_assign(&mut self, shift: u32) {
+        *self = BoundedUint::shr(self, shift);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn shr() {
+        let max = B::max(128);
+
+        assert_eq!(max >> 0, max);
+        assert_eq!(max.shr_vartime(67), B::from(U128::MAX.shr_vartime(67)));
+        assert_eq!(max >> 127, B::one_with_precision(128));
+        assert_eq!(max >> 128, B::zero_with_precision(128));
+        assert_eq!(max.shr_vartime(128), B::zero_with_precision(128));
+
+        let mut x = max;
+        x >>= 4;
+        assert_eq!(x, B::from(U128::MAX.shr_vartime(4)));
+        assert_eq!(x.bits_precision(), 128);
+    }
+}
@@ -0,0 +1,103 @@
+//! [`BoundedUint`] subtraction operations.
+
+use super::BoundedUint;
+use crate::{CheckedSub, Limb, SubMod, Zero};
+use subtle::{Choice, CtOption};
+
+impl<const MAX_LIMBS: usize> BoundedUint<MAX_LIMBS> {
+    /// Computes `a - (b + borrow)`, returning the result along with the new borrow.
+    #[inline(always)]
+    pub fn sbb(&self, rhs: &Self, borrow: Limb) -> (Self, Limb) {
+        Self::fold_limbs(self, rhs, borrow, |a, b, c| a.sbb(b, c))
+    }
+
+    /// Computes `a - (b + borrow)` in-place, returning the new borrow.
+    ///
+    /// Panics if `rhs` has a larger precision than `self`.
+    #[inline]
+    pub fn sbb_assign(&mut self, rhs: &Self, mut borrow: Limb) -> Limb {
+        assert!(rhs.nlimbs <= self.nlimbs, "rhs precision exceeds self");
+
+        for i in 0..self.nlimbs {
+            let (limb, b) = self.limbs[i].sbb(rhs.limbs[i], borrow);
+            self.limbs[i] = limb;
+            borrow = b;
+        }
+
+        borrow
+    }
+
+    /// Perform wrapping subtraction, discarding overflow.
+    pub fn wrapping_sub(&self, rhs: &Self) -> Self {
+        self.sbb(rhs, Limb::ZERO).0
+    }
+
+    /// Computes `self - rhs mod p`.
+    ///
+    /// Assumes `self - rhs` as unbounded signed integer is in `[-p, p)`.
+    pub fn sub_mod(&self, rhs: &Self, p: &Self) -> Self {
+        debug_assert_eq!(self.bits_precision(), p.bits_precision());
+        debug_assert_eq!(rhs.bits_precision(), p.bits_precision());
+        debug_assert!(self < p);
+        debug_assert!(rhs < p);
+
+        let (mut out, mask) = self.sbb(rhs, Limb::ZERO);
+
+        // If underflow occurred on the final limb, borrow = 0xfff...fff, otherwise
+        // borrow = 0x000...000. Thus, we use it as a mask to conditionally add the modulus.
+        out.conditional_adc_assign(p, Choice::from((mask.0 & 1) as u8));
+        out
+    }
+}
+
+impl<const MAX_LIMBS: usize> CheckedSub<&BoundedUint<MAX_LIMBS>> for BoundedUint<MAX_LIMBS> {
+    type Output = Self;
+
+    fn checked_sub(&self, rhs: &Self) -> CtOption<Self> {
+        let (result, borrow) = self.sbb(rhs, Limb::ZERO);
+        CtOption::new(result, borrow.is_zero())
+    }
+}
+
+impl<const MAX_LIMBS: usize> SubMod for BoundedUint<MAX_LIMBS> {
+    type Output = Self;
+
+    fn sub_mod(&self, rhs: &Self, p: &Self) -> Self {
+        self.sub_mod(rhs, p)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoundedUint, CheckedSub, Limb, U128};
+
+    type B = BoundedUint<4>;
+
+    #[test]
+    fn sbb_with_borrow() {
+        let (res, borrow) = B::zero_with_precision(128).sbb(&B::one(), Limb::ZERO);
+        assert_eq!(res, B::max(128));
+        assert_eq!(borrow, Limb::MAX);
+    }
+
+    #[test]
+    fn checked_sub_ok() {
+        let result = B::from(3u64).checked_sub(&B::from(2u64));
+        assert_eq!(result.unwrap(), B::one());
+    }
+
+    #[test]
+    fn checked_sub_overflow() {
+        let result = B::zero().checked_sub(&B::one());
+        assert!(!bool::from(result.is_some()));
+    }
+
+    #[test]
+    fn sub_mod() {
+        let p = B::from(U128::MAX.wrapping_sub(&U128::from(58u8)));
+        let a = B::from(3u8).widen(128);
+        let b = B::from(5u8).widen(128);
+        assert_eq!(a.sub_mod(&b, &p), p.wrapping_sub(&B::from(2u8)));
+        assert_eq!(b.sub_mod(&a, &p), B::from(2u8).widen(128));
+    }
+}
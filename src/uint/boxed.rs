@@ -7,25 +7,33 @@ mod bit_or;
 mod bits;
 mod cmp;
 mod ct;
+mod digits;
 mod div;
+mod div_reciprocal;
 pub(crate) mod encoding;
 mod inv_mod;
 mod mul;
 mod mul_mod;
 mod neg;
+mod public;
 mod shl;
 mod shr;
+mod storage;
 mod sub;
 mod sub_mod;
 
 mod bit_not;
 mod bit_xor;
 mod neg_mod;
+
+#[cfg(feature = "num-traits")]
+mod num_traits;
 #[cfg(feature = "rand_core")]
 mod rand;
 
-use crate::{Integer, Limb, NonZero, Uint, Word, Zero, U128, U64};
-use alloc::{boxed::Box, vec, vec::Vec};
+use self::storage::LimbStorage;
+use crate::{DecodeError, Integer, Limb, NonZero, Uint, Word, Zero, U128, U64};
+use alloc::{boxed::Box, vec::Vec};
 use core::{fmt, mem};
 use subtle::{Choice, ConstantTimeEq};
 
@@ -40,13 +48,16 @@ use zeroize::Zeroize;
 /// Unlike many other heap-allocated big integer libraries, this type is not
 /// arbitrary precision and will wrap at its fixed-precision rather than
 /// automatically growing.
+///
+/// Integers with a precision of up to 256 bits are stored inline, without a heap allocation.
+/// Only larger integers are stored on the heap.
 #[allow(clippy::derived_hash_with_manual_eq)]
 #[derive(Clone, Hash)]
 pub struct BoxedUint {
-    /// Boxed slice containing limbs.
+    /// Limbs, stored inline for small integers and on the heap for larger ones.
     ///
     /// Stored from least significant to most significant.
-    pub(crate) limbs: Box<[Limb]>,
+    pub(crate) limbs: LimbStorage,
 }
 
 impl BoxedUint {
@@ -56,23 +67,24 @@ impl BoxedUint {
 
     /// Get the value `0` represented as succinctly as possible.
     pub fn zero() -> Self {
-        Self {
-            limbs: vec![Limb::ZERO; 1].into(),
-        }
+        Limb::ZERO.into()
     }
 
     /// Get the value `0` with the given number of bits of precision.
     ///
     /// `at_least_bits_precision` is rounded up to a multiple of [`Limb::BITS`].
     pub fn zero_with_precision(at_least_bits_precision: u32) -> Self {
-        vec![Limb::ZERO; Self::limbs_for_precision(at_least_bits_precision)].into()
+        Self {
+            limbs: LimbStorage::from_elem(
+                Limb::ZERO,
+                Self::limbs_for_precision(at_least_bits_precision),
+            ),
+        }
     }
 
     /// Get the value `1`, represented as succinctly as possible.
     pub fn one() -> Self {
-        Self {
-            limbs: vec![Limb::ONE; 1].into(),
-        }
+        Limb::ONE.into()
     }
 
     /// Get the value `1` with the given number of bits of precision.
@@ -126,7 +138,12 @@ impl BoxedUint {
     ///
     /// That is, returns the value `2^self.bits_precision() - 1`.
     pub fn max(at_least_bits_precision: u32) -> Self {
-        vec![Limb::MAX; Self::limbs_for_precision(at_least_bits_precision)].into()
+        Self {
+            limbs: LimbStorage::from_elem(
+                Limb::MAX,
+                Self::limbs_for_precision(at_least_bits_precision),
+            ),
+        }
     }
 
     /// Create a [`BoxedUint`] from an array of [`Word`]s (i.e. word-sized unsigned
@@ -175,12 +192,12 @@ impl BoxedUint {
 
     /// Convert this [`BoxedUint`] into its inner limbs.
     pub fn to_limbs(&self) -> Box<[Limb]> {
-        self.limbs.clone()
+        self.as_limbs().into()
     }
 
     /// Convert this [`BoxedUint`] into its inner limbs.
     pub fn into_limbs(self) -> Box<[Limb]> {
-        self.limbs
+        self.limbs.into()
     }
 
     /// Get the number of limbs in this [`BoxedUint`].
@@ -222,17 +239,17 @@ impl BoxedUint {
         F: Fn(Limb, Limb, Limb) -> (Limb, Limb),
     {
         let nlimbs = cmp::max(lhs.nlimbs(), rhs.nlimbs());
-        let mut limbs = Vec::with_capacity(nlimbs);
+        let mut limbs = LimbStorage::from_elem(Limb::ZERO, nlimbs);
 
         for i in 0..nlimbs {
             let &a = lhs.limbs.get(i).unwrap_or(&Limb::ZERO);
             let &b = rhs.limbs.get(i).unwrap_or(&Limb::ZERO);
             let (limb, c) = f(a, b, carry);
-            limbs.push(limb);
+            limbs[i] = limb;
             carry = c;
         }
 
-        (limbs.into(), carry)
+        (Self { limbs }, carry)
     }
 
     /// Iterate over the limbs of the inputs, applying the given function, and
@@ -243,15 +260,15 @@ impl BoxedUint {
         F: Fn(Limb, Limb) -> Limb,
     {
         let nlimbs = cmp::max(lhs.nlimbs(), rhs.nlimbs());
-        let mut limbs = Vec::with_capacity(nlimbs);
+        let mut limbs = LimbStorage::from_elem(Limb::ZERO, nlimbs);
 
         for i in 0..nlimbs {
             let &a = lhs.limbs.get(i).unwrap_or(&Limb::ZERO);
             let &b = rhs.limbs.get(i).unwrap_or(&Limb::ZERO);
-            limbs.push(f(a, b));
+            limbs[i] = f(a, b);
         }
 
-        limbs.into()
+        Self { limbs }
     }
 }
 
@@ -296,19 +313,19 @@ impl Default for BoxedUint {
 
 impl From<u8> for BoxedUint {
     fn from(n: u8) -> Self {
-        vec![Limb::from(n); 1].into()
+        Limb::from(n).into()
     }
 }
 
 impl From<u16> for BoxedUint {
     fn from(n: u16) -> Self {
-        vec![Limb::from(n); 1].into()
+        Limb::from(n).into()
     }
 }
 
 impl From<u32> for BoxedUint {
     fn from(n: u32) -> Self {
-        vec![Limb::from(n); 1].into()
+        Limb::from(n).into()
     }
 }
 
@@ -326,7 +343,9 @@ impl From<u128> for BoxedUint {
 
 impl From<Limb> for BoxedUint {
     fn from(limb: Limb) -> Self {
-        vec![limb; 1].into()
+        Self {
+            limbs: LimbStorage::from_elem(limb, 1),
+        }
     }
 }
 
@@ -345,13 +364,13 @@ impl From<Box<[Limb]>> for BoxedUint {
 }
 
 impl From<Vec<Limb>> for BoxedUint {
-    fn from(mut limbs: Vec<Limb>) -> BoxedUint {
+    fn from(limbs: Vec<Limb>) -> BoxedUint {
         if limbs.is_empty() {
-            limbs.push(Limb::ZERO);
+            return Self::zero();
         }
 
         Self {
-            limbs: limbs.into_boxed_slice(),
+            limbs: limbs.into(),
         }
     }
 }
@@ -373,7 +392,33 @@ impl From<Vec<Word>> for BoxedUint {
 
 impl<const LIMBS: usize> From<Uint<LIMBS>> for BoxedUint {
     fn from(uint: Uint<LIMBS>) -> BoxedUint {
-        Vec::from(uint.to_limbs()).into()
+        uint.as_limbs().as_slice().into()
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BoxedUint> for Uint<LIMBS> {
+    type Error = DecodeError;
+
+    /// Converts a [`BoxedUint`] into a [`Uint`] which is at least as wide, zero-extending it.
+    ///
+    /// Returns [`DecodeError::Precision`] if the precision of the [`BoxedUint`] exceeds
+    /// [`Uint::BITS`], regardless of its value: use [`BoxedUint::shorten`] first to truncate it.
+    fn try_from(uint: &BoxedUint) -> Result<Self, DecodeError> {
+        if uint.nlimbs() > LIMBS {
+            return Err(DecodeError::Precision);
+        }
+
+        let mut limbs = [Limb::ZERO; LIMBS];
+        limbs[..uint.nlimbs()].copy_from_slice(&uint.limbs);
+        Ok(Uint::new(limbs))
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BoxedUint> for Uint<LIMBS> {
+    type Error = DecodeError;
+
+    fn try_from(uint: BoxedUint) -> Result<Self, DecodeError> {
+        Self::try_from(&uint)
     }
 }
 
@@ -386,10 +431,6 @@ impl Integer for BoxedUint {
         self.bits()
     }
 
-    fn bits_vartime(&self) -> u32 {
-        self.bits_vartime()
-    }
-
     fn bits_precision(&self) -> u32 {
         self.bits_precision()
     }
@@ -465,7 +506,7 @@ impl fmt::UpperHex for BoxedUint {
 #[cfg(test)]
 mod tests {
     use super::BoxedUint;
-    use crate::Word;
+    use crate::{DecodeError, Uint, Word, U128, U256};
     use alloc::vec::Vec;
 
     #[test]
@@ -475,4 +516,29 @@ mod tests {
         assert_eq!(uint.nlimbs(), 4);
         assert_eq!(uint.as_words(), words);
     }
+
+    #[test]
+    fn inline_storage() {
+        let small = BoxedUint::max(256);
+        assert!(!small.limbs.is_heap());
+        assert_eq!(small.bits_vartime(), 256);
+
+        let large = small.widen(512);
+        assert!(large.limbs.is_heap());
+        assert_eq!(large.bits_vartime(), 256);
+        assert_eq!(large.shorten(256), small);
+        assert_eq!(BoxedUint::from(large.to_limbs()), large);
+        assert_eq!(&*small.clone().into_limbs(), small.as_limbs());
+    }
+
+    #[test]
+    fn try_into_uint() {
+        let boxed = BoxedUint::from(U128::MAX);
+        assert_eq!(U128::try_from(&boxed), Ok(U128::MAX));
+        assert_eq!(U256::try_from(boxed.clone()), Ok(U128::MAX.resize()));
+        assert_eq!(
+            Uint::<{ U128::LIMBS - 1 }>::try_from(boxed),
+            Err(DecodeError::Precision)
+        );
+    }
 }
@@ -1,19 +1,19 @@
 //! [`BoxedUint`] bitwise NOT operations.
 
 use super::BoxedUint;
-use crate::{Limb, Wrapping};
+use crate::Wrapping;
 use core::ops::Not;
 
 impl BoxedUint {
     /// Computes bitwise `!a`.
     pub fn not(&self) -> Self {
-        let mut limbs = vec![Limb::ZERO; self.nlimbs()];
+        let mut ret = Self::zero_with_precision(self.bits_precision());
 
         for i in 0..self.nlimbs() {
-            limbs[i] = self.limbs[i].not();
+            ret.limbs[i] = self.limbs[i].not();
         }
 
-        limbs.into()
+        ret
     }
 }
 
@@ -26,7 +26,7 @@ impl BoxedUint {
 
     /// Calculate the number of bits needed to represent this number in variable-time with respect
     /// to `self`.
-    pub fn bits_vartime(&self) -> u32 {
+    pub(crate) fn bits_vartime(&self) -> u32 {
         let mut i = self.nlimbs() - 1;
         while i > 0 && self.limbs[i].0 == 0 {
             i -= 1;
@@ -1,7 +1,10 @@
 //! Constant-time helper functions.
 
 use super::BoxedUint;
-use crate::Limb;
+use crate::{
+    traits::{ct_lookup_with, ct_select_slice_with},
+    ConstantTimeLookup, Limb,
+};
 use subtle::{Choice, ConditionallySelectable, CtOption};
 
 impl BoxedUint {
@@ -13,13 +16,13 @@ impl BoxedUint {
     /// Panics if `a` and `b` don't have the same precision.
     pub fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
         debug_assert_eq!(a.bits_precision(), b.bits_precision());
-        let mut limbs = vec![Limb::ZERO; a.nlimbs()].into_boxed_slice();
+        let mut ret = Self::zero_with_precision(a.bits_precision());
 
         for i in 0..a.nlimbs() {
-            limbs[i] = Limb::conditional_select(&a.limbs[i], &b.limbs[i], choice);
+            ret.limbs[i] = Limb::conditional_select(&a.limbs[i], &b.limbs[i], choice);
         }
 
-        Self { limbs }
+        ret
     }
 
     /// Conditionally assign `other` to `self`, according to `choice`.
@@ -106,9 +109,23 @@ impl BoxedUint {
     }
 }
 
+impl ConstantTimeLookup for BoxedUint {
+    /// Panics if the elements of `table` don't have the same precision.
+    fn ct_lookup(table: &[Self], index: usize) -> CtOption<Self> {
+        ct_lookup_with(table, index, Self::conditional_assign)
+    }
+
+    /// Panics if the corresponding elements of `a` and `b` don't have the same precision.
+    fn ct_select_slice(out: &mut [Self], a: &[Self], b: &[Self], choice: Choice) {
+        ct_select_slice_with(out, a, b, choice, Self::conditional_select)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::BoxedUint;
+    use crate::ConstantTimeLookup;
+    use alloc::vec::Vec;
     use subtle::{Choice, CtOption};
 
     #[test]
@@ -120,6 +137,31 @@ mod tests {
         assert_eq!(b, BoxedUint::conditional_select(&a, &b, Choice::from(1)));
     }
 
+    #[test]
+    fn ct_lookup() {
+        let table: Vec<BoxedUint> = (0u8..4).map(BoxedUint::from).collect();
+
+        for i in 0..4 {
+            let ret = BoxedUint::ct_lookup(&table, i).unwrap();
+            assert_eq!(ret, table[i]);
+        }
+
+        assert!(bool::from(BoxedUint::ct_lookup(&table, 4).is_none()));
+    }
+
+    #[test]
+    fn ct_select_slice() {
+        let a = vec![BoxedUint::zero(), BoxedUint::one()];
+        let b = vec![BoxedUint::max(64), BoxedUint::from(2u8)];
+        let mut out = vec![BoxedUint::zero(); 2];
+
+        BoxedUint::ct_select_slice(&mut out, &a, &b, Choice::from(0));
+        assert_eq!(out, a);
+
+        BoxedUint::ct_select_slice(&mut out, &a, &b, Choice::from(1));
+        assert_eq!(out, b);
+    }
+
     #[test]
     fn conditional_map_some() {
         let n = BoxedUint::one();
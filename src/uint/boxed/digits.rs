@@ -0,0 +1,131 @@
+//! Conversions of [`BoxedUint`] to and from digits in an arbitrary radix.
+//!
+//! See the [`Uint`][`crate::Uint`] counterparts for the digit format.
+
+use super::{mul_mod::mac_by_limb, BoxedUint};
+use crate::{
+    uint::digits::{finalize_digits, push_chunk_digits, radix_chunk},
+    Limb, Reciprocal,
+};
+use alloc::vec::Vec;
+use subtle::{Choice, ConstantTimeEq, ConstantTimeLess, CtOption};
+
+impl BoxedUint {
+    /// Serializes `self` as digits in the given `radix`, which must be in the range `2..=256`.
+    ///
+    /// Returns the digits without leading zeros, or a single zero digit if `self == 0`.
+    ///
+    /// This function is variable-time with respect to `self`, see [`BoxedUint::to_digits_fixed`]
+    /// for a constant-time variant.
+    pub fn to_digits(&self, radix: u32) -> Vec<u8> {
+        let (chunk, chunk_digits) = radix_chunk(radix);
+        let reciprocal = Reciprocal::ct_new(Limb(chunk));
+        let (reciprocal, _is_some) = reciprocal.components_ref();
+
+        // Divide by the largest power of the radix that fits in a limb, and split the remainders
+        // into digits using native arithmetic.
+        let mut digits = Vec::new();
+        let mut value = self.clone();
+        loop {
+            let (quo, rem) = value.div_rem_limb_with_reciprocal(reciprocal);
+            push_chunk_digits(&mut digits, rem.0, radix, chunk_digits);
+            value = quo;
+
+            if value.is_zero().into() {
+                break;
+            }
+        }
+
+        finalize_digits(digits)
+    }
+
+    /// Serializes `self` as exactly `out.len()` digits in the given `radix`, which must be in the
+    /// range `2..=256`, padding with leading zeros.
+    ///
+    /// Returns the truthy value if `self` fits in the given number of digits, in which case the
+    /// digits are written to `out`. Otherwise the contents of `out` are unspecified.
+    ///
+    /// This function is constant-time with respect to `self`, and variable only with respect to
+    /// `radix`, `out.len()` and the precision of `self`.
+    pub fn to_digits_fixed(&self, radix: u32, out: &mut [u8]) -> Choice {
+        assert!((2..=256).contains(&radix), "unsupported radix");
+        let reciprocal = Reciprocal::ct_new(Limb::from_u32(radix));
+        let (reciprocal, _is_some) = reciprocal.components_ref();
+
+        let mut value = self.clone();
+        for digit in out.iter_mut().rev() {
+            let (quo, rem) = value.div_rem_limb_with_reciprocal(reciprocal);
+            *digit = rem.0 as u8;
+            value = quo;
+        }
+
+        value.is_zero()
+    }
+
+    /// Deserializes an integer with the given precision from digits in the given `radix`, which
+    /// must be in the range `2..=256`. An empty slice of digits is decoded as zero.
+    ///
+    /// Returns none if any digit is out of range, or if the value does not fit in
+    /// `bits_precision` (rounded up to a multiple of [`Limb::BITS`]).
+    ///
+    /// This function is constant-time with respect to the values of the digits, and variable only
+    /// with respect to `radix`, `digits.len()` and `bits_precision`.
+    pub fn from_digits(radix: u32, digits: &[u8], bits_precision: u32) -> CtOption<Self> {
+        assert!((2..=256).contains(&radix), "unsupported radix");
+        let radix_limb = Limb::from_u32(radix);
+        let zero = Self::zero_with_precision(bits_precision);
+        let mut res = zero.clone();
+        let mut is_some = Choice::from(1);
+
+        for &digit in digits {
+            is_some &= u32::from(digit).ct_lt(&radix);
+            let (value, carry) = mac_by_limb(&zero, &res, radix_limb, Limb::from_u8(digit));
+            is_some &= carry.ct_eq(&Limb::ZERO);
+            res = value;
+        }
+
+        CtOption::new(res, is_some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BoxedUint;
+
+    #[test]
+    fn to_digits() {
+        let n = BoxedUint::from(0x1234_5678_9abc_def0_1122_3344_5566_7788u128);
+        let digits = n.to_digits(10);
+        let expected: alloc::vec::Vec<u8> = "24197857203266734864716154819477665672"
+            .bytes()
+            .map(|b| b - b'0')
+            .collect();
+        assert_eq!(digits, expected);
+        assert_eq!(BoxedUint::zero().to_digits(58), [0]);
+        assert_eq!(BoxedUint::max(128).to_digits(256), [0xff; 16]);
+    }
+
+    #[test]
+    fn to_digits_fixed() {
+        let n = BoxedUint::from(1_000_000u64);
+        let mut out = [0xffu8; 9];
+        assert!(bool::from(n.to_digits_fixed(10, &mut out)));
+        assert_eq!(out, [0, 0, 1, 0, 0, 0, 0, 0, 0]);
+
+        let mut out = [0u8; 6];
+        assert!(!bool::from(n.to_digits_fixed(10, &mut out)));
+    }
+
+    #[test]
+    fn from_digits() {
+        let n = BoxedUint::from(0x1234_5678_9abc_def0_1122_3344_5566_7788u128);
+        let digits = n.to_digits(58);
+        assert_eq!(BoxedUint::from_digits(58, &digits, 128).unwrap(), n);
+        assert!(bool::from(
+            BoxedUint::from_digits(256, &[1; 17], 128).is_none()
+        ));
+        assert!(bool::from(
+            BoxedUint::from_digits(58, &[1, 58], 128).is_none()
+        ));
+    }
+}
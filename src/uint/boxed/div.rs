@@ -1,55 +1,159 @@
 //! [`BoxedUint`] division operations.
 
-use crate::{BoxedUint, CheckedDiv, Limb, NonZero, Wrapping};
+use super::storage::LimbStorage;
+use crate::{
+    uint::{div::inv_mod_limb, div_limb::div_rem_limb_with_reciprocal_boxed},
+    BoxedUint, CheckedDiv, Limb, MultiLimbReciprocal, NonZero, Reciprocal, Wrapping,
+};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
-use subtle::{Choice, ConstantTimeEq, ConstantTimeLess, CtOption};
+use subtle::{Choice, ConstantTimeEq, CtOption};
 
 impl BoxedUint {
+    /// Computes `self` / `rhs` using a pre-made reciprocal,
+    /// returns the quotient (q) and remainder (r).
+    pub fn div_rem_limb_with_reciprocal(&self, reciprocal: &Reciprocal) -> (Self, Limb) {
+        div_rem_limb_with_reciprocal_boxed(self, reciprocal)
+    }
+
+    /// Computes `self` / `rhs`, returns the quotient (q) and remainder (r).
+    pub fn div_rem_limb(&self, rhs: NonZero<Limb>) -> (Self, Limb) {
+        // Guaranteed to succeed since `rhs` is nonzero.
+        let reciprocal = Reciprocal::ct_new(*rhs);
+        let (reciprocal, _is_some) = reciprocal.components_ref();
+        div_rem_limb_with_reciprocal_boxed(self, reciprocal)
+    }
+
+    /// Computes `self` % `rhs` using a pre-made reciprocal.
+    pub fn rem_limb_with_reciprocal(&self, reciprocal: &Reciprocal) -> Limb {
+        self.div_rem_limb_with_reciprocal(reciprocal).1
+    }
+
+    /// Computes `self` % `rhs`.
+    pub fn rem_limb(&self, rhs: NonZero<Limb>) -> Limb {
+        self.div_rem_limb(rhs).1
+    }
+
     /// Computes self / rhs, returns the quotient, remainder.
+    ///
+    /// The precisions of `self` and `rhs` may differ, e.g. a double-width product can be divided
+    /// by a single-width divisor: the quotient has the precision of `self`, and the remainder has
+    /// the precision of `rhs`.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`.
     pub fn div_rem(&self, rhs: &NonZero<Self>) -> (Self, Self) {
         // Since `rhs` is nonzero, this should always hold.
         self.div_rem_unchecked(rhs.as_ref())
     }
 
+    /// Computes self / rhs, writing the quotient to `quotient` and the remainder to `remainder`.
+    ///
+    /// The quotient must have the precision of `self`, and the remainder the precision of `rhs`,
+    /// which must not be larger than the one of `self`.
+    ///
+    /// NOTE: this still allocates the reciprocal of `rhs`. Use
+    /// [`BoxedUint::div_rem_with_reciprocal_into`] with a [`MultiLimbReciprocal`] computed up front
+    /// to divide by the same `rhs` repeatedly without allocating.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`.
+    pub fn div_rem_into(&self, rhs: &NonZero<Self>, quotient: &mut Self, remainder: &mut Self) {
+        let (reciprocal, _is_some) = MultiLimbReciprocal::new_inner(rhs.as_ref());
+        self.div_rem_with_reciprocal_into(&reciprocal, quotient, remainder);
+    }
+
     /// Computes self % rhs, returns the remainder.
+    ///
+    /// The remainder has the precision of `rhs`. See [`BoxedUint::div_rem`] for more information.
     pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
         self.div_rem(rhs).1
     }
 
+    /// Computes `(lower + upper * 2^lower.bits_precision())` % `rhs`, i.e. reduces a double-width
+    /// integer given by its two halves, e.g. a product, without converting to Montgomery form.
+    ///
+    /// The remainder has the precision of `rhs`.
+    ///
+    /// This function is constant-time with respect to both the dividend and `rhs`.
+    pub fn rem_wide(lower_upper: (Self, Self), rhs: &NonZero<Self>) -> Self {
+        let (lower, upper) = lower_upper;
+        let limbs: LimbStorage = lower
+            .limbs
+            .iter()
+            .chain(upper.limbs.iter())
+            .copied()
+            .collect();
+        Self { limbs }.rem(rhs)
+    }
+
     /// Computes self / rhs, returns the quotient, remainder.
     ///
+    /// The precisions of `self` and `rhs` may differ, see [`BoxedUint::div_rem`].
+    ///
     /// Variable-time with respect to `rhs`
-    pub fn div_rem_vartime(&self, rhs: &NonZero<Self>) -> (Self, Self) {
+    pub(crate) fn div_rem_vartime(&self, rhs: &NonZero<Self>) -> (Self, Self) {
         // Since `rhs` is nonzero, this should always hold.
         self.div_rem_vartime_unchecked(rhs.as_ref())
     }
 
     /// Computes self % rhs, returns the remainder.
     ///
-    /// Variable-time with respect to `rhs`.
-    ///
-    /// # Panics
+    /// The remainder has the precision of `rhs`.
     ///
-    /// Panics if `self` and `rhs` have different precisions.
-    // TODO(tarcieri): handle different precisions without panicking
-    pub fn rem_vartime(&self, rhs: &NonZero<Self>) -> Self {
-        debug_assert_eq!(self.bits_precision(), rhs.bits_precision());
+    /// Variable-time with respect to `rhs`.
+    pub(crate) fn rem_vartime(&self, rhs: &NonZero<Self>) -> Self {
+        let bits_precision = self.bits_precision().max(rhs.bits_precision());
         let mb = rhs.bits();
-        let mut bd = self.bits_precision() - mb;
-        let mut rem = self.clone();
-        let mut c = rhs.shl_vartime(bd);
+        let mut bd = bits_precision - mb;
+        let mut rem = self.widen(bits_precision);
+        let mut c = rhs.widen(bits_precision).shl_vartime(bd);
 
         loop {
             let (r, borrow) = rem.sbb(&c, Limb::ZERO);
             rem = Self::conditional_select(&r, &rem, !borrow.ct_eq(&Limb::ZERO));
             if bd == 0 {
-                break rem;
+                break rem.shorten(rhs.bits_precision());
             }
             bd -= 1;
             c.shr1_assign();
         }
     }
 
+    /// Computes `self` / `rhs` when `rhs` is known to divide `self`, using Jebelean's exact
+    /// division: the quotient limbs are computed starting from the least significant one, by
+    /// multiplying with the inverse of `rhs` modulo `2^Limb::BITS`.
+    ///
+    /// The result is unspecified if the division is not exact.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`.
+    pub fn div_exact(&self, rhs: &NonZero<Self>) -> Self {
+        debug_assert_eq!(self.bits_precision(), rhs.bits_precision());
+
+        // Remove the power of two, which `self` has to be divisible by as well,
+        // so that the divisor becomes odd.
+        let shift = rhs.trailing_zeros();
+        let divisor = rhs.shr(shift);
+        let mut rem = self.shr(shift);
+        let inverse = inv_mod_limb(divisor.limbs[0]);
+        let mut quo = Self::zero_with_precision(self.bits_precision());
+
+        for i in 0..self.nlimbs() {
+            // The lower `i` limbs of the remainder are zero at this point,
+            // and the quotient limb is chosen to clear the next one.
+            let q = rem.limbs[i].wrapping_mul(inverse);
+            quo.limbs[i] = q;
+
+            // Subtract `q * divisor * 2^(i * Limb::BITS)` from the remainder.
+            let (mut carry, mut borrow) = (Limb::ZERO, Limb::ZERO);
+            for (r, d) in rem.limbs[i..].iter_mut().zip(divisor.limbs.iter()) {
+                let (product, c) = Limb::ZERO.mac(*d, q, carry);
+                let (diff, b) = r.sbb(product, borrow);
+                *r = diff;
+                (carry, borrow) = (c, b);
+            }
+        }
+
+        quo
+    }
+
     /// Wrapped division is just normal division i.e. `self` / `rhs`
     /// There’s no way wrapping could ever happen.
     ///
@@ -72,32 +176,9 @@ impl BoxedUint {
     ///
     /// This function is constant-time with respect to both `self` and `rhs`.
     fn div_rem_unchecked(&self, rhs: &Self) -> (Self, Self) {
-        debug_assert_eq!(self.bits_precision(), rhs.bits_precision());
-        let mb = rhs.bits();
-        let bits_precision = self.bits_precision();
-        let mut rem = self.clone();
-        let mut quo = Self::zero_with_precision(bits_precision);
-        let mut c = rhs.shl(bits_precision - mb);
-        let mut i = bits_precision;
-        let mut done = Choice::from(0u8);
-
-        loop {
-            let (mut r, borrow) = rem.sbb(&c, Limb::ZERO);
-            rem.conditional_assign(&r, !(Choice::from((borrow.0 & 1) as u8) | done));
-            r = quo.bitor(&Self::one());
-            quo.conditional_assign(&r, !(Choice::from((borrow.0 & 1) as u8) | done));
-            if i == 0 {
-                break;
-            }
-            i -= 1;
-            // when `i < mb`, the computation is actually done, so we ensure `quo` and `rem`
-            // aren't modified further (but do the remaining iterations anyway to be constant-time)
-            done = i.ct_lt(&mb);
-            c.shr1_assign();
-            quo.conditional_assign(&quo.shl1(), !done);
-        }
-
-        (quo, rem)
+        // If `rhs` is zero, the reciprocal is calculated for a divisor of 1 instead.
+        let (reciprocal, _is_some) = MultiLimbReciprocal::new_inner(rhs);
+        self.div_rem_with_reciprocal(&reciprocal)
     }
 
     /// Computes `self` / `rhs`, returns the quotient (q), remainder (r) without checking if `rhs`
@@ -105,12 +186,12 @@ impl BoxedUint {
     ///
     /// This function operates in variable-time.
     fn div_rem_vartime_unchecked(&self, rhs: &Self) -> (Self, Self) {
-        debug_assert_eq!(self.bits_precision(), rhs.bits_precision());
+        let bits_precision = self.bits_precision().max(rhs.bits_precision());
         let mb = rhs.bits_vartime();
-        let mut bd = self.bits_precision() - mb;
-        let mut remainder = self.clone();
-        let mut quotient = Self::zero_with_precision(self.bits_precision());
-        let mut c = rhs.shl_vartime(bd);
+        let mut bd = bits_precision - mb;
+        let mut remainder = self.widen(bits_precision);
+        let mut quotient = Self::zero_with_precision(bits_precision);
+        let mut c = rhs.widen(bits_precision).shl_vartime(bd);
 
         loop {
             let (mut r, borrow) = remainder.sbb(&c, Limb::ZERO);
@@ -126,7 +207,10 @@ impl BoxedUint {
             quotient.shl1_assign();
         }
 
-        (quotient, remainder)
+        (
+            quotient.shorten(self.bits_precision()),
+            remainder.shorten(rhs.bits_precision()),
+        )
     }
 }
 
@@ -234,6 +318,16 @@ impl DivAssign<NonZero<BoxedUint>> for Wrapping<BoxedUint> {
     }
 }
 
+/// Panics if `rhs == 0`, like [`core::num::Wrapping`].
+impl Div<Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn div(self, rhs: Wrapping<BoxedUint>) -> Self::Output {
+        let rhs = Option::<NonZero<BoxedUint>>::from(NonZero::new(rhs.0)).expect("divide by zero");
+        Wrapping(self.0.wrapping_div(&rhs))
+    }
+}
+
 impl Rem<&NonZero<BoxedUint>> for &BoxedUint {
     type Output = BoxedUint;
 
@@ -282,9 +376,19 @@ impl RemAssign<NonZero<BoxedUint>> for BoxedUint {
     }
 }
 
+/// Panics if `rhs == 0`, like [`core::num::Wrapping`].
+impl Rem<Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn rem(self, rhs: Wrapping<BoxedUint>) -> Self::Output {
+        let rhs = Option::<NonZero<BoxedUint>>::from(NonZero::new(rhs.0)).expect("modulo zero");
+        Wrapping(self.0.rem(&rhs))
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{BoxedUint, NonZero};
+    use super::{BoxedUint, Limb, NonZero};
 
     #[test]
     fn rem() {
@@ -299,4 +403,70 @@ mod tests {
         let p = NonZero::new(BoxedUint::from(997u128)).unwrap();
         assert_eq!(BoxedUint::from(648u128), n.rem_vartime(&p));
     }
+
+    #[test]
+    fn div_rem_mixed_precision() {
+        let a = BoxedUint::from(0xFFEECCBBAA99887766u128);
+        let b = BoxedUint::from(0x123456789ABCDEF0u128);
+        let c = BoxedUint::from(0x1122334455u64);
+        let product = a.mul(&b);
+        assert_eq!(product.bits_precision(), 256);
+
+        let (quo, rem) = product
+            .wrapping_add(&c.widen(256))
+            .div_rem(&NonZero::new(b.clone()).unwrap());
+        assert_eq!(quo, a.widen(256));
+        assert_eq!(rem, c.widen(128));
+
+        let (quo, rem) = c.div_rem(&NonZero::new(b.clone()).unwrap());
+        assert_eq!(quo, BoxedUint::zero_with_precision(64));
+        assert_eq!(rem, c.widen(128));
+        assert_eq!(c.div_rem_vartime(&NonZero::new(b).unwrap()), (quo, rem));
+    }
+
+    #[test]
+    fn div_rem_into() {
+        let a = BoxedUint::from(0xFFEECCBBAA99887766u128);
+        let b = NonZero::new(BoxedUint::from(0x123456789ABCDEF0u64)).unwrap();
+
+        let mut quo = BoxedUint::max(128);
+        let mut rem = BoxedUint::max(64);
+        a.div_rem_into(&b, &mut quo, &mut rem);
+        assert_eq!((quo, rem), a.div_rem(&b));
+    }
+
+    #[test]
+    fn div_rem_limb() {
+        let n = BoxedUint::from(0xFFEECCBBAA99887766u128);
+        let (quo, rem) = n.div_rem_limb(NonZero::new(Limb::from(997u32)).unwrap());
+        assert_eq!(quo, BoxedUint::from(0xFFEECCBBAA99887766u128 / 997));
+        assert_eq!(rem, Limb::from(648u32));
+        assert_eq!(
+            n.rem_limb(NonZero::new(Limb::from(997u32)).unwrap()),
+            Limb::from(648u32)
+        );
+    }
+
+    #[test]
+    fn rem_wide() {
+        let a = BoxedUint::from(0xFFEECCBBAA99887766u128);
+        let b = BoxedUint::from(0x123456789ABCDEF0u128);
+        let p = NonZero::new(BoxedUint::from(997u128)).unwrap();
+        let product = a.mul(&b);
+        let lower_upper = (product.shorten(128), product.shr_vartime(128).shorten(128));
+        let expected = 0xFFEECCBBAA99887766u128 % 997 * (0x123456789ABCDEF0u128 % 997) % 997;
+        assert_eq!(
+            BoxedUint::rem_wide(lower_upper, &p),
+            BoxedUint::from(expected)
+        );
+    }
+
+    #[test]
+    fn div_exact() {
+        let a = BoxedUint::from(0xFFEECCBBAA99887766u128).widen(256);
+        let b = BoxedUint::from(0x123456789ABCDEF0u128).widen(256);
+        let product = a.wrapping_mul(&b);
+        assert_eq!(product.div_exact(&NonZero::new(b.clone()).unwrap()), a);
+        assert_eq!(product.div_exact(&NonZero::new(a).unwrap()), b);
+    }
 }
@@ -0,0 +1,239 @@
+//! [`BoxedUint`] division by invariant multi-limb integers.
+
+use crate::{
+    uint::div_reciprocal::estimate_quotient, BoxedUint, Limb, MultiLimbReciprocal, Reciprocal,
+};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+impl MultiLimbReciprocal<BoxedUint> {
+    /// Pre-calculates a reciprocal for a known divisor, to be used in the division later.
+    /// Returns the reciprocal, which is none if `divisor == 0`.
+    ///
+    /// The reciprocal can be used with dividends of any precision.
+    pub fn new(divisor: &BoxedUint) -> CtOption<Self> {
+        let (params, is_some) = Self::new_inner(divisor);
+        CtOption::new(params, is_some)
+    }
+
+    /// Pre-calculates a reciprocal for a known divisor, returning it along with the truthy value if
+    /// `divisor != 0`. Otherwise the reciprocal is calculated for a divisor of 1.
+    pub(crate) fn new_inner(divisor: &BoxedUint) -> (Self, Choice) {
+        let bits_precision = divisor.bits_precision();
+        let is_some = !divisor.is_zero();
+
+        // Substitute a divisor of 1 if `divisor == 0`, so that the normalization works.
+        let divisor = BoxedUint::conditional_select(
+            &BoxedUint::one_with_precision(bits_precision),
+            divisor,
+            is_some,
+        );
+        let shift = bits_precision - divisor.bits();
+        let divisor_normalized = divisor.shl(shift);
+
+        // The most significant limb is nonzero since the divisor is normalized.
+        let top = divisor_normalized.limbs[divisor_normalized.nlimbs() - 1];
+        let reciprocal = *Reciprocal::ct_new(top).components_ref().0;
+
+        let params = Self {
+            divisor_normalized,
+            shift,
+            reciprocal,
+        };
+
+        (params, is_some)
+    }
+
+    /// Returns the divisor this reciprocal was calculated for.
+    pub fn divisor(&self) -> BoxedUint {
+        self.divisor_normalized.shr(self.shift)
+    }
+}
+
+impl BoxedUint {
+    /// Computes `self` / `rhs` using a pre-made reciprocal,
+    /// returns the quotient (q) and remainder (r).
+    ///
+    /// The precision of `self` may differ from the one of the divisor: the quotient has the
+    /// precision of `self`, and the remainder has the precision of the divisor.
+    ///
+    /// This function is constant-time with respect to both `self` and the divisor.
+    pub fn div_rem_with_reciprocal(&self, reciprocal: &MultiLimbReciprocal<Self>) -> (Self, Self) {
+        let divisor = &reciprocal.divisor_normalized;
+        let bits_precision = self.bits_precision().max(divisor.bits_precision());
+        let dividend = self.widen(bits_precision);
+        let mut quo = Self::zero_with_precision(bits_precision);
+        let mut rem = Self::zero_with_precision(divisor.bits_precision());
+        dividend.div_rem_with_reciprocal_into(reciprocal, &mut quo, &mut rem);
+
+        (quo.shorten(self.bits_precision()), rem)
+    }
+
+    /// Computes `self` / `rhs` using a pre-made reciprocal, writing the quotient to `quotient`
+    /// and the remainder to `remainder` without allocating.
+    ///
+    /// The quotient must have the precision of `self`, and the remainder the precision of the
+    /// divisor, which must not be larger than the one of `self`.
+    ///
+    /// This function is constant-time with respect to both `self` and the divisor.
+    pub fn div_rem_with_reciprocal_into(
+        &self,
+        reciprocal: &MultiLimbReciprocal<Self>,
+        quotient: &mut Self,
+        remainder: &mut Self,
+    ) {
+        let divisor = &reciprocal.divisor_normalized;
+        assert!(
+            self.bits_precision() >= divisor.bits_precision(),
+            "divisor precision exceeds dividend precision"
+        );
+        assert_eq!(
+            quotient.bits_precision(),
+            self.bits_precision(),
+            "quotient precision mismatch"
+        );
+        assert_eq!(
+            remainder.bits_precision(),
+            divisor.bits_precision(),
+            "remainder precision mismatch"
+        );
+
+        // The shifted dividend, split into two parts. Since the divisor was shifted by the same
+        // amount, the upper part is smaller than the normalized divisor.
+        //
+        // The lower part is held in the quotient buffer: each of its limbs is consumed in the
+        // same step that computes the corresponding limb of the quotient.
+        quotient.limbs.copy_from_slice(&self.limbs);
+        quotient.shl_assign(reciprocal.shift);
+
+        // The upper part is `self >> (self.bits_precision() - shift)`, which is computed from the
+        // top limbs of `self` since `shift` is at most the precision of the divisor.
+        let nlimbs = remainder.nlimbs();
+        remainder
+            .limbs
+            .copy_from_slice(&self.limbs[self.nlimbs() - nlimbs..]);
+        remainder.shr_assign(divisor.bits_precision() - reciprocal.shift);
+
+        for j in (0..self.nlimbs()).rev() {
+            // Shift the next limb of the dividend into the partial remainder.
+            let top = remainder.limbs[nlimbs - 1];
+            remainder.limbs.copy_within(..nlimbs - 1, 1);
+            remainder.limbs[0] = quotient.limbs[j];
+
+            quotient.limbs[j] = div_rem_step(top, remainder, divisor, &reciprocal.reciprocal);
+        }
+
+        remainder.shr_assign(reciprocal.shift);
+    }
+
+    /// Computes `self` % `rhs` using a pre-made reciprocal, returns the remainder.
+    ///
+    /// The remainder has the precision of the divisor.
+    ///
+    /// This function is constant-time with respect to both `self` and the divisor.
+    pub fn rem_with_reciprocal(&self, reciprocal: &MultiLimbReciprocal<Self>) -> Self {
+        self.div_rem_with_reciprocal(reciprocal).1
+    }
+}
+
+/// Divides the partial remainder `top * 2^bits_precision + low`, which is smaller than
+/// `divisor * 2^Limb::BITS`, by the normalized `divisor` in place, returning a single-limb
+/// quotient.
+fn div_rem_step(
+    top: Limb,
+    low: &mut BoxedUint,
+    divisor: &BoxedUint,
+    reciprocal: &Reciprocal,
+) -> Limb {
+    let nlimbs = divisor.nlimbs();
+    let q = estimate_quotient(
+        top.0,
+        low.limbs[nlimbs - 1].0,
+        divisor.limbs[nlimbs - 1].0,
+        reciprocal,
+    );
+
+    // Subtract `q * divisor` from the partial remainder.
+    let (mut borrow, mut carry) = (Limb::ZERO, Limb::ZERO);
+    for (l, d) in low.limbs.iter_mut().zip(divisor.limbs.iter()) {
+        let (product, c) = Limb::ZERO.mac(*d, Limb(q), carry);
+        let (diff, b) = l.sbb(product, borrow);
+        *l = diff;
+        (borrow, carry) = (b, c);
+    }
+    let (mut top, borrow) = top.sbb(carry, borrow);
+    let mut negative = !borrow.ct_eq(&Limb::ZERO);
+    let mut q = Limb(q);
+
+    // Add the divisor back while the result is negative.
+    for _ in 0..2 {
+        let carry = low.conditional_adc_assign(divisor, negative);
+        let (sum_top, carry) = top.adc(Limb::ZERO, Limb(carry.unwrap_u8().into()));
+        top = Limb::conditional_select(&top, &sum_top, negative);
+        q = Limb::conditional_select(&q, &q.wrapping_sub(Limb::ONE), negative);
+        negative &= carry.ct_eq(&Limb::ZERO);
+    }
+
+    debug_assert_eq!(top, Limb::ZERO);
+    q
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{BoxedUint, MultiLimbReciprocal, NonZero};
+    use hex_literal::hex;
+
+    #[test]
+    fn div_rem_with_reciprocal() {
+        let divisor = BoxedUint::from_be_slice(
+            &hex!("00000000000000008000000000000000ffffffffffffffff"),
+            192,
+        )
+        .unwrap();
+        let dividend = BoxedUint::from_be_slice(
+            &hex!("ffed9235288bc781ae66267594c9c9500925e4749b575bd1"),
+            192,
+        )
+        .unwrap();
+
+        let reciprocal = MultiLimbReciprocal::<BoxedUint>::new(&divisor).unwrap();
+        assert_eq!(reciprocal.divisor(), divisor);
+
+        let expected = dividend.div_rem(&NonZero::new(divisor).unwrap());
+        assert_eq!(dividend.div_rem_with_reciprocal(&reciprocal), expected);
+        assert_eq!(dividend.rem_with_reciprocal(&reciprocal), expected.1);
+
+        let mut quo = BoxedUint::max(192);
+        let mut rem = BoxedUint::max(192);
+        dividend.div_rem_with_reciprocal_into(&reciprocal, &mut quo, &mut rem);
+        assert_eq!((quo, rem), expected);
+    }
+
+    #[test]
+    fn div_rem_with_reciprocal_mixed_precision() {
+        let divisor = BoxedUint::from(0xFFEECCBBAA998877665544332211u128);
+        let reciprocal = MultiLimbReciprocal::<BoxedUint>::new(&divisor).unwrap();
+        let dividend = BoxedUint::max(320);
+
+        let (quo, rem) = dividend.div_rem_with_reciprocal(&reciprocal);
+        assert_eq!(quo.bits_precision(), 320);
+        assert_eq!(rem.bits_precision(), 128);
+
+        let expected = dividend.div_rem_vartime(&NonZero::new(divisor.widen(320)).unwrap());
+        assert_eq!(quo, expected.0);
+        assert_eq!(rem, expected.1.shorten(128));
+
+        // A dividend narrower than the divisor
+        let dividend = BoxedUint::from(0x123456789u64);
+        let (quo, rem) = dividend.div_rem_with_reciprocal(&reciprocal);
+        assert_eq!(quo, BoxedUint::zero_with_precision(64));
+        assert_eq!(rem, dividend.widen(128));
+    }
+
+    #[test]
+    fn zero_divisor() {
+        let reciprocal =
+            MultiLimbReciprocal::<BoxedUint>::new(&BoxedUint::zero_with_precision(128));
+        assert!(bool::from(reciprocal.is_none()));
+    }
+}
@@ -4,14 +4,20 @@ use crate::BoxedUint;
 use subtle::{Choice, ConstantTimeEq, ConstantTimeLess, CtOption};
 
 impl BoxedUint {
-    /// Computes the multiplicative inverse of `self` mod `modulus`.
-    /// Returns `None` if an inverse does not exist.
+    /// Computes the multiplicative inverse of `self` mod `modulus`, where `modulus` may be even.
+    /// Returns `None` if an inverse does not exist, including when `modulus` is zero.
+    ///
+    /// This is constant-time with respect to both `self` and `modulus`.
     pub fn inv_mod(&self, modulus: &Self) -> CtOption<Self> {
         debug_assert_eq!(self.bits_precision(), modulus.bits_precision());
+        let bits_precision = self.bits_precision();
+        let one = Self::one_with_precision(bits_precision);
+        let modulus_is_nonzero = !modulus.is_zero();
 
-        // Decompose `modulus = s * 2^k` where `s` is odd
+        // Decompose `modulus = s * 2^k` where `s` is odd.
+        // A modulus of zero is replaced with 1, and the result discarded below.
         let k = modulus.trailing_zeros();
-        let s = modulus.shr(k);
+        let s = Self::conditional_select(&one, &modulus.shr(k), modulus_is_nonzero);
 
         // Decompose `self` into RNS with moduli `2^k` and `s` and calculate the inverses.
         // Using the fact that `(z^{-1} mod (m1 * m2)) mod m1 == z^{-1} mod m1`
@@ -28,13 +34,13 @@ impl BoxedUint {
         let m_odd_inv = s.inv_mod2k(k); // `s` is odd, so this always exists
 
         // This part is mod 2^k
-        let mask = Self::one().shl(k).wrapping_sub(&Self::one());
+        let mask = one.shl(k).wrapping_sub(&one);
         let t = (b.wrapping_sub(&a).wrapping_mul(&m_odd_inv)).bitand(&mask);
 
         // Will not overflow since `a <= s - 1`, `t <= 2^k - 1`,
         // so `a + s * t <= s * 2^k - 1 == modulus - 1`.
         let result = a.wrapping_add(&s.wrapping_mul(&t));
-        CtOption::new(result, a_is_some & b_is_some)
+        CtOption::new(result, a_is_some & b_is_some & modulus_is_nonzero)
     }
 
     /// Computes 1/`self` mod `2^k`.
@@ -141,8 +147,10 @@ impl BoxedUint {
 }
 
 #[cfg(test)]
+#[allow(clippy::unwrap_used)]
 mod tests {
     use super::BoxedUint;
+    use crate::NonZero;
     use hex_literal::hex;
 
     #[test]
@@ -173,4 +181,37 @@ mod tests {
         let a = v.inv_mod2k(256);
         assert_eq!(e, a);
     }
+
+    #[test]
+    fn inv_mod_even() {
+        // `λ(n)` for the RSA modulus `n = 1000000007 * 998244353`, which has a large power of two
+        let lambda = BoxedUint::from(1000000006u64 / 2 * 998244352u64).widen(256);
+        let e = BoxedUint::from(65537u64).widen(256);
+        let d = e.inv_mod(&lambda).unwrap();
+        assert_eq!(
+            e.mul(&d)
+                .rem_vartime(&NonZero::new(lambda.widen(512)).unwrap()),
+            BoxedUint::one_with_precision(512)
+        );
+
+        // `3 * 2^100`, for which the power of two doesn't fit in a single limb
+        let modulus = BoxedUint::from(3u64).widen(256).shl(100);
+        let x = BoxedUint::from(7u64).widen(256);
+        let expected =
+            BoxedUint::from_be_slice(&hex!("00000006db6db6db6db6db6db6db6db7"), 256).unwrap();
+        assert_eq!(x.inv_mod(&modulus).unwrap(), expected);
+
+        // Even values have no inverse modulo an even modulus
+        assert!(bool::from(
+            BoxedUint::from(2u64).widen(256).inv_mod(&modulus).is_none()
+        ));
+    }
+
+    #[test]
+    fn inv_mod_zero_modulus() {
+        let x = BoxedUint::from(7u64).widen(256);
+        assert!(bool::from(
+            x.inv_mod(&BoxedUint::zero_with_precision(256)).is_none()
+        ));
+    }
 }
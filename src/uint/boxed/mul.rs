@@ -9,9 +9,22 @@ impl BoxedUint {
     ///
     /// Returns a widened output with a limb count equal to the sums of the input limb counts.
     pub fn mul(&self, rhs: &Self) -> Self {
-        let mut limbs = vec![Limb::ZERO; self.nlimbs() + rhs.nlimbs()];
-        mul_limbs(&self.limbs, &rhs.limbs, &mut limbs);
-        limbs.into()
+        let mut ret = Self::zero_with_precision(self.bits_precision() + rhs.bits_precision());
+        mul_limbs(&self.limbs, &rhs.limbs, &mut ret.limbs);
+        ret
+    }
+
+    /// Multiply `self` by `rhs`, writing the widened product to `out` without allocating.
+    ///
+    /// Panics if the precision of `out` is not the sum of the precisions of `self` and `rhs`.
+    pub fn mul_into(&self, rhs: &Self, out: &mut Self) {
+        assert_eq!(
+            out.nlimbs(),
+            self.nlimbs() + rhs.nlimbs(),
+            "output precision mismatch"
+        );
+        out.limbs.fill(Limb::ZERO);
+        mul_limbs(&self.limbs, &rhs.limbs, &mut out.limbs);
     }
 
     /// Perform wrapping multiplication, wrapping to the width of `self`.
@@ -24,6 +37,13 @@ impl BoxedUint {
         // TODO(tarcieri): more optimized implementation (shared with `Uint`?)
         self.mul(self)
     }
+
+    /// Multiply `self` by itself, writing the widened product to `out` without allocating.
+    ///
+    /// Panics if the precision of `out` is not twice the precision of `self`.
+    pub fn square_into(&self, out: &mut Self) {
+        self.mul_into(self, out)
+    }
 }
 
 impl CheckedMul<BoxedUint> for BoxedUint {
@@ -45,9 +65,7 @@ impl CheckedMul<&BoxedUint> for BoxedUint {
             .iter()
             .fold(Choice::from(1), |choice, limb| choice & limb.is_zero());
 
-        let mut limbs = product.limbs.into_vec();
-        limbs.truncate(self.nlimbs());
-        CtOption::new(limbs.into(), is_some)
+        CtOption::new(product.shorten(self.bits_precision()), is_some)
     }
 }
 
@@ -143,4 +161,19 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn mul_into() {
+        let a = BoxedUint::from(0x1234_5678_9abc_def0u64);
+        let b = BoxedUint::from(0xfedc_ba98_7654_3210_0011_2233_4455_6677u128);
+
+        // The output is overwritten, not accumulated into
+        let mut out = BoxedUint::max(192);
+        a.mul_into(&b, &mut out);
+        assert_eq!(out, a.mul(&b));
+
+        let mut out = BoxedUint::max(256);
+        b.square_into(&mut out);
+        assert_eq!(out, b.square());
+    }
 }
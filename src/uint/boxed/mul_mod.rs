@@ -1,15 +1,14 @@
 //! [`BoxedUint`] modular multiplication operations.
 
 use crate::{
-    modular::{BoxedResidue, BoxedResidueParams},
-    BoxedUint, Limb, MulMod, WideWord, Word,
+    modular::{boxed_residue::mul_mod_into, BoxedResidue, BoxedResidueParams, BoxedResidueScratch},
+    BoxedUint, Limb, MulMod, NonZero, WideWord, Word,
 };
 
 impl BoxedUint {
-    /// Computes `self * rhs mod p` for odd `p`.
+    /// Computes `self * rhs mod p`.
     ///
-    /// Panics if `p` is even.
-    // TODO(tarcieri): support for even `p`?
+    /// Panics if `p` is zero.
     pub fn mul_mod(&self, rhs: &BoxedUint, p: &BoxedUint) -> BoxedUint {
         // NOTE: the overhead of converting to Montgomery form to perform this operation and then
         // immediately converting out of Montgomery form after just a single operation is likely to
@@ -24,10 +23,30 @@ impl BoxedUint {
                 let ret = lhs * rhs;
                 ret.retrieve()
             }
-            None => todo!("even moduli are currently unsupported"),
+            None => {
+                // Even moduli don't support Montgomery form, so reduce the product directly.
+                let p = Option::<NonZero<BoxedUint>>::from(NonZero::new(p.clone()))
+                    .expect("modulus is zero");
+                self.mul(rhs).rem(&p)
+            }
         }
     }
 
+    /// Computes `self * rhs mod p` for the odd modulus `p` of the given `residue_params`, writing
+    /// the result to `out` and using the given scratch space instead of allocating.
+    ///
+    /// Panics if the precisions of `self`, `rhs`, `out` and `scratch` don't match the precision
+    /// of `residue_params`.
+    pub fn mul_mod_into(
+        &self,
+        rhs: &Self,
+        residue_params: &BoxedResidueParams,
+        scratch: &mut BoxedResidueScratch,
+        out: &mut Self,
+    ) {
+        mul_mod_into(self, rhs, residue_params, scratch, out);
+    }
+
     /// Computes `self * rhs mod p` for the special modulus
     /// `p = MAX+1-c` where `c` is small enough to fit in a single [`Limb`].
     ///
@@ -76,7 +95,7 @@ impl MulMod for BoxedUint {
 }
 
 /// Computes `a + (b * c) + carry`, returning the result along with the new carry.
-fn mac_by_limb(a: &BoxedUint, b: &BoxedUint, c: Limb, carry: Limb) -> (BoxedUint, Limb) {
+pub(crate) fn mac_by_limb(a: &BoxedUint, b: &BoxedUint, c: Limb, carry: Limb) -> (BoxedUint, Limb) {
     let mut a = a.clone();
     let mut carry = carry;
 
@@ -20,16 +20,16 @@ impl BoxedUint {
 
     /// Perform wrapping negation.
     pub fn wrapping_neg(&self) -> Self {
-        let mut ret = vec![Limb::ZERO; self.nlimbs()];
+        let mut ret = Self::zero_with_precision(self.bits_precision());
         let mut carry = 1;
 
         for i in 0..self.nlimbs() {
             let r = (!self.limbs[i].0 as WideWord) + carry;
-            ret[i] = Limb(r as Word);
+            ret.limbs[i] = Limb(r as Word);
             carry = r >> Limb::BITS;
         }
 
-        ret.into()
+        ret
     }
 }
 
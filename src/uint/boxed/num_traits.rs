@@ -0,0 +1,346 @@
+//! [`BoxedUint`] interoperability with the [`num-traits`] crate.
+//!
+//! As with [`Uint`][`crate::Uint`], traits which require the arithmetic operators are implemented
+//! on [`Wrapping<BoxedUint>`].
+//!
+//! Values created from primitives or parsed from strings have the smallest precision able to
+//! represent them (but at least one limb).
+//!
+//! [`num-traits`]: https://docs.rs/num-traits
+
+use crate::{
+    uint::num_traits::{from_str_radix_limbs, limbs_to_u128, FromStrRadixError},
+    BoxedUint, Limb, NonZero, Wrapping,
+};
+use num_traits::{
+    CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr, CheckedSub,
+    FromPrimitive, Num, One, Saturating, ToPrimitive, Unsigned, WrappingAdd, WrappingMul,
+    WrappingNeg, WrappingSub, Zero,
+};
+use subtle::ConstantTimeEq;
+
+impl ToPrimitive for BoxedUint {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u128().and_then(|n| n.try_into().ok())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| n.try_into().ok())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_u128().and_then(|n| n.try_into().ok())
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        limbs_to_u128(&self.limbs)
+    }
+}
+
+impl FromPrimitive for BoxedUint {
+    fn from_i64(n: i64) -> Option<Self> {
+        n.try_into().ok().map(Self::from_u64_min_precision)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Self::from_u64_min_precision(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        n.try_into().ok().map(Self::from_u128_min_precision)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        Some(Self::from_u128_min_precision(n))
+    }
+}
+
+impl BoxedUint {
+    /// Create a [`BoxedUint`] from a `u64` with the minimal precision required to hold it.
+    fn from_u64_min_precision(n: u64) -> Self {
+        Self::from_u128_min_precision(n.into())
+    }
+
+    /// Create a [`BoxedUint`] from a `u128` with the minimal precision required to hold it.
+    fn from_u128_min_precision(n: u128) -> Self {
+        let bits = (u128::BITS - n.leading_zeros()).max(1);
+        Self::from(n).shorten(bits)
+    }
+}
+
+impl Saturating for BoxedUint {
+    fn saturating_add(self, v: Self) -> Self {
+        let (result, carry) = self.adc(&v, Limb::ZERO);
+        Self::conditional_select(
+            &result,
+            &Self::max(self.bits_precision()),
+            carry.ct_eq(&Limb::ONE),
+        )
+    }
+
+    fn saturating_sub(self, v: Self) -> Self {
+        let (result, borrow) = self.sbb(&v, Limb::ZERO);
+        Self::conditional_select(
+            &result,
+            &Self::zero_with_precision(self.bits_precision()),
+            borrow.ct_eq(&Limb::MAX),
+        )
+    }
+}
+
+impl CheckedShl for BoxedUint {
+    fn checked_shl(&self, rhs: u32) -> Option<Self> {
+        (rhs < self.bits_precision()).then(|| self.shl(rhs))
+    }
+}
+
+impl CheckedShr for BoxedUint {
+    fn checked_shr(&self, rhs: u32) -> Option<Self> {
+        (rhs < self.bits_precision()).then(|| self.shr(rhs))
+    }
+}
+
+impl CheckedNeg for BoxedUint {
+    fn checked_neg(&self) -> Option<Self> {
+        bool::from(self.is_zero()).then(|| self.clone())
+    }
+}
+
+impl Zero for Wrapping<BoxedUint> {
+    fn zero() -> Self {
+        Wrapping(BoxedUint::zero())
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0.is_zero().into()
+    }
+}
+
+impl One for Wrapping<BoxedUint> {
+    fn one() -> Self {
+        Wrapping(BoxedUint::one())
+    }
+}
+
+impl Num for Wrapping<BoxedUint> {
+    type FromStrRadixErr = FromStrRadixError;
+
+    /// Parse a number in the given `radix`, which must lie in the range `[2, 36]`.
+    ///
+    /// The result has the smallest precision able to represent the parsed number.
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, FromStrRadixError> {
+        let bits_per_digit = u32::BITS - radix.saturating_sub(1).leading_zeros();
+        let bits_precision = (s.len() as u32).saturating_mul(bits_per_digit).max(1);
+
+        let mut ret = BoxedUint::zero_with_precision(bits_precision);
+        from_str_radix_limbs(s, radix, &mut ret.limbs)?;
+
+        // Leading zeros and digits which don't use all of `bits_per_digit` may leave extra limbs
+        let bits = ret.bits_vartime().max(1);
+        Ok(Wrapping(ret.shorten(bits)))
+    }
+}
+
+impl Unsigned for Wrapping<BoxedUint> {}
+
+impl ToPrimitive for Wrapping<BoxedUint> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.0.to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.0.to_u128()
+    }
+}
+
+impl FromPrimitive for Wrapping<BoxedUint> {
+    fn from_i64(n: i64) -> Option<Self> {
+        BoxedUint::from_i64(n).map(Wrapping)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        BoxedUint::from_u64(n).map(Wrapping)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        BoxedUint::from_i128(n).map(Wrapping)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        BoxedUint::from_u128(n).map(Wrapping)
+    }
+}
+
+impl CheckedAdd for Wrapping<BoxedUint> {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Option::from(crate::CheckedAdd::checked_add(&self.0, &v.0)).map(Wrapping)
+    }
+}
+
+impl CheckedSub for Wrapping<BoxedUint> {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Option::from(crate::CheckedSub::checked_sub(&self.0, &v.0)).map(Wrapping)
+    }
+}
+
+impl CheckedMul for Wrapping<BoxedUint> {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Option::from(crate::CheckedMul::checked_mul(&self.0, &v.0)).map(Wrapping)
+    }
+}
+
+impl CheckedDiv for Wrapping<BoxedUint> {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        Option::from(self.0.checked_div(&v.0)).map(Wrapping)
+    }
+}
+
+impl CheckedRem for Wrapping<BoxedUint> {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        Option::<NonZero<BoxedUint>>::from(NonZero::new(v.0.clone()))
+            .map(|v| Wrapping(self.0.rem(&v)))
+    }
+}
+
+impl WrappingAdd for Wrapping<BoxedUint> {
+    fn wrapping_add(&self, v: &Self) -> Self {
+        Wrapping(self.0.wrapping_add(&v.0))
+    }
+}
+
+impl WrappingSub for Wrapping<BoxedUint> {
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        Wrapping(self.0.wrapping_sub(&v.0))
+    }
+}
+
+impl WrappingMul for Wrapping<BoxedUint> {
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        Wrapping(self.0.wrapping_mul(&v.0))
+    }
+}
+
+impl WrappingNeg for Wrapping<BoxedUint> {
+    fn wrapping_neg(&self) -> Self {
+        Wrapping(self.0.wrapping_neg())
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+mod biguint {
+    use crate::{
+        uint::num_traits::biguint::{biguint_to_limbs, limbs_to_biguint},
+        BoxedUint, Limb,
+    };
+    use num_bigint::BigUint;
+
+    impl From<&BoxedUint> for BigUint {
+        fn from(uint: &BoxedUint) -> BigUint {
+            limbs_to_biguint(&uint.limbs)
+        }
+    }
+
+    impl From<BoxedUint> for BigUint {
+        fn from(uint: BoxedUint) -> BigUint {
+            BigUint::from(&uint)
+        }
+    }
+
+    impl From<&BigUint> for BoxedUint {
+        /// Convert a [`BigUint`] into a [`BoxedUint`] with the minimal number of limbs required
+        /// to hold it (but at least one).
+        fn from(n: &BigUint) -> BoxedUint {
+            let bits_precision = u32::try_from(n.bits())
+                .expect("BigUint too large")
+                .max(Limb::BITS);
+
+            let mut ret = BoxedUint::zero_with_precision(bits_precision);
+            biguint_to_limbs(n, &mut ret.limbs).expect("precision should be sufficient");
+            ret
+        }
+    }
+
+    impl From<BigUint> for BoxedUint {
+        fn from(n: BigUint) -> BoxedUint {
+            BoxedUint::from(&n)
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use crate::{BoxedUint, FromStrRadixError, Limb, Wrapping};
+    use num_traits::{CheckedRem, FromPrimitive, Num, Saturating, ToPrimitive};
+
+    #[test]
+    fn from_str_radix() {
+        let n =
+            Wrapping::<BoxedUint>::from_str_radix("340282366920938463463374607431768211455", 10)
+                .unwrap();
+        assert_eq!(n.0.to_u128(), Some(u128::MAX));
+
+        let n = Wrapping::<BoxedUint>::from_str_radix("+ff", 16).unwrap();
+        assert_eq!(n.0, BoxedUint::from(0xffu8));
+
+        let n = Wrapping::<BoxedUint>::from_str_radix(&"0".repeat(100), 10).unwrap();
+        assert_eq!(n.0.bits_precision(), Limb::BITS);
+
+        let n =
+            Wrapping::<BoxedUint>::from_str_radix("000000000000000000000000000000001", 16).unwrap();
+        assert_eq!(n.0, BoxedUint::one());
+
+        let n = Wrapping::<BoxedUint>::from_str_radix("12z", 10);
+        assert_eq!(n, Err(FromStrRadixError::InvalidDigit));
+    }
+
+    #[test]
+    fn primitive_conversions() {
+        let n = BoxedUint::from_u128(u128::MAX).unwrap();
+        assert_eq!(n.bits_precision(), 128);
+        assert_eq!(n.to_u128(), Some(u128::MAX));
+        assert_eq!(n.to_u64(), None);
+        assert_eq!(BoxedUint::from_i64(-1), None);
+        assert_eq!(BoxedUint::from_u64(0).unwrap().bits_precision(), Limb::BITS);
+    }
+
+    #[test]
+    fn saturating() {
+        let max = BoxedUint::max(128);
+        let one = BoxedUint::one_with_precision(128);
+        assert_eq!(max.clone().saturating_add(one.clone()), max);
+        assert_eq!(
+            BoxedUint::zero_with_precision(128).saturating_sub(one),
+            BoxedUint::zero_with_precision(128)
+        );
+    }
+
+    #[test]
+    fn checked_rem() {
+        let a = Wrapping(BoxedUint::from(10u8));
+        assert_eq!(
+            a.checked_rem(&Wrapping(BoxedUint::from(3u8))).unwrap().0,
+            BoxedUint::one()
+        );
+        assert_eq!(a.checked_rem(&Wrapping(BoxedUint::zero())), None);
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn biguint_round_trip() {
+        use num_bigint::BigUint;
+
+        let n = BigUint::from(u128::MAX);
+        let uint = BoxedUint::from(&n);
+        assert_eq!(uint.bits_precision(), 128);
+        assert_eq!(BigUint::from(&uint), n);
+    }
+}
@@ -0,0 +1,124 @@
+//! Variable-time operations on [`Public`] boxed integers.
+
+use crate::{BoxedUint, NonZero, Public};
+use core::ops::{Div, Rem};
+
+impl Public<BoxedUint> {
+    /// Calculate the number of bits needed to represent this number in variable time.
+    pub fn bits_vartime(&self) -> u32 {
+        self.as_inner().bits_vartime()
+    }
+
+    /// Computes `self << shift` in variable time.
+    pub fn shl_vartime(&self, shift: u32) -> Self {
+        Self::new(self.as_inner().shl_vartime(shift))
+    }
+
+    /// Computes `self <<= shift` in place in variable time, without allocating.
+    pub fn shl_vartime_assign(&mut self, shift: u32) {
+        self.as_inner_mut().shl_vartime_assign(shift)
+    }
+
+    /// Computes `self >> shift` in variable time.
+    pub fn shr_vartime(&self, shift: u32) -> Self {
+        Self::new(self.as_inner().shr_vartime(shift))
+    }
+
+    /// Computes `self >>= shift` in place in variable time, without allocating.
+    pub fn shr_vartime_assign(&mut self, shift: u32) {
+        self.as_inner_mut().shr_vartime_assign(shift)
+    }
+
+    /// Computes `self / rhs` and `self % rhs` in variable time.
+    ///
+    /// Use `BoxedUint / &Public<NonZero<BoxedUint>>` and `BoxedUint % &Public<NonZero<BoxedUint>>`
+    /// to divide a secret value by a public divisor.
+    pub fn div_rem_vartime(&self, rhs: &Public<NonZero<BoxedUint>>) -> (Self, Self) {
+        let (q, r) = self.as_inner().div_rem_vartime(rhs.as_inner());
+        (Self::new(q), Self::new(r))
+    }
+
+    /// Computes `self % rhs` in variable time.
+    ///
+    /// The remainder has the precision of `rhs`.
+    pub fn rem_vartime(&self, rhs: &Public<NonZero<BoxedUint>>) -> Self {
+        Self::new(self.as_inner().rem_vartime(rhs.as_inner()))
+    }
+}
+
+impl Div<&Public<NonZero<BoxedUint>>> for BoxedUint {
+    type Output = BoxedUint;
+
+    /// Divides by a public divisor, in variable time with respect to `rhs` only.
+    fn div(self, rhs: &Public<NonZero<BoxedUint>>) -> BoxedUint {
+        self.div_rem_vartime(rhs.as_inner()).0
+    }
+}
+
+impl Div<&Public<NonZero<BoxedUint>>> for &BoxedUint {
+    type Output = BoxedUint;
+
+    /// Divides by a public divisor, in variable time with respect to `rhs` only.
+    fn div(self, rhs: &Public<NonZero<BoxedUint>>) -> BoxedUint {
+        self.div_rem_vartime(rhs.as_inner()).0
+    }
+}
+
+impl Rem<&Public<NonZero<BoxedUint>>> for BoxedUint {
+    type Output = BoxedUint;
+
+    /// Reduces by a public divisor, in variable time with respect to `rhs` only.
+    fn rem(self, rhs: &Public<NonZero<BoxedUint>>) -> BoxedUint {
+        self.rem_vartime(rhs.as_inner())
+    }
+}
+
+impl Rem<&Public<NonZero<BoxedUint>>> for &BoxedUint {
+    type Output = BoxedUint;
+
+    /// Reduces by a public divisor, in variable time with respect to `rhs` only.
+    fn rem(self, rhs: &Public<NonZero<BoxedUint>>) -> BoxedUint {
+        self.rem_vartime(rhs.as_inner())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{BoxedUint, NonZero, Public};
+
+    #[test]
+    fn vartime_ops() {
+        let x = Public::new(BoxedUint::from(0x1234_5678u64));
+        assert_eq!(x.bits_vartime(), 29);
+        assert_eq!(
+            x.shl_vartime(4).into_inner(),
+            BoxedUint::from(0x1_2345_6780u64)
+        );
+        assert_eq!(
+            x.shr_vartime(4).into_inner(),
+            BoxedUint::from(0x123_4567u64)
+        );
+
+        let mut y = x.clone();
+        y.shl_vartime_assign(8);
+        y.shr_vartime_assign(4);
+        assert_eq!(y.into_inner(), BoxedUint::from(0x1_2345_6780u64));
+
+        let d = Public::new(NonZero::new(BoxedUint::from(10u64)).unwrap());
+        let (q, r) = x.div_rem_vartime(&d);
+        assert_eq!(q.into_inner(), BoxedUint::from(0x1234_5678u64 / 10));
+        assert_eq!(r.into_inner(), BoxedUint::from(0x1234_5678u64 % 10));
+        assert_eq!(
+            x.rem_vartime(&d).into_inner(),
+            BoxedUint::from(0x1234_5678u64 % 10)
+        );
+    }
+
+    #[test]
+    fn public_operands() {
+        let secret = BoxedUint::from(0x1234_5678u64);
+        let d = Public::new(NonZero::new(BoxedUint::from(10u64)).unwrap());
+        assert_eq!(&secret / &d, BoxedUint::from(0x1234_5678u64 / 10));
+        assert_eq!(secret % &d, BoxedUint::from(0x1234_5678u64 % 10));
+    }
+}
@@ -3,6 +3,7 @@
 use super::BoxedUint;
 use crate::{uint::rand::random_mod_core, Limb, NonZero, Random, RandomMod};
 use rand_core::CryptoRngCore;
+use subtle::Choice;
 
 impl BoxedUint {
     /// Generate a cryptographically secure random [`BoxedUint`].
@@ -16,11 +17,58 @@ impl BoxedUint {
 
         // Since `bits_precision` will be rounded up on creation of `ret`,
         // we need to clear the high bits if the rounding occurred.
-        ret.limbs[ret.limbs.len() - 1] =
-            ret.limbs[ret.limbs.len() - 1] & (Limb::MAX >> (ret.bits_precision() - bits_precision));
+        let nlimbs = ret.nlimbs();
+        ret.limbs[nlimbs - 1] =
+            ret.limbs[nlimbs - 1] & (Limb::MAX >> (ret.bits_precision() - bits_precision));
 
         ret
     }
+
+    /// Generate a cryptographically secure random [`BoxedUint`] in the range `[lo, hi)`,
+    /// with the precision of `hi`.
+    ///
+    /// Like [`RandomMod::random_mod`], this uses rejection sampling: each candidate is generated
+    /// and compared in constant time, and only the number of rejected candidates, which is
+    /// independent of the returned value, is revealed through timing.
+    ///
+    /// Panics if `lo >= hi`, or if `lo` and `hi` have different precisions.
+    pub fn random_in_range(rng: &mut impl CryptoRngCore, lo: &Self, hi: &Self) -> Self {
+        assert_eq!(lo.bits_precision(), hi.bits_precision());
+        assert!(lo < hi, "empty range");
+        let width = NonZero(hi.wrapping_sub(lo));
+        lo.wrapping_add(&Self::random_mod(rng, &width))
+    }
+
+    /// Generate a cryptographically secure random [`BoxedUint`] with the given precision and
+    /// exactly `bit_length` bits, i.e. uniformly distributed in the range
+    /// `[2^(bit_length - 1), 2^bit_length)`.
+    ///
+    /// Panics if `bit_length` is zero or larger than `bits_precision`.
+    pub fn random_with_bit_length(
+        rng: &mut impl CryptoRngCore,
+        bit_length: u32,
+        bits_precision: u32,
+    ) -> Self {
+        assert!(
+            bit_length > 0 && bit_length <= bits_precision,
+            "bit length out of range"
+        );
+        // `random` rounds the precision up to whole limbs, which may exceed `bits_precision` when
+        // it isn't a multiple of `Limb::BITS`, so only copy its limbs.
+        let random = Self::random(rng, bit_length);
+        let mut ret = Self::zero_with_precision(bits_precision);
+        ret.limbs[..random.nlimbs()].copy_from_slice(&random.limbs);
+        ret.set_bit(bit_length - 1, Choice::from(1));
+        ret
+    }
+
+    /// Generate a cryptographically secure random odd [`BoxedUint`]
+    /// in range `[0, 2^bits_precision)`.
+    pub fn random_odd(rng: &mut impl CryptoRngCore, bits_precision: u32) -> Self {
+        let mut ret = Self::random(rng, bits_precision);
+        ret.limbs[0] |= Limb::ONE;
+        ret
+    }
 }
 
 impl RandomMod for BoxedUint {
@@ -45,6 +93,9 @@ mod tests {
     use crate::{BoxedUint, NonZero, RandomMod};
     use rand_core::SeedableRng;
 
+    /// Number of samples drawn in the distribution tests.
+    const SAMPLES: usize = 10_000;
+
     #[test]
     fn random() {
         let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
@@ -76,4 +127,55 @@ mod tests {
         // Check that the value is in range
         assert!(res < BoxedUint::from(0x10000000000000001u128));
     }
+
+    #[test]
+    fn random_in_range() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let lo = BoxedUint::from(1000u64);
+        let hi = BoxedUint::from(1010u64);
+        let mut counts = [0usize; 10];
+
+        for _ in 0..SAMPLES {
+            let res = BoxedUint::random_in_range(&mut rng, &lo, &hi);
+            assert!(res >= lo && res < hi);
+            counts[res.wrapping_sub(&lo).as_words()[0] as usize] += 1;
+        }
+
+        // Each value is expected `SAMPLES / 10` times, with a standard deviation of 30.
+        for count in counts {
+            assert!(
+                count.abs_diff(SAMPLES / 10) < 150,
+                "biased counts: {counts:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn random_with_bit_length() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        for bit_length in [1, 2, 63, 64, 65, 255, 256] {
+            let res = BoxedUint::random_with_bit_length(&mut rng, bit_length, 256);
+            assert_eq!(res.bits_precision(), 256);
+            assert_eq!(res.bits(), bit_length);
+        }
+
+        // Precisions which aren't a multiple of the limb size
+        for (bit_length, bits_precision) in [(1, 1), (63, 65), (65, 65), (100, 100), (129, 200)] {
+            let res = BoxedUint::random_with_bit_length(&mut rng, bit_length, bits_precision);
+            assert_eq!(
+                res.bits_precision(),
+                BoxedUint::zero_with_precision(bits_precision).bits_precision()
+            );
+            assert_eq!(res.bits(), bit_length);
+        }
+    }
+
+    #[test]
+    fn random_odd() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let res = BoxedUint::random_odd(&mut rng, 256);
+        assert_eq!(res.bits_precision(), 256);
+        assert!(bool::from(res.is_odd()));
+    }
 }
@@ -2,27 +2,32 @@
 
 use crate::{BoxedUint, CtChoice, Limb, Word};
 use core::ops::{Shl, ShlAssign};
-use subtle::{Choice, ConstantTimeLess};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
 
 impl BoxedUint {
     /// Computes `self << shift`.
     /// Returns zero if `shift >= Self::BITS`.
     pub fn shl(&self, shift: u32) -> Self {
+        let mut result = self.clone();
+        result.shl_assign(shift);
+        result
+    }
+
+    /// Computes `self <<= shift` in place, without allocating.
+    /// Sets `self` to zero if `shift >= Self::BITS`.
+    pub fn shl_assign(&mut self, shift: u32) {
         let overflow = !shift.ct_lt(&self.bits_precision());
         let shift = shift % self.bits_precision();
         let log2_bits = u32::BITS - self.bits_precision().leading_zeros();
-        let mut result = self.clone();
 
         for i in 0..log2_bits {
             let bit = Choice::from(((shift >> i) & 1) as u8);
-            result = Self::conditional_select(&result, &result.shl_vartime(1 << i), bit);
+            self.conditional_shl_vartime_assign(1 << i, bit);
         }
 
-        Self::conditional_select(
-            &result,
-            &Self::zero_with_precision(self.bits_precision()),
-            overflow,
-        )
+        for limb in self.limbs.iter_mut() {
+            limb.conditional_assign(&Limb::ZERO, overflow);
+        }
     }
 
     /// Computes `self << shift`.
@@ -31,25 +36,46 @@ impl BoxedUint {
     ///
     /// When used with a fixed `shift`, this function is constant-time with respect to `self`.
     #[inline(always)]
-    pub fn shl_vartime(&self, shift: u32) -> Self {
-        let nlimbs = self.nlimbs();
-        let mut limbs = vec![Limb::ZERO; nlimbs].into_boxed_slice();
+    pub(crate) fn shl_vartime(&self, shift: u32) -> Self {
+        let mut result = self.clone();
+        result.shl_vartime_assign(shift);
+        result
+    }
 
-        if shift >= self.bits_precision() {
-            return Self { limbs };
-        }
+    /// Computes `self <<= shift` in place, without allocating.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect to `self`.
+    #[inline(always)]
+    pub(crate) fn shl_vartime_assign(&mut self, shift: u32) {
+        self.conditional_shl_vartime_assign(shift, Choice::from(1));
+    }
 
+    /// Computes `self <<= shift` in place if `choice` is truthy, and leaves `self` unchanged
+    /// otherwise.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    fn conditional_shl_vartime_assign(&mut self, shift: u32, choice: Choice) {
+        let nlimbs = self.nlimbs();
         let shift_num = (shift / Limb::BITS) as usize;
         let rem = shift % Limb::BITS;
 
-        let mut i = nlimbs;
-        while i > shift_num {
-            i -= 1;
-            limbs[i] = self.limbs[i - shift_num];
-        }
+        // Each limb of the result only depends on the limbs at the same or lower positions, so the
+        // limbs can be updated in place starting from the most significant one.
+        for i in (0..nlimbs).rev() {
+            let mut limb = Limb::ZERO;
+
+            if i >= shift_num {
+                limb.0 = self.limbs[i - shift_num].0 << rem;
+
+                if rem > 0 && i > shift_num {
+                    limb.0 |= self.limbs[i - shift_num - 1].0 >> (Limb::BITS - rem);
+                }
+            }
 
-        let (new_lower, _carry) = (Self { limbs }).shl_limb(rem);
-        new_lower
+            self.limbs[i].conditional_assign(&limb, choice);
+        }
     }
 
     /// Computes `self << shift` where `0 <= shift < Limb::BITS`,
@@ -57,7 +83,7 @@ impl BoxedUint {
     #[inline(always)]
     pub(crate) fn shl_limb(&self, shift: u32) -> (Self, Limb) {
         let nlimbs = self.nlimbs();
-        let mut limbs = vec![Limb::ZERO; nlimbs].into_boxed_slice();
+        let mut ret = Self::zero_with_precision(self.bits_precision());
 
         let nz = CtChoice::from_u32_nonzero(shift);
         let lshift = shift;
@@ -69,24 +95,17 @@ impl BoxedUint {
             let mut limb = self.limbs[i].0 << lshift;
             let hi = self.limbs[i - 1].0 >> rshift;
             limb |= nz.if_true_word(hi);
-            limbs[i] = Limb(limb);
+            ret.limbs[i] = Limb(limb);
             i -= 1
         }
-        limbs[0] = Limb(self.limbs[0].0 << lshift);
-
-        (Self { limbs }, Limb(carry))
-    }
+        ret.limbs[0] = Limb(self.limbs[0].0 << lshift);
 
-    /// Computes `self >> 1` in constant-time.
-    pub(crate) fn shl1(&self) -> Self {
-        // TODO(tarcieri): optimized implementation
-        self.shl_vartime(1)
+        (ret, Limb(carry))
     }
 
-    /// Computes `self >> 1` in-place in constant-time.
+    /// Computes `self << 1` in-place in constant-time.
     pub(crate) fn shl1_assign(&mut self) {
-        // TODO(tarcieri): optimized implementation
-        *self = self.shl1();
+        self.shl_vartime_assign(1);
     }
 }
 
@@ -108,8 +127,7 @@ impl Shl<u32> for &BoxedUint {
 
 impl ShlAssign<u32> for BoxedUint {
     fn shl_assign(&mut self, shift: u32) {
-        // TODO(tarcieri): in-place implementation that avoids clone
-        *self = self.clone().shl(shift)
+        BoxedUint::shl_assign(self, shift)
     }
 }
 
@@ -127,5 +145,26 @@ mod tests {
             BoxedUint::from(0x80000000000000000u128),
             one.shl_vartime(67)
         );
+        assert_eq!(BoxedUint::zero_with_precision(128), one.shl_vartime(128));
+    }
+
+    #[test]
+    fn shl_assign() {
+        let n = BoxedUint::from(0x3c442b21f19185fe433f0a65af902b8fu128);
+
+        for shift in [0, 1, 63, 64, 65, 127, 128, 200] {
+            let mut m = n.clone();
+            m.shl_assign(shift);
+            assert_eq!(m, n.shl_vartime(shift));
+
+            let mut m = n.clone();
+            m.shl_vartime_assign(shift);
+            assert_eq!(m, n.shl_vartime(shift));
+        }
+
+        assert_eq!(
+            n.shl(68),
+            BoxedUint::from(0x3c442b21f19185fe433f0a65af902b8fu128 << 68)
+        );
     }
 }
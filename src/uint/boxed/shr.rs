@@ -2,27 +2,32 @@
 
 use crate::{BoxedUint, Limb};
 use core::ops::{Shr, ShrAssign};
-use subtle::{Choice, ConstantTimeLess};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
 
 impl BoxedUint {
-    /// Computes `self << shift`.
+    /// Computes `self >> shift`.
     /// Returns zero if `shift >= Self::BITS`.
     pub fn shr(&self, shift: u32) -> Self {
+        let mut result = self.clone();
+        result.shr_assign(shift);
+        result
+    }
+
+    /// Computes `self >>= shift` in place, without allocating.
+    /// Sets `self` to zero if `shift >= Self::BITS`.
+    pub fn shr_assign(&mut self, shift: u32) {
         let overflow = !shift.ct_lt(&self.bits_precision());
         let shift = shift % self.bits_precision();
         let log2_bits = u32::BITS - self.bits_precision().leading_zeros();
-        let mut result = self.clone();
 
         for i in 0..log2_bits {
             let bit = Choice::from(((shift >> i) & 1) as u8);
-            result = Self::conditional_select(&result, &result.shr_vartime(1 << i), bit);
+            self.conditional_shr_vartime_assign(1 << i, bit);
         }
 
-        Self::conditional_select(
-            &result,
-            &Self::zero_with_precision(self.bits_precision()),
-            overflow,
-        )
+        for limb in self.limbs.iter_mut() {
+            limb.conditional_assign(&Limb::ZERO, overflow);
+        }
     }
 
     /// Computes `self >> shift`.
@@ -31,38 +36,46 @@ impl BoxedUint {
     ///
     /// When used with a fixed `shift`, this function is constant-time with respect to `self`.
     #[inline(always)]
-    pub fn shr_vartime(&self, shift: u32) -> Self {
-        let nlimbs = self.nlimbs();
-        let full_shifts = (shift / Limb::BITS) as usize;
-        let small_shift = shift & (Limb::BITS - 1);
-        let mut limbs = vec![Limb::ZERO; nlimbs].into_boxed_slice();
+    pub(crate) fn shr_vartime(&self, shift: u32) -> Self {
+        let mut result = self.clone();
+        result.shr_vartime_assign(shift);
+        result
+    }
 
-        if shift > self.bits_precision() {
-            return Self { limbs };
-        }
+    /// Computes `self >>= shift` in place, without allocating.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    ///
+    /// When used with a fixed `shift`, this function is constant-time with respect to `self`.
+    #[inline(always)]
+    pub(crate) fn shr_vartime_assign(&mut self, shift: u32) {
+        self.conditional_shr_vartime_assign(shift, Choice::from(1));
+    }
 
-        let n = nlimbs - full_shifts;
-        let mut i = 0;
+    /// Computes `self >>= shift` in place if `choice` is truthy, and leaves `self` unchanged
+    /// otherwise.
+    ///
+    /// NOTE: this operation is variable time with respect to `shift` *ONLY*.
+    fn conditional_shr_vartime_assign(&mut self, shift: u32, choice: Choice) {
+        let nlimbs = self.nlimbs();
+        let shift_num = (shift / Limb::BITS) as usize;
+        let rem = shift % Limb::BITS;
 
-        if small_shift == 0 {
-            while i < n {
-                limbs[i] = Limb(self.limbs[i + full_shifts].0);
-                i += 1;
-            }
-        } else {
-            while i < n {
-                let mut lo = self.limbs[i + full_shifts].0 >> small_shift;
+        // Each limb of the result only depends on the limbs at the same or higher positions, so
+        // the limbs can be updated in place starting from the least significant one.
+        for i in 0..nlimbs {
+            let mut limb = Limb::ZERO;
 
-                if i < (nlimbs - 1) - full_shifts {
-                    lo |= self.limbs[i + full_shifts + 1].0 << (Limb::BITS - small_shift);
-                }
+            if shift_num < nlimbs - i {
+                limb.0 = self.limbs[i + shift_num].0 >> rem;
 
-                limbs[i] = Limb(lo);
-                i += 1;
+                if rem > 0 && shift_num < nlimbs - i - 1 {
+                    limb.0 |= self.limbs[i + shift_num + 1].0 << (Limb::BITS - rem);
+                }
             }
-        }
 
-        Self { limbs }
+            self.limbs[i].conditional_assign(&limb, choice);
+        }
     }
 
     /// Computes `self >> 1` in constant-time, returning a true [`Choice`] if the overflowing bit
@@ -109,8 +122,7 @@ impl Shr<u32> for &BoxedUint {
 
 impl ShrAssign<u32> for BoxedUint {
     fn shr_assign(&mut self, shift: u32) {
-        // TODO(tarcieri): in-place implementation that avoids clone
-        *self = self.clone().shr(shift);
+        BoxedUint::shr_assign(self, shift)
     }
 }
 
@@ -134,4 +146,24 @@ mod tests {
         assert_eq!(BoxedUint::from(2u8), n.shr_vartime(66));
         assert_eq!(BoxedUint::from(4u8), n.shr_vartime(65));
     }
+
+    #[test]
+    fn shr_assign() {
+        let n = BoxedUint::from(0x3c442b21f19185fe433f0a65af902b8fu128);
+
+        for shift in [0, 1, 63, 64, 65, 127, 128, 200] {
+            let mut m = n.clone();
+            m.shr_assign(shift);
+            assert_eq!(m, n.shr_vartime(shift));
+
+            let mut m = n.clone();
+            m.shr_vartime_assign(shift);
+            assert_eq!(m, n.shr_vartime(shift));
+        }
+
+        assert_eq!(
+            n.shr(68),
+            BoxedUint::from(0x3c442b21f19185fe433f0a65af902b8fu128 >> 68)
+        );
+    }
 }
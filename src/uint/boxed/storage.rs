@@ -0,0 +1,195 @@
+//! Limb storage for [`BoxedUint`] which keeps small integers inline.
+
+use crate::{Limb, U256};
+use alloc::{boxed::Box, vec::Vec};
+use core::{
+    hash::{Hash, Hasher},
+    mem,
+    ops::{Deref, DerefMut},
+};
+
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
+#[cfg(doc)]
+use super::BoxedUint;
+
+/// Capacity of the inline storage, in limbs.
+pub(crate) const INLINE_LIMBS: usize = U256::LIMBS;
+
+/// Storage for the limbs of a [`BoxedUint`].
+///
+/// Integers of up to [`INLINE_LIMBS`] limbs are stored inline, larger ones spill to the heap.
+/// Which representation is used only depends on the number of limbs, i.e. on the precision of the
+/// integer and never on its value, so it has no impact on constant-time operations.
+#[derive(Clone)]
+pub(crate) enum LimbStorage {
+    /// Limbs stored inline: only the first `len` limbs are part of the integer.
+    Inline {
+        limbs: [Limb; INLINE_LIMBS],
+        len: usize,
+    },
+    /// Limbs stored on the heap.
+    Heap(Box<[Limb]>),
+}
+
+impl LimbStorage {
+    /// Should `nlimbs` limbs be stored inline?
+    const fn fits_inline(nlimbs: usize) -> bool {
+        nlimbs <= INLINE_LIMBS
+    }
+
+    /// Create storage for `nlimbs` limbs, all set to `limb`.
+    pub(crate) fn from_elem(limb: Limb, nlimbs: usize) -> Self {
+        if Self::fits_inline(nlimbs) {
+            let mut limbs = [Limb::ZERO; INLINE_LIMBS];
+            limbs[..nlimbs].fill(limb);
+            Self::Inline { limbs, len: nlimbs }
+        } else {
+            Self::Heap(vec![limb; nlimbs].into_boxed_slice())
+        }
+    }
+
+    /// Is this storage allocated on the heap?
+    #[cfg(test)]
+    pub(crate) fn is_heap(&self) -> bool {
+        matches!(self, Self::Heap(_))
+    }
+}
+
+impl Deref for LimbStorage {
+    type Target = [Limb];
+
+    fn deref(&self) -> &[Limb] {
+        match self {
+            Self::Inline { limbs, len } => &limbs[..*len],
+            Self::Heap(limbs) => limbs,
+        }
+    }
+}
+
+impl DerefMut for LimbStorage {
+    fn deref_mut(&mut self) -> &mut [Limb] {
+        match self {
+            Self::Inline { limbs, len } => &mut limbs[..*len],
+            Self::Heap(limbs) => limbs,
+        }
+    }
+}
+
+impl AsRef<[Limb]> for LimbStorage {
+    fn as_ref(&self) -> &[Limb] {
+        self
+    }
+}
+
+impl AsMut<[Limb]> for LimbStorage {
+    fn as_mut(&mut self) -> &mut [Limb] {
+        self
+    }
+}
+
+impl Hash for LimbStorage {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.deref().hash(state);
+    }
+}
+
+#[cfg(feature = "zeroize")]
+impl Zeroize for LimbStorage {
+    fn zeroize(&mut self) {
+        match self {
+            Self::Inline { limbs, .. } => limbs.zeroize(),
+            Self::Heap(limbs) => limbs.zeroize(),
+        }
+    }
+}
+
+/// Zeroizes inline limbs on drop, like the stack-allocated limbs of a [`Uint`].
+///
+/// [`Uint`]: crate::Uint
+#[cfg(feature = "zeroize")]
+impl Drop for LimbStorage {
+    fn drop(&mut self) {
+        if let Self::Inline { limbs, .. } = self {
+            limbs.zeroize();
+        }
+    }
+}
+
+impl From<&[Limb]> for LimbStorage {
+    fn from(slice: &[Limb]) -> Self {
+        if Self::fits_inline(slice.len()) {
+            let mut limbs = [Limb::ZERO; INLINE_LIMBS];
+            limbs[..slice.len()].copy_from_slice(slice);
+            Self::Inline {
+                limbs,
+                len: slice.len(),
+            }
+        } else {
+            Self::Heap(slice.into())
+        }
+    }
+}
+
+impl From<Vec<Limb>> for LimbStorage {
+    fn from(vec: Vec<Limb>) -> Self {
+        if Self::fits_inline(vec.len()) {
+            vec.as_slice().into()
+        } else {
+            Self::Heap(vec.into_boxed_slice())
+        }
+    }
+}
+
+impl From<LimbStorage> for Box<[Limb]> {
+    fn from(mut storage: LimbStorage) -> Box<[Limb]> {
+        match &mut storage {
+            LimbStorage::Inline { limbs, len } => limbs[..*len].into(),
+            LimbStorage::Heap(limbs) => mem::take(limbs),
+        }
+    }
+}
+
+impl FromIterator<Limb> for LimbStorage {
+    fn from_iter<I: IntoIterator<Item = Limb>>(iter: I) -> Self {
+        let mut iter = iter.into_iter();
+        let mut limbs = [Limb::ZERO; INLINE_LIMBS];
+        let mut len = 0;
+
+        while let Some(limb) = iter.next() {
+            if !Self::fits_inline(len + 1) {
+                // Spill to the heap
+                let mut vec = Vec::with_capacity(INLINE_LIMBS * 2);
+                vec.extend_from_slice(&limbs[..len]);
+                vec.push(limb);
+                vec.extend(iter);
+                return Self::Heap(vec.into_boxed_slice());
+            }
+
+            limbs[len] = limb;
+            len += 1;
+        }
+
+        Self::Inline { limbs, len }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LimbStorage, INLINE_LIMBS};
+    use crate::Limb;
+
+    #[test]
+    fn from_iter() {
+        let inline: LimbStorage = (0..INLINE_LIMBS).map(|i| Limb::from(i as u8)).collect();
+        assert!(!inline.is_heap());
+        assert_eq!(inline.len(), INLINE_LIMBS);
+
+        let heap: LimbStorage = (0..=INLINE_LIMBS).map(|i| Limb::from(i as u8)).collect();
+        assert!(heap.is_heap());
+        assert_eq!(heap.len(), INLINE_LIMBS + 1);
+        assert_eq!(&heap[..INLINE_LIMBS], &*inline);
+        assert_eq!(heap[INLINE_LIMBS], Limb::from(INLINE_LIMBS as u8));
+    }
+}
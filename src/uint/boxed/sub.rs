@@ -1,6 +1,8 @@
 //! [`BoxedUint`] subtraction operations.
 
-use crate::{BoxedUint, CheckedSub, Limb, Zero};
+use core::ops::{Sub, SubAssign};
+
+use crate::{BoxedUint, CheckedSub, Limb, Wrapping, Zero};
 use subtle::{Choice, ConditionallySelectable, CtOption};
 
 impl BoxedUint {
@@ -58,10 +60,55 @@ impl CheckedSub<&BoxedUint> for BoxedUint {
     }
 }
 
+impl Sub<Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    type Output = Self;
+
+    fn sub(self, rhs: Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl Sub<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    type Output = Self;
+
+    fn sub(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl Sub<Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn sub(self, rhs: Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl Sub<&Wrapping<BoxedUint>> for &Wrapping<BoxedUint> {
+    type Output = Wrapping<BoxedUint>;
+
+    fn sub(self, rhs: &Wrapping<BoxedUint>) -> Wrapping<BoxedUint> {
+        Wrapping(self.0.wrapping_sub(&rhs.0))
+    }
+}
+
+impl SubAssign<Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    fn sub_assign(&mut self, other: Wrapping<BoxedUint>) {
+        *self = Wrapping(self.0.wrapping_sub(&other.0));
+    }
+}
+
+impl SubAssign<&Wrapping<BoxedUint>> for Wrapping<BoxedUint> {
+    fn sub_assign(&mut self, other: &Wrapping<BoxedUint>) {
+        *self = Wrapping(self.0.wrapping_sub(&other.0));
+    }
+}
+
 #[cfg(test)]
 #[allow(clippy::unwrap_used)]
 mod tests {
     use super::{BoxedUint, CheckedSub, Limb};
+    use crate::Wrapping;
 
     #[test]
     fn sbb_no_borrow() {
@@ -92,4 +139,10 @@ mod tests {
         let result = BoxedUint::zero().checked_sub(&BoxedUint::one());
         assert!(!bool::from(result.is_some()));
     }
+
+    #[test]
+    fn wrapping_sub() {
+        let result = Wrapping(BoxedUint::zero()) - Wrapping(BoxedUint::one());
+        assert_eq!(result.0, BoxedUint::max(Limb::BITS));
+    }
 }
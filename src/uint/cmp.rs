@@ -3,14 +3,14 @@
 //! By default these are all constant-time and use the `subtle` crate.
 
 use super::Uint;
-use crate::{CtChoice, Limb};
+use crate::{ConstCtOption, CtChoice, Limb, Word};
 use core::cmp::Ordering;
 use subtle::{Choice, ConstantTimeEq, ConstantTimeGreater, ConstantTimeLess};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Return `b` if `c` is truthy, otherwise return `a`.
     #[inline]
-    pub(crate) const fn ct_select(a: &Self, b: &Self, c: CtChoice) -> Self {
+    pub const fn ct_select(a: &Self, b: &Self, c: CtChoice) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
 
         let mut i = 0;
@@ -22,6 +22,28 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint { limbs }
     }
 
+    /// Returns `table[index]`, scanning the full table in constant time. The result is none if
+    /// `index` is out of bounds.
+    ///
+    /// This is the `const` counterpart of [`ConstantTimeLookup::ct_lookup`].
+    ///
+    /// Panics if `table` is empty.
+    ///
+    /// [`ConstantTimeLookup::ct_lookup`]: crate::ConstantTimeLookup::ct_lookup
+    pub const fn const_ct_lookup(table: &[Self], index: usize) -> ConstCtOption<Self> {
+        let mut ret = table[0];
+
+        let mut i = 1;
+        while i < table.len() {
+            let choice = CtChoice::from_word_eq(i as Word, index as Word);
+            ret = Self::ct_select(&ret, &table[i], choice);
+            i += 1;
+        }
+
+        let in_bounds = CtChoice::from_word_lt(index as Word, table.len() as Word);
+        ConstCtOption::new(ret, in_bounds)
+    }
+
     #[inline]
     pub(crate) const fn ct_swap(a: &Self, b: &Self, c: CtChoice) -> (Self, Self) {
         let new_a = Self::ct_select(a, b, c);
@@ -32,7 +54,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
     /// Returns the truthy value if `self`!=0 or the falsy value otherwise.
     #[inline]
-    pub(crate) const fn ct_is_nonzero(&self) -> CtChoice {
+    pub const fn ct_is_nonzero(&self) -> CtChoice {
         let mut b = 0;
         let mut i = 0;
         while i < LIMBS {
@@ -43,13 +65,14 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Returns the truthy value if `self` is odd or the falsy value otherwise.
-    pub(crate) const fn ct_is_odd(&self) -> CtChoice {
+    #[inline]
+    pub const fn ct_is_odd(&self) -> CtChoice {
         CtChoice::from_word_lsb(self.limbs[0].0 & 1)
     }
 
-    /// Returns the truthy value if `self == rhs` or the falsy value otherwise.
+    /// Returns the truthy value if `lhs == rhs` or the falsy value otherwise.
     #[inline]
-    pub(crate) const fn ct_eq(lhs: &Self, rhs: &Self) -> CtChoice {
+    pub const fn ct_eq(lhs: &Self, rhs: &Self) -> CtChoice {
         let mut acc = 0;
         let mut i = 0;
 
@@ -62,9 +85,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Limb(acc).ct_is_nonzero().not()
     }
 
-    /// Returns the truthy value if `self <= rhs` and the falsy value otherwise.
+    /// Returns the truthy value if `lhs < rhs` and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn ct_lt(lhs: &Self, rhs: &Self) -> CtChoice {
+    pub const fn ct_lt(lhs: &Self, rhs: &Self) -> CtChoice {
         // We could use the same approach as in Limb::ct_lt(),
         // but since we have to use Uint::wrapping_sub(), which calls `sbb()`,
         // there are no savings compared to just calling `sbb()` directly.
@@ -72,9 +95,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         CtChoice::from_word_mask(borrow.0)
     }
 
-    /// Returns the truthy value if `self >= rhs` and the falsy value otherwise.
+    /// Returns the truthy value if `lhs > rhs` and the falsy value otherwise.
     #[inline]
-    pub(crate) const fn ct_gt(lhs: &Self, rhs: &Self) -> CtChoice {
+    pub const fn ct_gt(lhs: &Self, rhs: &Self) -> CtChoice {
         let (_res, borrow) = rhs.sbb(lhs, Limb::ZERO);
         CtChoice::from_word_mask(borrow.0)
     }
@@ -101,7 +124,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Returns the Ordering between `self` and `rhs` in variable time.
-    pub const fn cmp_vartime(&self, rhs: &Self) -> Ordering {
+    pub(crate) const fn cmp_vartime(&self, rhs: &Self) -> Ordering {
         let mut i = LIMBS - 1;
         loop {
             let (val, borrow) = self.limbs[i].sbb(rhs.limbs[i], Limb::ZERO);
@@ -0,0 +1,175 @@
+//! Conversions of [`Uint`] to and from digits in an arbitrary radix, e.g. for base32, base36 or
+//! base58 encodings.
+//!
+//! Digits are the numeric values in `0..radix` (not characters) ordered from the most
+//! significant to the least significant one, so that callers can map them to any alphabet.
+
+use super::{div_limb::Reciprocal, mul_mod::mac_by_limb};
+use crate::{Limb, Uint};
+use subtle::{Choice, ConstantTimeEq, ConstantTimeLess, CtOption};
+
+#[cfg(feature = "alloc")]
+use {crate::Word, alloc::vec::Vec};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Serializes `self` as digits in the given `radix`, which must be in the range `2..=256`.
+    ///
+    /// Returns the digits without leading zeros, or a single zero digit if `self == 0`.
+    ///
+    /// This function is variable-time with respect to `self`, see [`Uint::to_digits_fixed`]
+    /// for a constant-time variant.
+    #[cfg(feature = "alloc")]
+    pub fn to_digits(&self, radix: u32) -> Vec<u8> {
+        let (chunk, chunk_digits) = radix_chunk(radix);
+        let reciprocal = Reciprocal::ct_new(Limb(chunk));
+        let (reciprocal, _is_some) = reciprocal.components_ref();
+
+        // Divide by the largest power of the radix that fits in a limb, and split the remainders
+        // into digits using native arithmetic.
+        let mut digits = Vec::new();
+        let mut value = *self;
+        loop {
+            let (quo, rem) = value.ct_div_rem_limb_with_reciprocal(reciprocal);
+            push_chunk_digits(&mut digits, rem.0, radix, chunk_digits);
+            value = quo;
+
+            if value == Self::ZERO {
+                break;
+            }
+        }
+
+        finalize_digits(digits)
+    }
+
+    /// Serializes `self` as exactly `out.len()` digits in the given `radix`, which must be in the
+    /// range `2..=256`, padding with leading zeros.
+    ///
+    /// Returns the truthy value if `self` fits in the given number of digits, in which case the
+    /// digits are written to `out`. Otherwise the contents of `out` are unspecified.
+    ///
+    /// This function is constant-time with respect to `self`, and variable only with respect to
+    /// `radix` and `out.len()`, which makes it suitable for secret values.
+    pub fn to_digits_fixed(&self, radix: u32, out: &mut [u8]) -> Choice {
+        assert!((2..=256).contains(&radix), "unsupported radix");
+        let reciprocal = Reciprocal::ct_new(Limb::from_u32(radix));
+        let (reciprocal, _is_some) = reciprocal.components_ref();
+
+        let mut value = *self;
+        for digit in out.iter_mut().rev() {
+            let (quo, rem) = value.ct_div_rem_limb_with_reciprocal(reciprocal);
+            *digit = rem.0 as u8;
+            value = quo;
+        }
+
+        value.ct_is_nonzero().not().into()
+    }
+
+    /// Deserializes an integer from digits in the given `radix`, which must be in the range
+    /// `2..=256`. An empty slice of digits is decoded as zero.
+    ///
+    /// Returns none if any digit is out of range, or if the value does not fit in `Self`.
+    ///
+    /// This function is constant-time with respect to the values of the digits, and variable only
+    /// with respect to `radix` and `digits.len()`.
+    pub fn from_digits(radix: u32, digits: &[u8]) -> CtOption<Self> {
+        assert!((2..=256).contains(&radix), "unsupported radix");
+        let radix_limb = Limb::from_u32(radix);
+        let mut res = Self::ZERO;
+        let mut is_some = Choice::from(1);
+
+        for &digit in digits {
+            is_some &= u32::from(digit).ct_lt(&radix);
+            let (value, carry) = mac_by_limb(&Self::ZERO, &res, radix_limb, Limb::from_u8(digit));
+            is_some &= carry.ct_eq(&Limb::ZERO);
+            res = value;
+        }
+
+        CtOption::new(res, is_some)
+    }
+}
+
+/// Returns the largest power of `radix` that fits in a [`Word`], along with its exponent,
+/// i.e. the number of digits a single limb-sized remainder splits into.
+#[cfg(feature = "alloc")]
+pub(crate) fn radix_chunk(radix: u32) -> (Word, usize) {
+    assert!((2..=256).contains(&radix), "unsupported radix");
+    let radix = Word::from(radix);
+    let mut chunk = radix;
+    let mut chunk_digits = 1;
+
+    while let Some(next) = chunk.checked_mul(radix) {
+        chunk = next;
+        chunk_digits += 1;
+    }
+
+    (chunk, chunk_digits)
+}
+
+/// Appends the `chunk_digits` least significant digits of `rem` to `digits`, least significant
+/// digit first.
+#[cfg(feature = "alloc")]
+pub(crate) fn push_chunk_digits(digits: &mut Vec<u8>, rem: Word, radix: u32, chunk_digits: usize) {
+    let radix = Word::from(radix);
+    let mut rem = rem;
+
+    for _ in 0..chunk_digits {
+        digits.push((rem % radix) as u8);
+        rem /= radix;
+    }
+}
+
+/// Strips the leading zeros from digits accumulated in little-endian order (keeping at least one
+/// digit), and reverses them into the big-endian output order.
+#[cfg(feature = "alloc")]
+pub(crate) fn finalize_digits(mut digits: Vec<u8>) -> Vec<u8> {
+    while digits.len() > 1 && digits.last() == Some(&0) {
+        digits.pop();
+    }
+
+    digits.reverse();
+    digits
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{U128, U64};
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn to_digits() {
+        let n = U128::from_u128(0x1234_5678_9abc_def0_1122_3344_5566_7788);
+        let digits = n.to_digits(10);
+        let expected: alloc::vec::Vec<u8> = "24197857203266734864716154819477665672"
+            .bytes()
+            .map(|b| b - b'0')
+            .collect();
+        assert_eq!(digits, expected);
+        assert_eq!(U128::ZERO.to_digits(58), [0]);
+        assert_eq!(U128::from_u8(58).to_digits(58), [1, 0]);
+        assert_eq!(U128::MAX.to_digits(256), [0xff; 16]);
+    }
+
+    #[test]
+    fn to_digits_fixed() {
+        let n = U64::from_u32(1_000_000);
+        let mut out = [0xffu8; 9];
+        assert!(bool::from(n.to_digits_fixed(10, &mut out)));
+        assert_eq!(out, [0, 0, 1, 0, 0, 0, 0, 0, 0]);
+
+        let mut out = [0u8; 6];
+        assert!(!bool::from(n.to_digits_fixed(10, &mut out)));
+    }
+
+    #[test]
+    fn from_digits() {
+        let n = U128::from_u128(0x1234_5678_9abc_def0_1122_3344_5566_7788);
+        let mut digits = [0u8; 25];
+        assert!(bool::from(n.to_digits_fixed(36, &mut digits)));
+        assert_eq!(U128::from_digits(36, &digits).unwrap(), n);
+
+        assert_eq!(U128::from_digits(58, &[]).unwrap(), U128::ZERO);
+        assert_eq!(U128::from_digits(256, &[0xff; 16]).unwrap(), U128::MAX);
+        assert!(bool::from(U128::from_digits(256, &[1; 17]).is_none()));
+        assert!(bool::from(U128::from_digits(58, &[1, 58]).is_none()));
+    }
+}
@@ -1,7 +1,7 @@
 //! [`Uint`] division operations.
 
 use super::div_limb::{div_rem_limb_with_reciprocal, Reciprocal};
-use crate::{CheckedDiv, CtChoice, Limb, NonZero, Uint, Word, Wrapping};
+use crate::{CheckedDiv, ConstCtOption, CtChoice, Limb, NonZero, Uint, Word, Wrapping};
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use subtle::CtOption;
 
@@ -28,8 +28,9 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// and the falsy value otherwise.
     #[inline(always)]
     pub(crate) const fn ct_div_rem_limb(&self, rhs: Limb) -> (Self, Limb, CtChoice) {
-        let (reciprocal, is_some) = Reciprocal::ct_new(rhs);
-        let (quo, rem) = div_rem_limb_with_reciprocal(self, &reciprocal);
+        let reciprocal = Reciprocal::ct_new(rhs);
+        let (reciprocal, is_some) = reciprocal.components_ref();
+        let (quo, rem) = div_rem_limb_with_reciprocal(self, reciprocal);
         (quo, rem, is_some)
     }
 
@@ -113,15 +114,14 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         (quo, rem, is_some)
     }
 
-    /// Computes `self` % `rhs`, returns the remainder and and the truthy value for is_some or the
-    /// falsy value for is_none.
+    /// Computes `self` % `rhs`, returns the remainder, which is none if `rhs == 0`.
     ///
     /// NOTE: Use only if you need to access const fn. Otherwise use [`Self::rem`].
     /// This is variable only with respect to `rhs`.
     ///
     /// When used with a fixed `rhs`, this function is constant-time with respect
     /// to `self`.
-    pub const fn const_rem(&self, rhs: &Self) -> (Self, CtChoice) {
+    pub const fn const_rem(&self, rhs: &Self) -> ConstCtOption<Self> {
         let mb = rhs.bits_vartime();
         let mut bd = Self::BITS - mb;
         let mut rem = *self;
@@ -138,17 +138,16 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
 
         let is_some = CtChoice::from_u32_nonzero(mb);
-        (rem, is_some)
+        ConstCtOption::new(rem, is_some)
     }
 
-    /// Computes `self` % `rhs`, returns the remainder and
-    /// and the truthy value for is_some or the falsy value for is_none.
+    /// Computes `self` % `rhs`, returns the remainder, which is none if `rhs == 0`.
     ///
     /// This is variable only with respect to `rhs`.
     ///
     /// When used with a fixed `rhs`, this function is constant-time with respect
     /// to `self`.
-    pub const fn const_rem_wide(lower_upper: (Self, Self), rhs: &Self) -> (Self, CtChoice) {
+    pub const fn const_rem_wide(lower_upper: (Self, Self), rhs: &Self) -> ConstCtOption<Self> {
         let mb = rhs.bits_vartime();
 
         // The number of bits to consider is two sets of limbs * BITS - mb (modulus bitcount)
@@ -174,7 +173,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         }
 
         let is_some = CtChoice::from_u32_nonzero(mb);
-        (lower, is_some)
+        ConstCtOption::new(lower, is_some)
     }
 
     /// Computes `self` % 2^k. Faster than reduce since its a power of 2.
@@ -203,6 +202,46 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         out
     }
 
+    /// Computes `self` / `rhs` when `rhs` is known to divide `self`, using Jebelean's exact
+    /// division: the quotient limbs are computed starting from the least significant one, by
+    /// multiplying with the inverse of `rhs` modulo `2^Limb::BITS`.
+    ///
+    /// The result is unspecified if the division is not exact.
+    ///
+    /// This function is constant-time with respect to both `self` and `rhs`.
+    pub const fn div_exact(&self, rhs: &NonZero<Self>) -> Self {
+        // Remove the power of two, which `self` has to be divisible by as well,
+        // so that the divisor becomes odd.
+        let shift = rhs.0.trailing_zeros();
+        let divisor = rhs.0.shr(shift);
+        let mut rem = self.shr(shift).limbs;
+        let inverse = inv_mod_limb(divisor.limbs[0]);
+        let mut quo = [Limb::ZERO; LIMBS];
+
+        let mut i = 0;
+        while i < LIMBS {
+            // The lower `i` limbs of the remainder are zero at this point,
+            // and the quotient limb is chosen to clear the next one.
+            let q = rem[i].wrapping_mul(inverse);
+            quo[i] = q;
+
+            // Subtract `q * divisor * 2^(i * Limb::BITS)` from the remainder.
+            let (mut carry, mut borrow) = (Limb::ZERO, Limb::ZERO);
+            let mut j = i;
+            while j < LIMBS {
+                let (product, c) = Limb::ZERO.mac(divisor.limbs[j - i], q, carry);
+                let (diff, b) = rem[j].sbb(product, borrow);
+                rem[j] = diff;
+                (carry, borrow) = (c, b);
+                j += 1;
+            }
+
+            i += 1;
+        }
+
+        Uint::new(quo)
+    }
+
     /// Computes self / rhs, returns the quotient, remainder.
     pub fn div_rem(&self, rhs: &NonZero<Self>) -> (Self, Self) {
         // Since `rhs` is nonzero, this should always hold.
@@ -211,7 +250,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     }
 
     /// Computes self / rhs, returns the quotient, remainder. Constant-time only for fixed `rhs`.
-    pub fn div_rem_vartime(&self, rhs: &NonZero<Self>) -> (Self, Self) {
+    pub(crate) fn div_rem_vartime(&self, rhs: &NonZero<Self>) -> (Self, Self) {
         // Since `rhs` is nonzero, this should always hold.
         let (q, r, _c) = self.const_div_rem_vartime(rhs);
         (q, r)
@@ -220,8 +259,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes self % rhs, returns the remainder.
     pub fn rem(&self, rhs: &NonZero<Self>) -> Self {
         // Since `rhs` is nonzero, this should always hold.
-        let (r, _c) = self.const_rem(rhs);
-        r
+        *self.const_rem(rhs).components_ref().0
     }
 
     /// Wrapped division is just normal division i.e. `self` / `rhs`
@@ -240,7 +278,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// This function exists, so that all operations are accounted for in the wrapping operations.
     ///
     /// Panics if `rhs == 0`. Constant-time only for fixed `rhs`.
-    pub const fn wrapping_div_vartime(&self, rhs: &Self) -> Self {
+    pub(crate) const fn wrapping_div_vartime(&self, rhs: &Self) -> Self {
         let (q, _, c) = self.const_div_rem_vartime(rhs);
         assert!(c.is_true_vartime(), "divide by zero");
         q
@@ -261,9 +299,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     ///
     /// Panics if `rhs == 0`.
     pub const fn wrapping_rem(&self, rhs: &Self) -> Self {
-        let (r, c) = self.const_rem(rhs);
-        assert!(c.is_true_vartime(), "modulo zero");
-        r
+        self.const_rem(rhs).expect("modulo zero")
     }
 
     /// Perform checked reduction, returning a [`CtOption`] which `is_some`
@@ -277,6 +313,22 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 // Division by a single limb
 //
 
+/// Computes the inverse of an odd `limb` modulo `2^Limb::BITS` using Newton's iteration.
+pub(crate) const fn inv_mod_limb(limb: Limb) -> Limb {
+    debug_assert!(limb.0 & 1 == 1);
+
+    // `limb * limb == 1 (mod 8)` for an odd `limb`, and each iteration doubles the number
+    // of correct low bits.
+    let mut inverse = limb.0;
+    let mut i = 0;
+    while i < 5 {
+        inverse = inverse.wrapping_mul(Word::wrapping_sub(2, limb.0.wrapping_mul(inverse)));
+        i += 1;
+    }
+
+    Limb(inverse)
+}
+
 impl<const LIMBS: usize> Div<&NonZero<Limb>> for &Uint<LIMBS> {
     type Output = Uint<LIMBS>;
 
@@ -652,6 +704,28 @@ impl<const LIMBS: usize> RemAssign<&NonZero<Uint<LIMBS>>> for Wrapping<Uint<LIMB
     }
 }
 
+//
+// Division of wrapped values
+//
+
+/// Panics if `rhs == 0`, like [`core::num::Wrapping`].
+impl<const LIMBS: usize> Div for Wrapping<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        Wrapping(self.0.wrapping_div(&rhs.0))
+    }
+}
+
+/// Panics if `rhs == 0`, like [`core::num::Wrapping`].
+impl<const LIMBS: usize> Rem for Wrapping<Uint<LIMBS>> {
+    type Output = Self;
+
+    fn rem(self, rhs: Self) -> Self {
+        Wrapping(self.0.wrapping_rem(&rhs.0))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{Limb, NonZero, Uint, Word, U256};
@@ -749,43 +823,43 @@ mod tests {
 
     #[test]
     fn reduce_one() {
-        let (r, is_some) = U256::from(10u8).const_rem(&U256::ONE);
-        assert!(is_some.is_true_vartime());
-        assert_eq!(r, U256::ZERO);
+        let r = U256::from(10u8).const_rem(&U256::ONE);
+        assert!(r.is_some().is_true_vartime());
+        assert_eq!(r.unwrap(), U256::ZERO);
     }
 
     #[test]
     fn reduce_zero() {
         let u = U256::from(10u8);
-        let (r, is_some) = u.const_rem(&U256::ZERO);
-        assert!(!is_some.is_true_vartime());
-        assert_eq!(r, u);
+        let r = u.const_rem(&U256::ZERO);
+        assert!(r.is_none().is_true_vartime());
+        assert_eq!(*r.components_ref().0, u);
     }
 
     #[test]
     fn reduce_tests() {
-        let (r, is_some) = U256::from(10u8).const_rem(&U256::from(2u8));
-        assert!(is_some.is_true_vartime());
-        assert_eq!(r, U256::ZERO);
-        let (r, is_some) = U256::from(10u8).const_rem(&U256::from(3u8));
-        assert!(is_some.is_true_vartime());
-        assert_eq!(r, U256::ONE);
-        let (r, is_some) = U256::from(10u8).const_rem(&U256::from(7u8));
-        assert!(is_some.is_true_vartime());
-        assert_eq!(r, U256::from(3u8));
+        let r = U256::from(10u8).const_rem(&U256::from(2u8));
+        assert!(r.is_some().is_true_vartime());
+        assert_eq!(r.unwrap(), U256::ZERO);
+        let r = U256::from(10u8).const_rem(&U256::from(3u8));
+        assert!(r.is_some().is_true_vartime());
+        assert_eq!(r.unwrap(), U256::ONE);
+        let r = U256::from(10u8).const_rem(&U256::from(7u8));
+        assert!(r.is_some().is_true_vartime());
+        assert_eq!(r.unwrap(), U256::from(3u8));
     }
 
     #[test]
     fn reduce_tests_wide_zero_padded() {
-        let (r, is_some) = U256::const_rem_wide((U256::from(10u8), U256::ZERO), &U256::from(2u8));
-        assert!(is_some.is_true_vartime());
-        assert_eq!(r, U256::ZERO);
-        let (r, is_some) = U256::const_rem_wide((U256::from(10u8), U256::ZERO), &U256::from(3u8));
-        assert!(is_some.is_true_vartime());
-        assert_eq!(r, U256::ONE);
-        let (r, is_some) = U256::const_rem_wide((U256::from(10u8), U256::ZERO), &U256::from(7u8));
-        assert!(is_some.is_true_vartime());
-        assert_eq!(r, U256::from(3u8));
+        let r = U256::const_rem_wide((U256::from(10u8), U256::ZERO), &U256::from(2u8));
+        assert!(r.is_some().is_true_vartime());
+        assert_eq!(r.unwrap(), U256::ZERO);
+        let r = U256::const_rem_wide((U256::from(10u8), U256::ZERO), &U256::from(3u8));
+        assert!(r.is_some().is_true_vartime());
+        assert_eq!(r.unwrap(), U256::ONE);
+        let r = U256::const_rem_wide((U256::from(10u8), U256::ZERO), &U256::from(7u8));
+        assert!(r.is_some().is_true_vartime());
+        assert_eq!(r.unwrap(), U256::from(3u8));
     }
 
     #[test]
@@ -816,6 +890,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn div_exact() {
+        let a =
+            U256::from_be_hex("00000000000000000000000000000000fffffffffffffffffffffffffffffe8b");
+        let b =
+            U256::from_be_hex("000000000000000000000000000000000123456789abcdef0123456789abcde0");
+        let product = a.wrapping_mul(&b);
+        assert_eq!(product.div_exact(&NonZero::new(b).unwrap()), a);
+        assert_eq!(product.div_exact(&NonZero::new(a).unwrap()), b);
+        assert_eq!(U256::ZERO.div_exact(&NonZero::new(b).unwrap()), U256::ZERO);
+        assert_eq!(
+            product.div_exact(&NonZero::new(U256::ONE).unwrap()),
+            product
+        );
+    }
+
+    #[test]
+    fn inv_mod_limb() {
+        for limb in [1, 3, 0x1234_5679, Word::MAX] {
+            let inverse = super::inv_mod_limb(Limb(limb));
+            assert_eq!(inverse.wrapping_mul(Limb(limb)), Limb::ONE);
+        }
+    }
+
     #[allow(clippy::op_ref)]
     #[test]
     fn rem_trait() {
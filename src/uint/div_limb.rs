@@ -3,7 +3,10 @@
 //! (DOI: 10.1109/TC.2010.143, <https://gmplib.org/~tege/division-paper.pdf>).
 use subtle::{Choice, ConditionallySelectable, CtOption};
 
-use crate::{CtChoice, Limb, Uint, WideWord, Word};
+use crate::{ConstCtOption, CtChoice, Limb, Uint, WideWord, Word};
+
+#[cfg(feature = "alloc")]
+use {crate::BoxedUint, alloc::vec};
 
 /// Calculates the reciprocal of the given 32-bit divisor with the highmost bit set.
 #[cfg(target_pointer_width = "32")]
@@ -131,7 +134,7 @@ const fn addhilo(x_hi: Word, x_lo: Word, y_hi: Word, y_lo: Word) -> (Word, Word)
 /// Calculate the quotient and the remainder of the division of a wide word
 /// (supplied as high and low words) by `d`, with a precalculated reciprocal `v`.
 #[inline(always)]
-const fn div2by1(u1: Word, u0: Word, reciprocal: &Reciprocal) -> (Word, Word) {
+pub(crate) const fn div2by1(u1: Word, u0: Word, reciprocal: &Reciprocal) -> (Word, Word) {
     let d = reciprocal.divisor_normalized;
 
     debug_assert!(d >= (1 << (Word::BITS - 1)));
@@ -168,13 +171,12 @@ pub struct Reciprocal {
 impl Reciprocal {
     /// Pre-calculates a reciprocal for a known divisor,
     /// to be used in the single-limb division later.
-    /// Returns the reciprocal, and the truthy value if `divisor != 0`
-    /// and the falsy value otherwise.
+    /// Returns the reciprocal, which is none if `divisor == 0`.
     ///
-    /// Note: if the returned flag is falsy, the returned reciprocal object is still self-consistent
+    /// Note: if the result is none, the contained reciprocal object is still self-consistent
     /// and can be passed to functions here without causing them to panic,
     /// but the results are naturally not to be used.
-    pub const fn ct_new(divisor: Limb) -> (Self, CtChoice) {
+    pub const fn ct_new(divisor: Limb) -> ConstCtOption<Self> {
         // Assuming this is constant-time for primitive types.
         let shift = divisor.0.leading_zeros();
 
@@ -191,7 +193,7 @@ impl Reciprocal {
         let divisor_normalized = divisor.0 << shift;
         let divisor_normalized = is_some.select_word(Word::MAX, divisor_normalized);
 
-        (
+        ConstCtOption::new(
             Self {
                 divisor_normalized,
                 shift,
@@ -216,10 +218,19 @@ impl Reciprocal {
         }
     }
 
-    /// A non-const-fn version of `new_const()`, wrapping the result in a `CtOption`.
+    /// A non-const-fn version of `ct_new()`, wrapping the result in a `CtOption`.
     pub fn new(divisor: Limb) -> CtOption<Self> {
-        let (rec, is_some) = Self::ct_new(divisor);
-        CtOption::new(rec, is_some.into())
+        Self::ct_new(divisor).into()
+    }
+
+    /// Return `b` if `c` is truthy, otherwise return `a`.
+    #[inline]
+    pub(crate) const fn ct_select(a: &Self, b: &Self, c: CtChoice) -> Self {
+        Self {
+            divisor_normalized: c.select_word(a.divisor_normalized, b.divisor_normalized),
+            shift: c.select_u32(a.shift, b.shift),
+            reciprocal: c.select_word(a.reciprocal, b.reciprocal),
+        }
     }
 }
 
@@ -266,6 +277,25 @@ pub(crate) const fn div_rem_limb_with_reciprocal<const L: usize>(
     (Uint::<L>::new(q), Limb(r >> reciprocal.shift))
 }
 
+/// Divides `u` by the divisor encoded in the `reciprocal`, and returns
+/// the quotient and the remainder.
+#[cfg(feature = "alloc")]
+pub(crate) fn div_rem_limb_with_reciprocal_boxed(
+    u: &BoxedUint,
+    reciprocal: &Reciprocal,
+) -> (BoxedUint, Limb) {
+    let (u_shifted, u_hi) = u.shl_limb(reciprocal.shift);
+    let mut r = u_hi.0;
+    let mut q = vec![Limb::ZERO; u.nlimbs()];
+
+    for (qj, uj) in q.iter_mut().zip(u_shifted.as_limbs()).rev() {
+        let (qj_, rj) = div2by1(r, uj.0, reciprocal);
+        *qj = Limb(qj_);
+        r = rj;
+    }
+    (q.into(), Limb(r >> reciprocal.shift))
+}
+
 #[cfg(test)]
 mod tests {
     use super::{div2by1, Reciprocal};
@@ -0,0 +1,233 @@
+//! Division by invariant multi-limb integers, using a pre-calculated normalized divisor along with
+//! the reciprocal of its most significant limb.
+//!
+//! The quotient limbs are estimated from the two most significant limbs of the partial remainder
+//! as in Algorithm D of Knuth's TAOCP vol. 2 (4.3.1), with the single-limb divisions performed by
+//! `div2by1` from "Improved Division by Invariant Integers" by Niels Möller and Torbjorn Granlund.
+//! Since the divisor is normalized, each estimate exceeds the actual quotient limb by at most 2,
+//! so two constant-time corrections suffice.
+
+use super::{
+    div_limb::{div2by1, Reciprocal},
+    mul_mod::mac_by_limb,
+};
+use crate::{ConstCtOption, CtChoice, Limb, Uint, Word};
+use subtle::CtOption;
+
+/// A pre-calculated normalized divisor and reciprocal, to be used for repeated division by the
+/// same multi-limb integer (e.g. reducing many values modulo the same modulus).
+///
+/// This is the multi-limb counterpart of [`Reciprocal`], and is available for both
+/// [`Uint`] and `BoxedUint`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MultiLimbReciprocal<T> {
+    /// The divisor shifted left so that its most significant bit is set
+    pub(crate) divisor_normalized: T,
+    /// The number of leading zeros of the divisor
+    pub(crate) shift: u32,
+    /// The reciprocal of the most significant limb of the normalized divisor
+    pub(crate) reciprocal: Reciprocal,
+}
+
+impl<const LIMBS: usize> MultiLimbReciprocal<Uint<LIMBS>> {
+    /// Pre-calculates a reciprocal for a known divisor, to be used in the division later.
+    /// Returns the reciprocal, which is none if `divisor == 0`.
+    ///
+    /// Note: if the result is none, the contained reciprocal object is still self-consistent
+    /// and can be passed to functions here without causing them to panic,
+    /// but the results are naturally not to be used.
+    pub const fn ct_new(divisor: &Uint<LIMBS>) -> ConstCtOption<Self> {
+        let is_some = divisor.ct_is_nonzero();
+
+        // Substitute a divisor of 1 if `divisor == 0`, so that the normalization works.
+        let divisor = Uint::ct_select(&Uint::ONE, divisor, is_some);
+        let shift = divisor.leading_zeros();
+        let divisor_normalized = divisor.shl(shift);
+
+        // The most significant limb is nonzero since the divisor is normalized.
+        let reciprocal = Reciprocal::ct_new(divisor_normalized.limbs[LIMBS - 1]);
+        let (reciprocal, _) = reciprocal.components_ref();
+
+        ConstCtOption::new(
+            Self {
+                divisor_normalized,
+                shift,
+                reciprocal: *reciprocal,
+            },
+            is_some,
+        )
+    }
+
+    /// A non-const-fn version of `ct_new()`, wrapping the result in a `CtOption`.
+    pub fn new(divisor: &Uint<LIMBS>) -> CtOption<Self> {
+        Self::ct_new(divisor).into()
+    }
+
+    /// Returns the divisor this reciprocal was calculated for.
+    pub const fn divisor(&self) -> Uint<LIMBS> {
+        self.divisor_normalized.shr(self.shift)
+    }
+}
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Computes `self` / `rhs` using a pre-made reciprocal,
+    /// returns the quotient (q) and remainder (r).
+    ///
+    /// This function is constant-time with respect to both `self` and the divisor.
+    pub const fn div_rem_with_reciprocal(
+        &self,
+        reciprocal: &MultiLimbReciprocal<Self>,
+    ) -> (Self, Self) {
+        let divisor = &reciprocal.divisor_normalized;
+
+        // The shifted dividend, split into two halves. Since the divisor was shifted by the same
+        // amount, the upper half is smaller than the normalized divisor.
+        let lower = self.shl(reciprocal.shift);
+        let mut rem = self.shr(Self::BITS - reciprocal.shift);
+        let mut quo = [Limb::ZERO; LIMBS];
+
+        let mut j = LIMBS;
+        while j > 0 {
+            j -= 1;
+
+            // Shift the next limb of the dividend into the partial remainder.
+            let top = rem.limbs[LIMBS - 1];
+            let mut low = [Limb::ZERO; LIMBS];
+            low[0] = lower.limbs[j];
+            let mut i = 1;
+            while i < LIMBS {
+                low[i] = rem.limbs[i - 1];
+                i += 1;
+            }
+
+            let (q, r) = div_rem_step(top, Uint::new(low), divisor, &reciprocal.reciprocal);
+            quo[j] = q;
+            rem = r;
+        }
+
+        (Uint::new(quo), rem.shr(reciprocal.shift))
+    }
+
+    /// Computes `self` % `rhs` using a pre-made reciprocal, returns the remainder.
+    ///
+    /// This function is constant-time with respect to both `self` and the divisor.
+    pub const fn rem_with_reciprocal(&self, reciprocal: &MultiLimbReciprocal<Self>) -> Self {
+        self.div_rem_with_reciprocal(reciprocal).1
+    }
+}
+
+/// Divides the partial remainder `top * 2^BITS + low`, which is smaller than `divisor * 2^Limb::BITS`,
+/// by the normalized `divisor`, returning a single-limb quotient and the remainder.
+#[inline(always)]
+const fn div_rem_step<const LIMBS: usize>(
+    top: Limb,
+    low: Uint<LIMBS>,
+    divisor: &Uint<LIMBS>,
+    reciprocal: &Reciprocal,
+) -> (Limb, Uint<LIMBS>) {
+    let q = estimate_quotient(
+        top.0,
+        low.limbs[LIMBS - 1].0,
+        divisor.limbs[LIMBS - 1].0,
+        reciprocal,
+    );
+
+    // Subtract `q * divisor` from the partial remainder.
+    let (product, product_hi) = mac_by_limb(&Uint::ZERO, divisor, Limb(q), Limb::ZERO);
+    let (mut low, borrow) = low.sbb(&product, Limb::ZERO);
+    let (mut top, borrow) = top.sbb(product_hi, borrow);
+    let mut negative = CtChoice::from_word_mask(borrow.0);
+    let mut q = q;
+
+    // Add the divisor back while the result is negative.
+    let mut i = 0;
+    while i < 2 {
+        let (sum, carry) = low.adc(divisor, Limb::ZERO);
+        let (sum_top, carry) = top.adc(Limb::ZERO, carry);
+        low = Uint::ct_select(&low, &sum, negative);
+        top = Limb::ct_select(top, sum_top, negative);
+        q = negative.select_word(q, q.wrapping_sub(1));
+        negative = negative.and(CtChoice::from_word_nonzero(carry.0).not());
+        i += 1;
+    }
+
+    debug_assert!(top.0 == 0);
+    (Limb(q), low)
+}
+
+/// Estimates the quotient limb from the two most significant limbs of the partial remainder
+/// (`u1`, `u0`) and the most significant limb `d` of the normalized divisor, where `u1 <= d`.
+///
+/// The result is at least the actual quotient limb, and exceeds it by at most 2.
+#[inline(always)]
+pub(crate) const fn estimate_quotient(
+    u1: Word,
+    u0: Word,
+    d: Word,
+    reciprocal: &Reciprocal,
+) -> Word {
+    // If `u1 == d` the quotient would overflow a limb, and the estimate is `Word::MAX`.
+    let overflow = CtChoice::from_word_eq(u1, d);
+    let (q, _) = div2by1(overflow.select_word(u1, 0), u0, reciprocal);
+    overflow.select_word(q, Word::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MultiLimbReciprocal;
+    use crate::{NonZero, Uint, U192, U256, U64};
+
+    #[test]
+    fn div_rem_with_reciprocal() {
+        let divisors = [
+            U256::ONE,
+            U256::from_u64(0x1234_5678_9abc_def1),
+            U256::from_be_hex("0000000000000000ffffffffffffffffffffffffffffffffffffffffffffffff"),
+            U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff"),
+            U256::MAX,
+        ];
+        let dividends = [
+            U256::ZERO,
+            U256::ONE,
+            U256::from_be_hex("0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef"),
+            U256::from_be_hex("ffffffff00000001000000000000000000000000fffffffffffffffffffffffe"),
+            U256::MAX,
+        ];
+
+        for divisor in divisors {
+            let reciprocal = MultiLimbReciprocal::<U256>::new(&divisor).unwrap();
+            assert_eq!(reciprocal.divisor(), divisor);
+
+            for dividend in dividends {
+                let expected = dividend.div_rem(&NonZero::new(divisor).unwrap());
+                assert_eq!(dividend.div_rem_with_reciprocal(&reciprocal), expected);
+                assert_eq!(dividend.rem_with_reciprocal(&reciprocal), expected.1);
+            }
+        }
+    }
+
+    #[test]
+    fn div_rem_with_reciprocal_single_limb() {
+        let divisor = U64::from_u8(7);
+        let reciprocal = MultiLimbReciprocal::<U64>::new(&divisor).unwrap();
+        let (quo, rem) = U64::MAX.div_rem_with_reciprocal(&reciprocal);
+        assert_eq!(quo, U64::MAX.wrapping_div(&divisor));
+        assert_eq!(rem, U64::MAX.wrapping_rem(&divisor));
+    }
+
+    #[test]
+    fn quotient_estimate_correction() {
+        // On 64-bit targets the estimate of the last quotient limb is too large by two here.
+        let divisor = U192::from_be_hex("00000000000000008000000000000000ffffffffffffffff");
+        let dividend = U192::from_be_hex("ffed9235288bc781ae66267594c9c9500925e4749b575bd1");
+        let reciprocal = MultiLimbReciprocal::<U192>::new(&divisor).unwrap();
+        let expected = dividend.div_rem(&NonZero::new(divisor).unwrap());
+        assert_eq!(dividend.div_rem_with_reciprocal(&reciprocal), expected);
+    }
+
+    #[test]
+    fn zero_divisor() {
+        let reciprocal = MultiLimbReciprocal::<Uint<2>>::ct_new(&Uint::ZERO);
+        assert!(reciprocal.is_none().is_true_vartime());
+    }
+}
@@ -6,8 +6,39 @@ mod der;
 #[cfg(feature = "rlp")]
 mod rlp;
 
-use super::Uint;
+use super::{mul_mod::mac_by_limb, Uint};
 use crate::{Encoding, Limb, Word};
+use core::fmt;
+
+#[cfg(doc)]
+use crate::BoundedUint;
+
+/// Decoding errors for integers with a precision chosen at runtime, i.e. [`BoundedUint`] and
+/// `BoxedUint`, which are also returned by the conversions from those integers and their residues
+/// to fixed-precision types whenever the precisions don't fit.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DecodeError {
+    /// Input size is too small to fit in the given precision.
+    InputSize,
+
+    /// The deserialized number is larger than the given precision.
+    Precision,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InputSize => write!(f, "input size is too small to fit in the given precision"),
+            Self::Precision => write!(
+                f,
+                "the deserialized number is larger than the given precision"
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DecodeError {}
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Create a new [`Uint`] from the provided big endian bytes.
@@ -122,6 +153,83 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         Uint::new(res)
     }
 
+    /// Create a new [`Uint`] from a string of digits in the given `radix`, which must be in the
+    /// range `2..=36`. Digits above 9 are case-insensitive letters, and underscores may be used as
+    /// separators.
+    ///
+    /// Unlike [`Uint::from_be_hex`], the string can be shorter than the full width of the integer,
+    /// in which case it is implicitly padded with leading zeros.
+    ///
+    /// Panics if the string is empty, contains an invalid digit, or the value does not fit in
+    /// `Self`. This is intended for parsing constants, and runs in variable time.
+    pub const fn const_from_str_radix(src: &str, radix: u32) -> Self {
+        assert!(radix >= 2 && radix <= 36, "unsupported radix");
+
+        let bytes = src.as_bytes();
+        let mut res = Self::ZERO;
+        let mut has_digits = false;
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let byte = bytes[i];
+            i += 1;
+
+            if byte == b'_' {
+                continue;
+            }
+
+            let digit = match byte {
+                b'0'..=b'9' => byte - b'0',
+                b'a'..=b'z' => byte - b'a' + 10,
+                b'A'..=b'Z' => byte - b'A' + 10,
+                _ => panic!("invalid digit"),
+            };
+            assert!((digit as u32) < radix, "invalid digit");
+
+            let (value, carry) = mac_by_limb(
+                &Self::ZERO,
+                &res,
+                Limb::from_u32(radix),
+                Limb::from_u8(digit),
+            );
+            assert!(carry.0 == 0, "value does not fit in the integer");
+            res = value;
+            has_digits = true;
+        }
+
+        assert!(has_digits, "string contains no digits");
+        res
+    }
+
+    /// Create a double-width integer, split into its lower and upper halves, from big endian
+    /// bytes which are at most twice as long as [`Uint::BYTES`]. Shorter inputs are implicitly
+    /// padded with leading zeros.
+    pub(crate) const fn from_be_slice_wide(bytes: &[u8]) -> (Self, Self) {
+        assert!(
+            bytes.len() <= 2 * Self::BYTES,
+            "bytes are longer than twice the integer size"
+        );
+
+        let mut lower = [Limb::ZERO; LIMBS];
+        let mut upper = [Limb::ZERO; LIMBS];
+        let mut i = 0;
+
+        while i < bytes.len() {
+            let byte = bytes[bytes.len() - 1 - i] as Word;
+            let shift = (i % Limb::BYTES) * 8;
+            let limb = i / Limb::BYTES;
+
+            if limb < LIMBS {
+                lower[limb].0 |= byte << shift;
+            } else {
+                upper[limb - LIMBS].0 |= byte << shift;
+            }
+            i += 1;
+        }
+
+        (Uint::new(lower), Uint::new(upper))
+    }
+
     /// Serialize this [`Uint`] as big-endian, writing it into the provided
     /// byte slice.
     #[inline]
@@ -190,11 +298,11 @@ const fn decode_hex_byte(bytes: [u8; 2]) -> (u8, u16) {
 
 #[cfg(test)]
 mod tests {
-    use crate::Limb;
+    use crate::{Limb, U128};
     use hex_literal::hex;
 
     #[cfg(feature = "alloc")]
-    use {crate::U128, alloc::format};
+    use alloc::format;
 
     #[cfg(target_pointer_width = "32")]
     use crate::U64 as UintEx;
@@ -274,6 +382,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn const_from_str_radix() {
+        const DEC: U128 = U128::const_from_str_radix("1_000_000_000_000_000_000_000", 10);
+        assert_eq!(DEC, U128::from_u128(10u128.pow(21)));
+
+        let hex = U128::const_from_str_radix("DeadBeef", 16);
+        assert_eq!(hex, U128::from_u32(0xdeadbeef));
+
+        let max = U128::const_from_str_radix("ffffffff_ffffffff_ffffffff_ffffffff", 16);
+        assert_eq!(max, U128::MAX);
+        assert_eq!(U128::const_from_str_radix("1011", 2), U128::from_u8(11));
+        assert_eq!(U128::const_from_str_radix("00000z", 36), U128::from_u8(35));
+    }
+
+    #[test]
+    #[should_panic(expected = "value does not fit in the integer")]
+    fn const_from_str_radix_overflow() {
+        U128::const_from_str_radix("340282366920938463463374607431768211456", 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid digit")]
+    fn const_from_str_radix_invalid_digit() {
+        U128::const_from_str_radix("12a", 10);
+    }
+
     #[cfg(feature = "alloc")]
     #[test]
     fn hex_upper() {
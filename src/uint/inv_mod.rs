@@ -1,12 +1,12 @@
 use super::Uint;
-use crate::CtChoice;
+use crate::{ConstCtOption, CtChoice};
 
 impl<const LIMBS: usize> Uint<LIMBS> {
     /// Computes 1/`self` mod `2^k`.
     /// This method is constant-time w.r.t. `self` but not `k`.
     ///
     /// Conditions: `self` < 2^k and `self` must be odd
-    pub const fn inv_mod2k_vartime(&self, k: u32) -> Self {
+    pub(crate) const fn inv_mod2k_vartime(&self, k: u32) -> Self {
         // Using the Algorithm 3 from "A Secure Algorithm for Inversion Modulo 2k"
         // by Sadiel de la Fe and Carles Ferrer.
         // See <https://www.mdpi.com/2410-387X/2/3/23>.
@@ -71,8 +71,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// `bits` and `modulus_bits` are the bounds on the bit size
     /// of `self` and `modulus`, respectively
     /// (the inversion speed will be proportional to `bits + modulus_bits`).
-    /// The second element of the tuple is the truthy value if an inverse exists,
-    /// otherwise it is a falsy value.
+    /// The result is none if no inverse exists.
     ///
     /// **Note:** variable time in `bits` and `modulus_bits`.
     ///
@@ -82,7 +81,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
         modulus: &Self,
         bits: u32,
         modulus_bits: u32,
-    ) -> (Self, CtChoice) {
+    ) -> ConstCtOption<Self> {
         debug_assert!(modulus.ct_is_odd().is_true_vartime());
 
         let mut a = *self;
@@ -133,27 +132,26 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         debug_assert!(!a.ct_is_nonzero().is_true_vartime());
 
-        (v, Uint::ct_eq(&b, &Uint::ONE))
+        ConstCtOption::new(v, Uint::ct_eq(&b, &Uint::ONE))
     }
 
     /// Computes the multiplicative inverse of `self` mod `modulus`, where `modulus` is odd.
-    /// Returns `(inverse, CtChoice::TRUE)` if an inverse exists,
-    /// otherwise `(undefined, CtChoice::FALSE)`.
-    pub const fn inv_odd_mod(&self, modulus: &Self) -> (Self, CtChoice) {
+    /// The result is none if no inverse exists.
+    pub const fn inv_odd_mod(&self, modulus: &Self) -> ConstCtOption<Self> {
         self.inv_odd_mod_bounded(modulus, Uint::<LIMBS>::BITS, Uint::<LIMBS>::BITS)
     }
 
     /// Computes the multiplicative inverse of `self` mod `modulus`.
-    /// Returns `(inverse, CtChoice::TRUE)` if an inverse exists,
-    /// otherwise `(undefined, CtChoice::FALSE)`.
-    pub const fn inv_mod(&self, modulus: &Self) -> (Self, CtChoice) {
+    /// The result is none if no inverse exists.
+    pub const fn inv_mod(&self, modulus: &Self) -> ConstCtOption<Self> {
         // Decompose `modulus = s * 2^k` where `s` is odd
         let k = modulus.trailing_zeros();
         let s = modulus.shr(k);
 
         // Decompose `self` into RNS with moduli `2^k` and `s` and calculate the inverses.
         // Using the fact that `(z^{-1} mod (m1 * m2)) mod m1 == z^{-1} mod m1`
-        let (a, a_is_some) = self.inv_odd_mod(&s);
+        let a = self.inv_odd_mod(&s);
+        let (a, a_is_some) = a.components_ref();
         let b = self.inv_mod2k(k);
         // inverse modulo 2^k exists either if `k` is 0 or if `self` is odd.
         let b_is_some = CtChoice::from_u32_nonzero(k).not().or(self.ct_is_odd());
@@ -167,12 +165,12 @@ impl<const LIMBS: usize> Uint<LIMBS> {
 
         // This part is mod 2^k
         let mask = Uint::ONE.shl(k).wrapping_sub(&Uint::ONE);
-        let t = (b.wrapping_sub(&a).wrapping_mul(&m_odd_inv)).bitand(&mask);
+        let t = (b.wrapping_sub(a).wrapping_mul(&m_odd_inv)).bitand(&mask);
 
         // Will not overflow since `a <= s - 1`, `t <= 2^k - 1`,
         // so `a + s * t <= s * 2^k - 1 == modulus - 1`.
         let result = a.wrapping_add(&s.wrapping_mul(&t));
-        (result, a_is_some.and(b_is_some))
+        ConstCtOption::new(result, a_is_some.and(b_is_some))
     }
 }
 
@@ -218,14 +216,14 @@ mod tests {
             "3E520968399B4017BF98A864FABA2B647EFC4998B56774D4F2CB026BC024A336"
         ]);
 
-        let (res, is_some) = a.inv_odd_mod(&m);
-        assert!(is_some.is_true_vartime());
-        assert_eq!(res, expected);
+        let res = a.inv_odd_mod(&m);
+        assert!(res.is_some().is_true_vartime());
+        assert_eq!(res.unwrap(), expected);
 
         // Even though it is less efficient, it still works
-        let (res, is_some) = a.inv_mod(&m);
-        assert!(is_some.is_true_vartime());
-        assert_eq!(res, expected);
+        let res = a.inv_mod(&m);
+        assert!(res.is_some().is_true_vartime());
+        assert_eq!(res.unwrap(), expected);
     }
 
     #[test]
@@ -249,9 +247,9 @@ mod tests {
             "5B9BFAE5D43C6BC6E7A9856C71C7318C76530E9E5AE35882D5ABB02F1696874D",
         ]);
 
-        let (res, is_some) = a.inv_mod(&m);
-        assert!(is_some.is_true_vartime());
-        assert_eq!(res, expected);
+        let res = a.inv_mod(&m);
+        assert!(res.is_some().is_true_vartime());
+        assert_eq!(res.unwrap(), expected);
     }
 
     #[test]
@@ -269,7 +267,7 @@ mod tests {
             "558D0B64E37CD0775C0D0104AE7D98BA23C815185DD43CD8B16292FD94156767"
         ]);
 
-        let (res, is_some) = a.inv_odd_mod_bounded(&m, 768, 512);
+        let res = a.inv_odd_mod_bounded(&m, 768, 512);
 
         let expected = U1024::from_be_hex(concat![
             "0000000000000000000000000000000000000000000000000000000000000000",
@@ -277,8 +275,8 @@ mod tests {
             "0DCC94E2FE509E6EBBA0825645A38E73EF85D5927C79C1AD8FFE7C8DF9A822FA",
             "09EB396A21B1EF05CBE51E1A8EF284EF01EBDD36A9A4EA17039D8EEFDD934768"
         ]);
-        assert!(is_some.is_true_vartime());
-        assert_eq!(res, expected);
+        assert!(res.is_some().is_true_vartime());
+        assert_eq!(res.unwrap(), expected);
     }
 
     #[test]
@@ -286,10 +284,10 @@ mod tests {
         let a = U64::from(3u64);
         let m = U64::from(13u64);
 
-        let (res, is_some) = a.inv_odd_mod(&m);
+        let res = a.inv_odd_mod(&m);
 
-        assert!(is_some.is_true_vartime());
-        assert_eq!(U64::from(9u64), res);
+        assert!(res.is_some().is_true_vartime());
+        assert_eq!(U64::from(9u64), res.unwrap());
     }
 
     #[test]
@@ -297,8 +295,8 @@ mod tests {
         let a = U64::from(14u64);
         let m = U64::from(49u64);
 
-        let (_res, is_some) = a.inv_odd_mod(&m);
+        let res = a.inv_odd_mod(&m);
 
-        assert!(!is_some.is_true_vartime());
+        assert!(res.is_none().is_true_vartime());
     }
 }
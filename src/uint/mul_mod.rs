@@ -71,7 +71,7 @@ impl<const LIMBS: usize> MulMod for Uint<LIMBS> {
 }
 
 /// Computes `a + (b * c) + carry`, returning the result along with the new carry.
-const fn mac_by_limb<const LIMBS: usize>(
+pub(crate) const fn mac_by_limb<const LIMBS: usize>(
     a: &Uint<LIMBS>,
     b: &Uint<LIMBS>,
     c: Limb,
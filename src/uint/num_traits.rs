@@ -0,0 +1,435 @@
+//! Interoperability with the [`num-traits`] crate.
+//!
+//! The [`Uint`] type does not implement the standard arithmetic operators, so the traits from
+//! [`num-traits`] which require them (e.g. [`Zero`], [`One`], [`Num`]) are implemented on
+//! [`Wrapping<Uint>`] instead, whose operators have wrapping semantics.
+//!
+//! Note that most of these traits return [`Option`] or `bool` and are therefore not constant-time.
+//!
+//! [`num-traits`]: https://docs.rs/num-traits
+
+use super::Uint;
+use crate::{Limb, Word, Wrapping};
+use core::fmt;
+use num_traits::{
+    Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedNeg, CheckedRem, CheckedShl, CheckedShr,
+    CheckedSub, FromPrimitive, Num, One, Saturating, ToPrimitive, Unsigned, WrappingAdd,
+    WrappingMul, WrappingNeg, WrappingSub, Zero,
+};
+
+/// Error returned by [`Num::from_str_radix`] when parsing an integer fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FromStrRadixError {
+    /// The input string is empty.
+    Empty,
+
+    /// The input string contains a character which is not a digit in the given radix.
+    InvalidDigit,
+
+    /// The parsed number is too large to fit in the target precision.
+    Overflow,
+}
+
+impl fmt::Display for FromStrRadixError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "cannot parse integer from empty string"),
+            Self::InvalidDigit => write!(f, "invalid digit found in string"),
+            Self::Overflow => write!(f, "number too large to fit in target precision"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for FromStrRadixError {}
+
+/// Parse `s` as a number in the given `radix` into `limbs`, which must be zeroed.
+///
+/// Variable-time with respect to the input string.
+pub(crate) fn from_str_radix_limbs(
+    s: &str,
+    radix: u32,
+    limbs: &mut [Limb],
+) -> Result<(), FromStrRadixError> {
+    assert!(
+        (2..=36).contains(&radix),
+        "from_str_radix: radix must lie in the range `[2, 36]`"
+    );
+
+    let s = s.strip_prefix('+').unwrap_or(s);
+    if s.is_empty() {
+        return Err(FromStrRadixError::Empty);
+    }
+
+    for c in s.chars() {
+        let digit = c.to_digit(radix).ok_or(FromStrRadixError::InvalidDigit)?;
+
+        // limbs = limbs * radix + digit
+        let mut carry = Limb::from(digit);
+        for limb in limbs.iter_mut() {
+            (*limb, carry) = Limb::ZERO.mac(*limb, Limb::from(radix), carry);
+        }
+
+        if carry != Limb::ZERO {
+            return Err(FromStrRadixError::Overflow);
+        }
+    }
+
+    Ok(())
+}
+
+/// Convert `limbs` into a `u128`, returning `None` if the value doesn't fit.
+pub(crate) fn limbs_to_u128(limbs: &[Limb]) -> Option<u128> {
+    let mut ret = 0u128;
+
+    for (i, limb) in limbs.iter().enumerate() {
+        let shift = i as u32 * Limb::BITS;
+
+        if shift < u128::BITS {
+            ret |= u128::from(limb.0) << shift;
+        } else if limb.0 != 0 {
+            return None;
+        }
+    }
+
+    Some(ret)
+}
+
+/// Write `n` into `limbs`, returning `false` if the value doesn't fit.
+pub(crate) fn u128_to_limbs(n: u128, limbs: &mut [Limb]) -> bool {
+    let mut n = n;
+
+    for limb in limbs.iter_mut() {
+        limb.0 = n as Word;
+        n = n.checked_shr(Limb::BITS).unwrap_or(0);
+    }
+
+    n == 0
+}
+
+impl<const LIMBS: usize> Bounded for Uint<LIMBS> {
+    fn min_value() -> Self {
+        Self::ZERO
+    }
+
+    fn max_value() -> Self {
+        Self::MAX
+    }
+}
+
+impl<const LIMBS: usize> ToPrimitive for Uint<LIMBS> {
+    fn to_i64(&self) -> Option<i64> {
+        self.to_u128().and_then(|n| n.try_into().ok())
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|n| n.try_into().ok())
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.to_u128().and_then(|n| n.try_into().ok())
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        limbs_to_u128(&self.limbs)
+    }
+}
+
+impl<const LIMBS: usize> FromPrimitive for Uint<LIMBS> {
+    fn from_i64(n: i64) -> Option<Self> {
+        n.try_into()
+            .ok()
+            .and_then(<Self as FromPrimitive>::from_u128)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        <Self as FromPrimitive>::from_u128(n.into())
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        n.try_into()
+            .ok()
+            .and_then(<Self as FromPrimitive>::from_u128)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        let mut ret = Self::ZERO;
+        u128_to_limbs(n, &mut ret.limbs).then_some(ret)
+    }
+}
+
+impl<const LIMBS: usize> Saturating for Uint<LIMBS> {
+    fn saturating_add(self, v: Self) -> Self {
+        Uint::saturating_add(&self, &v)
+    }
+
+    fn saturating_sub(self, v: Self) -> Self {
+        Uint::saturating_sub(&self, &v)
+    }
+}
+
+impl<const LIMBS: usize> CheckedShl for Uint<LIMBS> {
+    fn checked_shl(&self, rhs: u32) -> Option<Self> {
+        (rhs < Self::BITS).then(|| self.shl(rhs))
+    }
+}
+
+impl<const LIMBS: usize> CheckedShr for Uint<LIMBS> {
+    fn checked_shr(&self, rhs: u32) -> Option<Self> {
+        (rhs < Self::BITS).then(|| self.shr(rhs))
+    }
+}
+
+impl<const LIMBS: usize> CheckedNeg for Uint<LIMBS> {
+    fn checked_neg(&self) -> Option<Self> {
+        bool::from(crate::Zero::is_zero(self)).then_some(Self::ZERO)
+    }
+}
+
+impl<const LIMBS: usize> Zero for Wrapping<Uint<LIMBS>> {
+    fn zero() -> Self {
+        Wrapping(Uint::ZERO)
+    }
+
+    fn is_zero(&self) -> bool {
+        crate::Zero::is_zero(&self.0).into()
+    }
+}
+
+impl<const LIMBS: usize> One for Wrapping<Uint<LIMBS>> {
+    fn one() -> Self {
+        Wrapping(Uint::ONE)
+    }
+}
+
+impl<const LIMBS: usize> Num for Wrapping<Uint<LIMBS>> {
+    type FromStrRadixErr = FromStrRadixError;
+
+    /// Parse a number in the given `radix`, which must lie in the range `[2, 36]`.
+    ///
+    /// Returns [`FromStrRadixError::Overflow`] if the number does not fit in `LIMBS`
+    /// (i.e. parsing is not wrapping).
+    fn from_str_radix(s: &str, radix: u32) -> Result<Self, FromStrRadixError> {
+        let mut ret = Uint::ZERO;
+        from_str_radix_limbs(s, radix, &mut ret.limbs)?;
+        Ok(Wrapping(ret))
+    }
+}
+
+impl<const LIMBS: usize> Unsigned for Wrapping<Uint<LIMBS>> {}
+
+impl<const LIMBS: usize> Bounded for Wrapping<Uint<LIMBS>> {
+    fn min_value() -> Self {
+        Wrapping(Uint::ZERO)
+    }
+
+    fn max_value() -> Self {
+        Wrapping(Uint::MAX)
+    }
+}
+
+impl<const LIMBS: usize> ToPrimitive for Wrapping<Uint<LIMBS>> {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        self.0.to_u64()
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        self.0.to_i128()
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        self.0.to_u128()
+    }
+}
+
+impl<const LIMBS: usize> FromPrimitive for Wrapping<Uint<LIMBS>> {
+    fn from_i64(n: i64) -> Option<Self> {
+        <Uint<LIMBS> as FromPrimitive>::from_i64(n).map(Wrapping)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        <Uint<LIMBS> as FromPrimitive>::from_u64(n).map(Wrapping)
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        <Uint<LIMBS> as FromPrimitive>::from_i128(n).map(Wrapping)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        <Uint<LIMBS> as FromPrimitive>::from_u128(n).map(Wrapping)
+    }
+}
+
+impl<const LIMBS: usize> CheckedAdd for Wrapping<Uint<LIMBS>> {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        Option::from(crate::CheckedAdd::checked_add(&self.0, &v.0)).map(Wrapping)
+    }
+}
+
+impl<const LIMBS: usize> CheckedSub for Wrapping<Uint<LIMBS>> {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        Option::from(crate::CheckedSub::checked_sub(&self.0, &v.0)).map(Wrapping)
+    }
+}
+
+impl<const LIMBS: usize> CheckedMul for Wrapping<Uint<LIMBS>> {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        Option::from(crate::CheckedMul::checked_mul(&self.0, &v.0)).map(Wrapping)
+    }
+}
+
+impl<const LIMBS: usize> CheckedDiv for Wrapping<Uint<LIMBS>> {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        Option::from(self.0.checked_div(&v.0)).map(Wrapping)
+    }
+}
+
+impl<const LIMBS: usize> CheckedRem for Wrapping<Uint<LIMBS>> {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        Option::from(self.0.checked_rem(&v.0)).map(Wrapping)
+    }
+}
+
+impl<const LIMBS: usize> WrappingAdd for Wrapping<Uint<LIMBS>> {
+    fn wrapping_add(&self, v: &Self) -> Self {
+        Wrapping(self.0.wrapping_add(&v.0))
+    }
+}
+
+impl<const LIMBS: usize> WrappingSub for Wrapping<Uint<LIMBS>> {
+    fn wrapping_sub(&self, v: &Self) -> Self {
+        Wrapping(self.0.wrapping_sub(&v.0))
+    }
+}
+
+impl<const LIMBS: usize> WrappingMul for Wrapping<Uint<LIMBS>> {
+    fn wrapping_mul(&self, v: &Self) -> Self {
+        Wrapping(self.0.wrapping_mul(&v.0))
+    }
+}
+
+impl<const LIMBS: usize> WrappingNeg for Wrapping<Uint<LIMBS>> {
+    fn wrapping_neg(&self) -> Self {
+        Wrapping(self.0.wrapping_neg())
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+pub(crate) mod biguint {
+    use super::Uint;
+    use crate::{DecodeError, Limb};
+    use alloc::vec::Vec;
+    use num_bigint::BigUint;
+
+    /// Convert little endian `limbs` into a [`BigUint`].
+    pub(crate) fn limbs_to_biguint(limbs: &[Limb]) -> BigUint {
+        let bytes: Vec<u8> = limbs.iter().flat_map(|limb| limb.0.to_le_bytes()).collect();
+        BigUint::from_bytes_le(&bytes)
+    }
+
+    /// Write `n` into `limbs`, returning [`DecodeError::Precision`] if it doesn't fit.
+    pub(crate) fn biguint_to_limbs(n: &BigUint, limbs: &mut [Limb]) -> Result<(), DecodeError> {
+        let bytes = n.to_bytes_le();
+
+        if bytes.len() > limbs.len() * Limb::BYTES {
+            return Err(DecodeError::Precision);
+        }
+
+        for (chunk, limb) in bytes.chunks(Limb::BYTES).zip(limbs.iter_mut()) {
+            *limb = Limb::from_le_slice(chunk);
+        }
+
+        Ok(())
+    }
+
+    impl<const LIMBS: usize> From<&Uint<LIMBS>> for BigUint {
+        fn from(uint: &Uint<LIMBS>) -> BigUint {
+            limbs_to_biguint(&uint.limbs)
+        }
+    }
+
+    impl<const LIMBS: usize> From<Uint<LIMBS>> for BigUint {
+        fn from(uint: Uint<LIMBS>) -> BigUint {
+            BigUint::from(&uint)
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<&BigUint> for Uint<LIMBS> {
+        type Error = DecodeError;
+
+        fn try_from(n: &BigUint) -> Result<Self, DecodeError> {
+            let mut ret = Self::ZERO;
+            biguint_to_limbs(n, &mut ret.limbs)?;
+            Ok(ret)
+        }
+    }
+
+    impl<const LIMBS: usize> TryFrom<BigUint> for Uint<LIMBS> {
+        type Error = DecodeError;
+
+        fn try_from(n: BigUint) -> Result<Self, DecodeError> {
+            Self::try_from(&n)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FromStrRadixError;
+    use crate::{Wrapping, U128, U64};
+    use num_traits::{CheckedAdd, FromPrimitive, Num, ToPrimitive, WrappingAdd};
+
+    #[test]
+    fn from_str_radix() {
+        let n = Wrapping::<U128>::from_str_radix("340282366920938463463374607431768211455", 10);
+        assert_eq!(n, Ok(Wrapping(U128::MAX)));
+
+        let n = Wrapping::<U128>::from_str_radix("ffffffffffffffffffffffffffffffff", 16);
+        assert_eq!(n, Ok(Wrapping(U128::MAX)));
+
+        let n = Wrapping::<U64>::from_str_radix("18446744073709551616", 10);
+        assert_eq!(n, Err(FromStrRadixError::Overflow));
+
+        let n = Wrapping::<U64>::from_str_radix("12z", 10);
+        assert_eq!(n, Err(FromStrRadixError::InvalidDigit));
+
+        let n = Wrapping::<U64>::from_str_radix("", 10);
+        assert_eq!(n, Err(FromStrRadixError::Empty));
+    }
+
+    #[test]
+    fn primitive_conversions() {
+        assert_eq!(
+            <U128 as FromPrimitive>::from_u128(u128::MAX),
+            Some(U128::MAX)
+        );
+        assert_eq!(<U64 as FromPrimitive>::from_u128(u128::MAX), None);
+        assert_eq!(U64::from_i64(-1), None);
+        assert_eq!(U128::MAX.to_u128(), Some(u128::MAX));
+        assert_eq!(U128::MAX.to_u64(), None);
+        assert_eq!(U128::from_u64(42).to_i64(), Some(42));
+    }
+
+    #[test]
+    fn checked_and_wrapping_add() {
+        let max = Wrapping(U64::MAX);
+        let one = Wrapping(U64::ONE);
+        assert_eq!(CheckedAdd::checked_add(&max, &one), None);
+        assert_eq!(WrappingAdd::wrapping_add(&max, &one), Wrapping(U64::ZERO));
+    }
+
+    #[cfg(feature = "num-bigint")]
+    #[test]
+    fn biguint_round_trip() {
+        use num_bigint::BigUint;
+
+        let n = BigUint::from(u128::MAX);
+        assert_eq!(U128::try_from(&n), Ok(U128::MAX));
+        assert_eq!(BigUint::from(U128::MAX), n);
+        assert!(U64::try_from(&n).is_err());
+    }
+}
@@ -0,0 +1,189 @@
+//! Variable-time operations on [`Public`] integers.
+
+use super::Uint;
+use crate::{NonZero, Public};
+use core::{
+    cmp::Ordering,
+    ops::{Div, Rem},
+};
+use subtle::CtOption;
+
+impl<const LIMBS: usize> Public<Uint<LIMBS>> {
+    /// Returns `true` if the bit at position `index` is set, `false` otherwise.
+    pub const fn bit_vartime(&self, index: u32) -> bool {
+        self.as_inner().bit_vartime(index)
+    }
+
+    /// Calculate the number of bits needed to represent this number.
+    pub const fn bits_vartime(&self) -> u32 {
+        self.as_inner().bits_vartime()
+    }
+
+    /// Calculate the number of leading zeros in the binary representation of this number.
+    pub const fn leading_zeros_vartime(&self) -> u32 {
+        self.as_inner().leading_zeros_vartime()
+    }
+
+    /// Calculate the number of trailing zeros in the binary representation of this number.
+    pub const fn trailing_zeros_vartime(&self) -> u32 {
+        self.as_inner().trailing_zeros_vartime()
+    }
+
+    /// Calculate the number of trailing ones in the binary representation of this number.
+    pub const fn trailing_ones_vartime(&self) -> u32 {
+        self.as_inner().trailing_ones_vartime()
+    }
+
+    /// Returns the Ordering between `self` and `rhs` in variable time.
+    pub const fn cmp_vartime(&self, rhs: &Self) -> Ordering {
+        self.as_inner().cmp_vartime(rhs.as_inner())
+    }
+
+    /// Computes `self << shift` in variable time.
+    pub const fn shl_vartime(&self, shift: u32) -> Self {
+        Self::new(self.as_inner().shl_vartime(shift))
+    }
+
+    /// Computes `self >> shift` in variable time.
+    pub const fn shr_vartime(&self, shift: u32) -> Self {
+        Self::new(self.as_inner().shr_vartime(shift))
+    }
+
+    /// Computes a left shift on a wide input as `(lo, hi)` in variable time.
+    pub const fn shl_vartime_wide(lower_upper: (Self, Self), shift: u32) -> (Self, Self) {
+        let (lower, upper) = Uint::shl_vartime_wide(
+            (*lower_upper.0.as_inner(), *lower_upper.1.as_inner()),
+            shift,
+        );
+        (Self::new(lower), Self::new(upper))
+    }
+
+    /// Computes a right shift on a wide input as `(lo, hi)` in variable time.
+    pub const fn shr_vartime_wide(lower_upper: (Self, Self), shift: u32) -> (Self, Self) {
+        let (lower, upper) = Uint::shr_vartime_wide(
+            (*lower_upper.0.as_inner(), *lower_upper.1.as_inner()),
+            shift,
+        );
+        (Self::new(lower), Self::new(upper))
+    }
+
+    /// Computes 1/`self` mod `2^k` in variable time.
+    ///
+    /// Conditions: `self` < 2^k and `self` must be odd
+    pub const fn inv_mod2k_vartime(&self, k: u32) -> Self {
+        Self::new(self.as_inner().inv_mod2k_vartime(k))
+    }
+
+    /// Computes √(`self`) in variable time.
+    pub const fn sqrt_vartime(&self) -> Self {
+        Self::new(self.as_inner().sqrt_vartime())
+    }
+
+    /// Wrapped sqrt is just normal √(`self`), computed in variable time.
+    pub const fn wrapping_sqrt_vartime(&self) -> Self {
+        Self::new(self.as_inner().wrapping_sqrt_vartime())
+    }
+
+    /// Perform checked sqrt in variable time, returning a [`CtOption`] which `is_some` only if
+    /// the √(`self`)² == self
+    pub fn checked_sqrt_vartime(&self) -> CtOption<Self> {
+        self.as_inner().checked_sqrt_vartime().map(Self::new)
+    }
+
+    /// Computes `self / rhs` and `self % rhs` in variable time.
+    ///
+    /// Use `Uint / &Public<NonZero<Uint>>` and `Uint % &Public<NonZero<Uint>>` to divide a secret
+    /// value by a public divisor.
+    pub fn div_rem_vartime(&self, rhs: &Public<NonZero<Uint<LIMBS>>>) -> (Self, Self) {
+        let (q, r) = self.as_inner().div_rem_vartime(rhs.as_inner());
+        (Self::new(q), Self::new(r))
+    }
+
+    /// Wrapped division is just normal division i.e. `self` / `rhs`, computed in variable time.
+    ///
+    /// Panics if `rhs == 0`.
+    pub const fn wrapping_div_vartime(&self, rhs: &Self) -> Self {
+        Self::new(self.as_inner().wrapping_div_vartime(rhs.as_inner()))
+    }
+}
+
+impl<const LIMBS: usize> Div<&Public<NonZero<Uint<LIMBS>>>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Divides by a public divisor, in variable time with respect to `rhs` only.
+    fn div(self, rhs: &Public<NonZero<Uint<LIMBS>>>) -> Uint<LIMBS> {
+        self.div_rem_vartime(rhs.as_inner()).0
+    }
+}
+
+impl<const LIMBS: usize> Div<&Public<NonZero<Uint<LIMBS>>>> for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Divides by a public divisor, in variable time with respect to `rhs` only.
+    fn div(self, rhs: &Public<NonZero<Uint<LIMBS>>>) -> Uint<LIMBS> {
+        self.div_rem_vartime(rhs.as_inner()).0
+    }
+}
+
+impl<const LIMBS: usize> Rem<&Public<NonZero<Uint<LIMBS>>>> for Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Reduces by a public divisor, in variable time with respect to `rhs` only.
+    fn rem(self, rhs: &Public<NonZero<Uint<LIMBS>>>) -> Uint<LIMBS> {
+        self.div_rem_vartime(rhs.as_inner()).1
+    }
+}
+
+impl<const LIMBS: usize> Rem<&Public<NonZero<Uint<LIMBS>>>> for &Uint<LIMBS> {
+    type Output = Uint<LIMBS>;
+
+    /// Reduces by a public divisor, in variable time with respect to `rhs` only.
+    fn rem(self, rhs: &Public<NonZero<Uint<LIMBS>>>) -> Uint<LIMBS> {
+        self.div_rem_vartime(rhs.as_inner()).1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonZero, Public, U128};
+    use core::cmp::Ordering;
+
+    #[test]
+    fn vartime_ops() {
+        let x = Public::new(U128::from_u64(0x1234_5678));
+        assert_eq!(x.bits_vartime(), 29);
+        assert!(x.bit_vartime(3));
+        assert_eq!(x.trailing_zeros_vartime(), 3);
+        assert_eq!(x.leading_zeros_vartime(), 99);
+        assert_eq!(x.cmp_vartime(&x.shr_vartime(1)), Ordering::Greater);
+        assert_eq!(x.shl_vartime(4).into_inner(), U128::from_u64(0x1_2345_6780));
+        assert_eq!(
+            Public::new(U128::from_u8(144)).sqrt_vartime().into_inner(),
+            U128::from_u8(12)
+        );
+        assert!(bool::from(
+            Public::new(U128::from_u8(145))
+                .checked_sqrt_vartime()
+                .is_none()
+        ));
+        assert_eq!(
+            Public::new(U128::from_u8(3))
+                .inv_mod2k_vartime(8)
+                .into_inner(),
+            U128::from_u8(171)
+        );
+
+        let d = Public::new(NonZero::new(U128::from_u8(10)).unwrap());
+        let (q, r) = x.div_rem_vartime(&d);
+        assert_eq!(q.into_inner(), U128::from_u64(0x1234_5678 / 10));
+        assert_eq!(r.into_inner(), U128::from_u64(0x1234_5678 % 10));
+    }
+
+    #[test]
+    fn public_operands() {
+        let secret = U128::from_u64(0x1234_5678);
+        let d = Public::new(NonZero::new(U128::from_u8(10)).unwrap());
+        assert_eq!(secret / &d, U128::from_u64(0x1234_5678 / 10));
+        assert_eq!(&secret % &d, U128::from_u64(0x1234_5678 % 10));
+    }
+}
@@ -37,6 +37,39 @@ impl<const LIMBS: usize> RandomMod for Uint<LIMBS> {
     }
 }
 
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Generate a cryptographically secure random [`Uint`] in the range `[lo, hi)`.
+    ///
+    /// Like [`RandomMod::random_mod`], this uses rejection sampling: each candidate is generated
+    /// and compared in constant time, and only the number of rejected candidates, which is
+    /// independent of the returned value, is revealed through timing.
+    ///
+    /// Panics if `lo >= hi`.
+    pub fn random_in_range(rng: &mut impl CryptoRngCore, lo: &Self, hi: &Self) -> Self {
+        assert!(lo < hi, "empty range");
+        let width = NonZero(hi.wrapping_sub(lo));
+        lo.wrapping_add(&Self::random_mod(rng, &width))
+    }
+
+    /// Generate a cryptographically secure random [`Uint`] with exactly `bit_length` bits,
+    /// i.e. uniformly distributed in the range `[2^(bit_length - 1), 2^bit_length)`.
+    ///
+    /// Panics if `bit_length` is zero or larger than [`Uint::BITS`].
+    pub fn random_with_bit_length(rng: &mut impl CryptoRngCore, bit_length: u32) -> Self {
+        assert!(
+            bit_length > 0 && bit_length <= Self::BITS,
+            "bit length out of range"
+        );
+        let n = Self::random(rng).shr_vartime(Self::BITS - bit_length);
+        n.bitor(&Self::ONE.shl_vartime(bit_length - 1))
+    }
+
+    /// Generate a cryptographically secure random odd [`Uint`].
+    pub fn random_odd(rng: &mut impl CryptoRngCore) -> Self {
+        Self::random(rng).bitor(&Self::ONE)
+    }
+}
+
 /// Generic implementation of `random_mod` which can be shared with `BoxedUint`.
 // TODO(tarcieri): obtain `n_bits` via a trait like `Integer`
 pub(super) fn random_mod_core<T>(
@@ -78,6 +111,9 @@ mod tests {
     use crate::{NonZero, RandomMod, U256};
     use rand_core::SeedableRng;
 
+    /// Number of samples drawn in the distribution tests.
+    const SAMPLES: usize = 10_000;
+
     #[test]
     fn random_mod() {
         let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
@@ -97,4 +133,78 @@ mod tests {
         // Check that the value is in range
         assert!(res < U256::from(0x10000000000000001u128));
     }
+
+    #[test]
+    fn random_in_range() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let lo = U256::from_u64(1000);
+        let hi = U256::from_u64(1010);
+        let mut counts = [0usize; 10];
+
+        for _ in 0..SAMPLES {
+            let res = U256::random_in_range(&mut rng, &lo, &hi);
+            assert!(res >= lo && res < hi);
+            counts[res.wrapping_sub(&lo).as_words()[0] as usize] += 1;
+        }
+
+        // Each value is expected `SAMPLES / 10` times, with a standard deviation of 30.
+        for count in counts {
+            assert!(
+                count.abs_diff(SAMPLES / 10) < 150,
+                "biased counts: {counts:?}"
+            );
+        }
+
+        // A range spanning multiple limbs
+        let lo = U256::from_u128(0x10000000000000001);
+        let hi = U256::MAX;
+        let res = U256::random_in_range(&mut rng, &lo, &hi);
+        assert!(res >= lo && res < hi);
+    }
+
+    #[test]
+    #[should_panic(expected = "empty range")]
+    fn random_in_range_empty() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        U256::random_in_range(&mut rng, &U256::ONE, &U256::ONE);
+    }
+
+    #[test]
+    fn random_with_bit_length() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+
+        for bit_length in [1, 2, 63, 64, 65, 255, 256] {
+            let res = U256::random_with_bit_length(&mut rng, bit_length);
+            assert_eq!(res.bits(), bit_length);
+        }
+
+        // The bits below the top one should be set with a probability of 1/2.
+        let mut counts = [0usize; 100];
+        for _ in 0..SAMPLES {
+            let res = U256::random_with_bit_length(&mut rng, 101);
+            assert_eq!(res.bits(), 101);
+            for (i, count) in counts.iter_mut().enumerate() {
+                *count += res.bit_vartime(i as u32) as usize;
+            }
+        }
+
+        // The expected count is `SAMPLES / 2` with a standard deviation of 50.
+        for count in counts {
+            assert!(count.abs_diff(SAMPLES / 2) < 250, "biased bits: {counts:?}");
+        }
+    }
+
+    #[test]
+    fn random_odd() {
+        let mut rng = rand_chacha::ChaCha8Rng::seed_from_u64(1);
+        let mut top_bits = 0;
+
+        for _ in 0..100 {
+            let res = U256::random_odd(&mut rng);
+            assert!(res.bit_vartime(0));
+            top_bits += res.bit_vartime(255) as usize;
+        }
+
+        assert!(top_bits > 0 && top_bits < 100);
+    }
 }
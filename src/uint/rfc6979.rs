@@ -0,0 +1,77 @@
+//! Integer conversions from RFC 6979 (section 2.3), used to derive deterministic nonces from hash
+//! output.
+
+use super::Uint;
+use crate::{CtChoice, Limb, NonZero};
+
+impl<const LIMBS: usize> Uint<LIMBS> {
+    /// Converts a bit string to an integer as `bits2int` in RFC 6979 (section 2.3.2): the big
+    /// endian `bytes` are truncated to their `qlen` leftmost bits, or implicitly padded with
+    /// leading zeros if they are shorter.
+    ///
+    /// This function is constant-time with respect to the value of `bytes`.
+    ///
+    /// Panics if `qlen` is zero or larger than [`Uint::BITS`].
+    pub fn bits2int(bytes: &[u8], qlen: u32) -> Self {
+        assert!(qlen > 0 && qlen <= Self::BITS, "qlen out of range");
+        let rlen = ((qlen + 7) / 8) as usize;
+        let bytes = &bytes[..bytes.len().min(rlen)];
+        let (x, _) = Self::from_be_slice_wide(bytes);
+
+        // Drop the excess low bits of the last byte if the input is longer than `qlen` bits.
+        let excess = (bytes.len() * 8).saturating_sub(qlen as usize);
+        x.shr_vartime(excess as u32)
+    }
+
+    /// Converts a bit string to an octet string as `bits2octets` in RFC 6979 (section 2.3.4),
+    /// i.e. writes `int2octets(bits2int(bytes) mod q)` into `out`, which must be exactly
+    /// `rlen = ceil(qlen / 8)` bytes long.
+    ///
+    /// This function is constant-time with respect to the value of `bytes`, and variable-time
+    /// with respect to `q`.
+    ///
+    /// Panics if `out` has the wrong length.
+    pub fn bits2octets(bytes: &[u8], q: &NonZero<Self>, out: &mut [u8]) {
+        let qlen = q.bits_vartime();
+        let rlen = ((qlen + 7) / 8) as usize;
+        assert_eq!(out.len(), rlen, "output must be ceil(qlen / 8) bytes long");
+
+        // Since `z1 < 2^qlen <= 2 * q`, a single conditional subtraction reduces it.
+        let z1 = Self::bits2int(bytes, qlen);
+        let (z2, borrow) = z1.sbb(q, Limb::ZERO);
+        let z2 = Self::ct_select(&z2, &z1, CtChoice::from_word_mask(borrow.0));
+
+        for (i, byte) in out.iter_mut().rev().enumerate() {
+            *byte = (z2.limbs[i / Limb::BYTES].0 >> (i % Limb::BYTES * 8)) as u8;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{NonZero, U192, U256};
+    use hex_literal::hex;
+
+    // Test vectors from RFC 6979 (section A.1.2), with `h1 = SHA-256("sample")`
+    const Q: U192 = U192::from_be_hex("00000004000000000000000000020108a2e0cc0d99f8a5ef");
+    const H1: [u8; 32] = hex!("af2bdbe1aa9b6ec1e2ade1d694f41fc71a831d0268e9891562113d8a62add1bf");
+
+    #[test]
+    fn bits2int() {
+        assert_eq!(
+            U192::bits2int(&H1, 163),
+            U192::from_be_hex("00000005795edf0d54db760f156f0eb4a7a0fe38d418e813")
+        );
+
+        // Shorter inputs are padded, not shifted
+        assert_eq!(U256::bits2int(&H1[..4], 163), U256::from_u32(0xaf2bdbe1));
+        assert_eq!(U256::bits2int(&H1, 256), U256::from_be_slice(&H1));
+    }
+
+    #[test]
+    fn bits2octets() {
+        let mut out = [0u8; 21];
+        U192::bits2octets(&H1, &NonZero::new(Q).unwrap(), &mut out);
+        assert_eq!(out, hex!("01795edf0d54db760f156d0dac04c0322b3a204224"));
+    }
+}
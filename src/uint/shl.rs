@@ -27,7 +27,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// When used with a fixed `shift`, this function is constant-time with respect
     /// to `self`.
     #[inline(always)]
-    pub const fn shl_vartime(&self, shift: u32) -> Self {
+    pub(crate) const fn shl_vartime(&self, shift: u32) -> Self {
         let mut limbs = [Limb::ZERO; LIMBS];
 
         if shift >= Self::BITS {
@@ -54,7 +54,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// When used with a fixed `shift`, this function is constant-time with respect
     /// to `self`.
     #[inline(always)]
-    pub const fn shl_vartime_wide(lower_upper: (Self, Self), shift: u32) -> (Self, Self) {
+    pub(crate) const fn shl_vartime_wide(lower_upper: (Self, Self), shift: u32) -> (Self, Self) {
         let (lower, mut upper) = lower_upper;
         let new_lower = lower.shl_vartime(shift);
         upper = upper.shl_vartime(shift);
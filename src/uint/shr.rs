@@ -28,7 +28,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// When used with a fixed `shift`, this function is constant-time with respect
     /// to `self`.
     #[inline(always)]
-    pub const fn shr_vartime(&self, shift: u32) -> Self {
+    pub(crate) const fn shr_vartime(&self, shift: u32) -> Self {
         let full_shifts = (shift / Limb::BITS) as usize;
         let small_shift = shift & (Limb::BITS - 1);
         let mut limbs = [Limb::ZERO; LIMBS];
@@ -68,7 +68,7 @@ impl<const LIMBS: usize> Uint<LIMBS> {
     /// When used with a fixed `shift`, this function is constant-time with respect
     /// to `self`.
     #[inline(always)]
-    pub const fn shr_vartime_wide(lower_upper: (Self, Self), shift: u32) -> (Self, Self) {
+    pub(crate) const fn shr_vartime_wide(lower_upper: (Self, Self), shift: u32) -> (Self, Self) {
         let (mut lower, upper) = lower_upper;
         let new_upper = upper.shr_vartime(shift);
         lower = lower.shr_vartime(shift);
@@ -36,7 +36,7 @@ proptest! {
         let inverter = BernsteinYangInverter::<6>::new(P.as_words(), &[1]);
         let actual = inverter.invert::<{U256::LIMBS}>(x.as_words());
 
-        prop_assert_eq!(bool::from(expected_is_some), actual.is_some());
+        prop_assert_eq!(expected_is_some, actual.is_some());
 
         if let Some(actual) = actual {
             let inv_bi = to_biguint(&U256::from(actual));
@@ -45,3 +45,79 @@ proptest! {
         }
     }
 }
+
+#[cfg(feature = "alloc")]
+mod boxed {
+    use crypto_bigint::{modular::BoxedBernsteinYangInverter, BoxedUint, Limb};
+    use num_bigint::{BigUint, ModInverse};
+    use proptest::prelude::*;
+
+    fn to_biguint(uint: &BoxedUint) -> BigUint {
+        BigUint::from_bytes_be(&uint.to_be_bytes())
+    }
+
+    prop_compose! {
+        /// Generate a random odd modulus along with a value of the same precision.
+        fn modulus_and_value()(mut bytes in any::<Vec<u8>>(), seed in any::<u64>()) -> (BoxedUint, BoxedUint) {
+            let extra = bytes.len() % Limb::BYTES;
+            let bytes_precision = bytes.len() - extra;
+            bytes.truncate(bytes_precision);
+            let bits_precision = bytes_precision as u32 * 8;
+
+            let mut modulus = BoxedUint::from_be_slice(&bytes, bits_precision).unwrap();
+            if modulus.is_even().into() {
+                modulus = modulus.wrapping_add(&BoxedUint::one());
+            }
+
+            let value_bytes: Vec<u8> = bytes
+                .iter()
+                .zip(seed.to_le_bytes().iter().cycle())
+                .map(|(a, b)| a.rotate_left(3) ^ b)
+                .collect();
+            let value = BoxedUint::from_be_slice(&value_bytes, bits_precision).unwrap();
+
+            (modulus, value)
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn inv_mod((modulus, value) in modulus_and_value()) {
+            let one = BoxedUint::one_with_precision(modulus.bits_precision());
+            let inverter = BoxedBernsteinYangInverter::new(&modulus, &one).unwrap();
+            let actual = Option::<BoxedUint>::from(inverter.invert(&value));
+
+            let modulus_bi = to_biguint(&modulus);
+            let value_bi = to_biguint(&value);
+            let expected = (&value_bi).mod_inverse(&modulus_bi);
+
+            match (expected, actual) {
+                (Some(exp), Some(act)) => {
+                    let exp = exp.to_biguint().unwrap() % &modulus_bi;
+                    prop_assert_eq!(exp, to_biguint(&act));
+                }
+                (None, None) => (),
+                (_, _) => panic!("disagreement on if modular inverse exists"),
+            }
+        }
+
+        #[test]
+        fn adjusted_inv_mod((modulus, value) in modulus_and_value(), adjuster in any::<u64>()) {
+            let modulus_bi = to_biguint(&modulus);
+            let adjuster_bi = BigUint::from(adjuster) % &modulus_bi;
+            let adjuster = BoxedUint::from_be_slice(
+                &adjuster_bi.to_bytes_be(),
+                modulus.bits_precision(),
+            );
+            prop_assume!(adjuster.is_ok());
+
+            let inverter = BoxedBernsteinYangInverter::new(&modulus, &adjuster.unwrap()).unwrap();
+            let actual = Option::<BoxedUint>::from(inverter.invert(&value));
+
+            if let Some(actual) = actual {
+                let product = (to_biguint(&actual) * to_biguint(&value)) % &modulus_bi;
+                prop_assert_eq!(product, adjuster_bi);
+            }
+        }
+    }
+}
@@ -0,0 +1,109 @@
+//! Equivalence tests between `crypto_bigint::BoundedUint`/`BoundedResidue` and `num-bigint`.
+
+use crypto_bigint::{
+    modular::{BoundedResidue, BoundedResidueParams},
+    BoundedUint, Limb, NonZero, Public,
+};
+use num_bigint::BigUint;
+use proptest::prelude::*;
+
+const MAX_LIMBS: usize = 8;
+
+type B = BoundedUint<MAX_LIMBS>;
+
+fn to_biguint(uint: &B) -> BigUint {
+    let mut bytes = vec![0u8; uint.nlimbs() * Limb::BYTES];
+    uint.write_le_bytes(&mut bytes);
+    BigUint::from_bytes_le(&bytes)
+}
+
+fn retrieve_biguint(residue: &BoundedResidue<MAX_LIMBS>) -> BigUint {
+    to_biguint(&residue.retrieve())
+}
+
+fn reduce(n: &B, p: BoundedResidueParams<MAX_LIMBS>) -> BoundedResidue<MAX_LIMBS> {
+    let modulus = NonZero::new(*p.modulus()).unwrap();
+    BoundedResidue::new(n.rem(&modulus), p)
+}
+
+prop_compose! {
+    /// Generate a random `BoundedUint` with a random precision.
+    fn uint()(nlimbs in 1..=MAX_LIMBS, bytes in any::<[u8; MAX_LIMBS * Limb::BYTES]>()) -> B {
+        let nbytes = nlimbs * Limb::BYTES;
+        B::from_be_slice(&bytes[..nbytes], nbytes as u32 * 8).unwrap()
+    }
+}
+prop_compose! {
+    /// Generate a random odd modulus.
+    fn modulus()(mut n in uint()) -> BoundedResidueParams<MAX_LIMBS> {
+        if n.is_even().into() {
+            n = n.wrapping_add(&B::one());
+        }
+
+        BoundedResidueParams::new(n).expect("modulus should be valid")
+    }
+}
+prop_compose! {
+    /// Generate two residues with a common modulus.
+    fn residue_pair()(a in uint(), b in uint(), n in modulus())
+        -> (BoundedResidue<MAX_LIMBS>, BoundedResidue<MAX_LIMBS>)
+    {
+        (reduce(&a, n), reduce(&b, n))
+    }
+}
+
+proptest! {
+    #[test]
+    fn wrapping_mul(a in uint(), b in uint()) {
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let expected = (a_bi * b_bi) % (BigUint::from(1u8) << a.bits_precision() as usize);
+
+        prop_assert_eq!(to_biguint(&a.wrapping_mul(&b)), expected);
+    }
+
+    #[test]
+    fn div_rem(a in uint(), b in uint()) {
+        prop_assume!(!bool::from(b.is_zero()));
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        let (q, r) = a.div_rem(&NonZero::new(b).unwrap());
+        prop_assert_eq!(to_biguint(&q), &a_bi / &b_bi);
+        prop_assert_eq!(to_biguint(&r), &a_bi % &b_bi);
+    }
+
+    #[test]
+    fn add_mod((a, b) in residue_pair()) {
+        let p = to_biguint(a.params().modulus());
+        let expected = (retrieve_biguint(&a) + retrieve_biguint(&b)) % p;
+
+        prop_assert_eq!(retrieve_biguint(&(a + b)), expected);
+    }
+
+    #[test]
+    fn sub_mod((a, b) in residue_pair()) {
+        let p = to_biguint(a.params().modulus());
+        let expected = (retrieve_biguint(&a) + &p - retrieve_biguint(&b)) % p;
+
+        prop_assert_eq!(retrieve_biguint(&(a - b)), expected);
+    }
+
+    #[test]
+    fn mul_mod((a, b) in residue_pair()) {
+        let p = to_biguint(a.params().modulus());
+        let expected = (retrieve_biguint(&a) * retrieve_biguint(&b)) % p;
+
+        prop_assert_eq!(retrieve_biguint(&(a * b)), expected);
+    }
+
+    #[test]
+    fn pow((a, b) in residue_pair()) {
+        let p = to_biguint(a.params().modulus());
+        let exponent = b.retrieve();
+        let expected = retrieve_biguint(&a).modpow(&to_biguint(&exponent), &p);
+
+        prop_assert_eq!(retrieve_biguint(&a.pow(&exponent)), expected.clone());
+        prop_assert_eq!(retrieve_biguint(&a.pow_vartime(&Public::new(exponent))), expected);
+    }
+}
@@ -3,8 +3,8 @@
 #![cfg(feature = "alloc")]
 
 use crypto_bigint::{
-    modular::{BoxedResidue, BoxedResidueParams},
-    BoxedUint, Limb, NonZero,
+    modular::{BoxedResidue, BoxedResidueParams, BoxedResidueScratch},
+    BoxedUint, Limb, NonZero, Public,
 };
 use num_bigint::{BigUint, ModInverse};
 use proptest::prelude::*;
@@ -20,7 +20,7 @@ fn retrieve_biguint(residue: &BoxedResidue) -> BigUint {
 
 fn reduce(n: &BoxedUint, p: BoxedResidueParams) -> BoxedResidue {
     let bits_precision = p.modulus().bits_precision();
-    let modulus = NonZero::new(p.modulus().clone()).unwrap();
+    let modulus = Public::new(NonZero::new(p.modulus().clone()).unwrap());
 
     let n = match n.bits_precision().cmp(&bits_precision) {
         Ordering::Less => n.widen(bits_precision),
@@ -28,7 +28,7 @@ fn reduce(n: &BoxedUint, p: BoxedResidueParams) -> BoxedResidue {
         Ordering::Greater => n.shorten(bits_precision),
     };
 
-    let n_reduced = n.rem_vartime(&modulus).widen(p.bits_precision());
+    let n_reduced = (n % &modulus).widen(p.bits_precision());
     BoxedResidue::new(n_reduced, p)
 }
 
@@ -66,8 +66,30 @@ proptest! {
         }
 
         let params1 = BoxedResidueParams::new(n.clone()).unwrap();
-        let params2 = BoxedResidueParams::new_vartime(n).unwrap();
-        prop_assert_eq!(params1, params2);
+        let params2 = Public::<BoxedResidueParams>::new_vartime(Public::new(n)).unwrap();
+        prop_assert_eq!(params1, params2.into_inner());
+    }
+
+    #[test]
+    fn from_precomputed(n in modulus()) {
+        let precomputed = BoxedResidueParams::from_precomputed(
+            n.modulus().clone(),
+            n.r().clone(),
+            n.r2().clone(),
+            n.r3().clone(),
+            n.mod_neg_inv(),
+        );
+        prop_assert_eq!(Option::from(precomputed), Some(n.clone()));
+        prop_assert_eq!(BoxedResidueParams::from_be_bytes(&n.to_be_bytes()), Some(n));
+    }
+
+    #[test]
+    fn reduce_wide_bytes(mut bytes in any::<Vec<u8>>(), n in modulus()) {
+        bytes.truncate(n.bits_precision() as usize / 4);
+        let expected = BigUint::from_bytes_be(&bytes) % to_biguint(n.modulus());
+
+        let actual = BoxedResidue::reduce_wide_bytes(&bytes, n);
+        prop_assert_eq!(retrieve_biguint(&actual), expected);
     }
 
     #[test]
@@ -93,12 +115,87 @@ proptest! {
 
         let a_bi = retrieve_biguint(&a);
         let b_bi = retrieve_biguint(&b);
-        let p_bi = to_biguint(&p);
+        let p_bi = to_biguint(p);
         let expected = (a_bi * b_bi) % p_bi;
 
         prop_assert_eq!(retrieve_biguint(&actual), expected);
     }
 
+    #[test]
+    fn sum_of_products(pairs in proptest::collection::vec(residue_pair(), 1..32)) {
+        // Use the parameters of the first pair for all of them
+        let params = pairs[0].0.params().clone();
+        let pairs = pairs
+            .into_iter()
+            .map(|(a, b)| (reduce(&a.retrieve(), params.clone()), reduce(&b.retrieve(), params.clone())))
+            .collect::<Vec<_>>();
+
+        let p_bi = to_biguint(params.modulus());
+        let expected = pairs
+            .iter()
+            .fold(BigUint::from(0u8), |acc, (a, b)| acc + retrieve_biguint(a) * retrieve_biguint(b))
+            % &p_bi;
+
+        prop_assert_eq!(retrieve_biguint(&BoxedResidue::sum_of_products(&pairs)), expected);
+    }
+
+    #[test]
+    fn sum_of_products_of_products(
+        n in (1u64 << 63)..(3u64 << 62),
+        pairs in proptest::collection::vec(any::<[u64; 4]>(), 1..32),
+    ) {
+        // Products of products modulo a modulus slightly above `2^63` are the most likely to be
+        // left unreduced by an "Almost Montgomery Multiplication".
+        let params = BoxedResidueParams::new(BoxedUint::from(n | 1)).unwrap();
+        let pairs = pairs
+            .into_iter()
+            .map(|words| {
+                let [a, b, c, d] = words.map(|word| reduce(&BoxedUint::from(word), params.clone()));
+                let ab = &a * &b;
+                let cd = &c * &d;
+                (&ab * &cd, &cd * &ab)
+            })
+            .collect::<Vec<_>>();
+
+        let p_bi = to_biguint(params.modulus());
+        let expected = pairs
+            .iter()
+            .fold(BigUint::from(0u8), |acc, (a, b)| acc + retrieve_biguint(a) * retrieve_biguint(b))
+            % &p_bi;
+
+        prop_assert_eq!(retrieve_biguint(&BoxedResidue::sum_of_products(&pairs)), expected);
+    }
+
+    #[test]
+    fn mul_by_small((a, _) in residue_pair(), k in any::<u32>()) {
+        let a_bi = retrieve_biguint(&a);
+        let p_bi = to_biguint(a.params().modulus());
+
+        prop_assert_eq!(retrieve_biguint(&a.mul_by_small(k)), (&a_bi * k) % &p_bi);
+        prop_assert_eq!(retrieve_biguint(&a.double()), (&a_bi * 2u32) % &p_bi);
+        prop_assert_eq!(retrieve_biguint(&a.triple()), (&a_bi * 3u32) % &p_bi);
+    }
+
+    #[test]
+    fn mul_by_small_of_product((a, b) in residue_pair(), k in any::<u32>()) {
+        let ab = &a * &b;
+        let ab_bi = retrieve_biguint(&ab);
+        let p_bi = to_biguint(a.params().modulus());
+
+        prop_assert_eq!(retrieve_biguint(&ab.mul_by_small(k)), (&ab_bi * k) % &p_bi);
+        prop_assert_eq!(retrieve_biguint(&ab.double()), (&ab_bi * 2u32) % &p_bi);
+        prop_assert_eq!(retrieve_biguint(&ab.triple()), (&ab_bi * 3u32) % &p_bi);
+    }
+
+    #[test]
+    fn div_by_2((a, _) in residue_pair()) {
+        let a_bi = retrieve_biguint(&a);
+        let p_bi = to_biguint(a.params().modulus());
+        let actual = retrieve_biguint(&a.div_by_2());
+
+        prop_assert_eq!((actual * 2u32) % &p_bi, a_bi);
+    }
+
     #[test]
     fn pow(a in uint(), b in uint(), n in modulus()) {
         let a = reduce(&a, n.clone());
@@ -111,4 +208,49 @@ proptest! {
 
         prop_assert_eq!(retrieve_biguint(&actual), expected);
     }
+
+    #[test]
+    fn pow_vartime(a in uint(), b in uint(), n in modulus()) {
+        let a = reduce(&a, n.clone());
+        let actual = a.pow_vartime(&Public::new(b.clone()));
+
+        let a_bi = retrieve_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let n_bi = to_biguint(n.modulus());
+        let expected = a_bi.modpow(&b_bi, &n_bi);
+
+        prop_assert_eq!(retrieve_biguint(&actual), expected);
+    }
+
+    #[test]
+    fn multi_exponentiate_vartime(a in uint(), b in uint(), c in uint(), d in uint(), n in modulus()) {
+        let a = reduce(&a, n.clone());
+        let c = reduce(&c, n.clone());
+        let actual = BoxedResidue::multi_exponentiate_vartime(&[(a.clone(), Public::new(b.clone())), (c.clone(), Public::new(d.clone()))]);
+
+        let n_bi = to_biguint(n.modulus());
+        let expected = (retrieve_biguint(&a).modpow(&to_biguint(&b), &n_bi)
+            * retrieve_biguint(&c).modpow(&to_biguint(&d), &n_bi))
+            % &n_bi;
+
+        prop_assert_eq!(retrieve_biguint(&actual), expected);
+    }
+
+    #[test]
+    fn pow_assign_with_scratch(a in uint(), b in uint(), n in modulus()) {
+        let a = reduce(&a, n.clone());
+        let expected = a.pow(&b);
+
+        let mut scratch = BoxedResidueScratch::new(&n);
+        let mut actual = a.clone();
+        actual.pow_assign_with_scratch(&b, &mut scratch);
+        prop_assert_eq!(retrieve_biguint(&actual), retrieve_biguint(&expected));
+
+        // The scratch space can be reused
+        let mut actual = a.clone();
+        actual.square_assign_with_scratch(&mut scratch);
+        prop_assert_eq!(retrieve_biguint(&actual), retrieve_biguint(&a.square()));
+        actual.mul_assign_with_scratch(&a, &mut scratch);
+        prop_assert_eq!(retrieve_biguint(&actual), retrieve_biguint(&(&a.square() * &a)));
+    }
 }
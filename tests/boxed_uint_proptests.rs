@@ -3,8 +3,12 @@
 #![cfg(feature = "alloc")]
 
 use core::cmp::Ordering;
-use crypto_bigint::{BoxedUint, CheckedAdd, Limb, NonZero};
+use crypto_bigint::{
+    modular::{BoxedResidueParams, BoxedResidueScratch},
+    BoxedUint, CheckedAdd, Limb, MultiLimbReciprocal, NonZero, Public,
+};
 use num_bigint::{BigUint, ModInverse};
+use num_traits::identities::Zero;
 use proptest::prelude::*;
 
 fn to_biguint(uint: &BoxedUint) -> BigUint {
@@ -21,7 +25,7 @@ fn to_uint(big_uint: BigUint) -> BoxedUint {
 
 fn reduce(x: &BoxedUint, n: &BoxedUint) -> BoxedUint {
     let bits_precision = n.bits_precision();
-    let modulus = NonZero::new(n.clone()).expect("odd n");
+    let modulus = Public::new(NonZero::new(n.clone()).expect("odd n"));
 
     let x = match x.bits_precision().cmp(&bits_precision) {
         Ordering::Less => x.widen(bits_precision),
@@ -29,7 +33,7 @@ fn reduce(x: &BoxedUint, n: &BoxedUint) -> BoxedUint {
         Ordering::Greater => x.shorten(bits_precision),
     };
 
-    let x_reduced = x.rem_vartime(&modulus);
+    let x_reduced = x % &modulus;
     debug_assert_eq!(x_reduced.bits_precision(), bits_precision);
     x_reduced
 }
@@ -76,7 +80,7 @@ proptest! {
     fn bits(a in uint()) {
         let expected = to_biguint(&a).bits() as u32;
         assert_eq!(expected, a.bits());
-        assert_eq!(expected, a.bits_vartime());
+        assert_eq!(expected, Public::new(a).bits_vartime());
     }
 
     #[test]
@@ -124,6 +128,52 @@ proptest! {
         prop_assert_eq!(expected_remainder, to_biguint(&actual_remainder));
     }
 
+    #[test]
+    fn div_rem_mixed_precision(a in uint(), b in uint()) {
+        if bool::from(!b.is_zero()) {
+            let a_bi = to_biguint(&a);
+            let b_bi = to_biguint(&b);
+
+            let b = NonZero::new(b).unwrap();
+            let (quo, rem) = a.div_rem(&b);
+            prop_assert_eq!(quo.bits_precision(), a.bits_precision());
+            prop_assert_eq!(rem.bits_precision(), b.bits_precision());
+            prop_assert_eq!(to_biguint(&quo), &a_bi / &b_bi);
+            prop_assert_eq!(to_biguint(&rem), &a_bi % &b_bi);
+
+            let a = Public::new(a);
+            let b = Public::new(b);
+            let (quo_vartime, rem_vartime) = a.div_rem_vartime(&b);
+            prop_assert_eq!(quo_vartime.into_inner(), quo);
+            prop_assert_eq!(rem_vartime.into_inner(), rem.clone());
+            prop_assert_eq!(a.rem_vartime(&b).into_inner(), rem);
+        }
+    }
+
+    #[test]
+    fn to_digits(a in uint(), radix in 2u32..=256) {
+        let digits = a.to_digits(radix);
+        prop_assert_eq!(&digits, &to_biguint(&a).to_radix_be(radix));
+        let decoded = BoxedUint::from_digits(radix, &digits, a.bits_precision());
+        prop_assert_eq!(decoded.unwrap(), a.clone());
+
+        let mut fixed = vec![0u8; digits.len() + 2];
+        prop_assert!(bool::from(a.to_digits_fixed(radix, &mut fixed)));
+        prop_assert_eq!(&fixed[..2], &[0, 0]);
+        prop_assert_eq!(&fixed[2..], &digits[..]);
+    }
+
+    #[test]
+    fn div_rem_limb(a in uint(), b in any::<u32>()) {
+        let b = Limb::from(b.max(1));
+        let a_bi = to_biguint(&a);
+        let b_bi = BigUint::from(b.0);
+
+        let (quo, rem) = a.div_rem_limb(NonZero::new(b).unwrap());
+        prop_assert_eq!(to_biguint(&quo), &a_bi / &b_bi);
+        prop_assert_eq!(BigUint::from(rem.0), &a_bi % &b_bi);
+    }
+
     #[test]
     fn div_rem_vartime((a, mut b) in uint_pair()) {
         if b.is_zero().into() {
@@ -135,9 +185,36 @@ proptest! {
         let expected_quotient = &a_bi / &b_bi;
         let expected_remainder = a_bi % b_bi;
 
-        let (actual_quotient, actual_remainder) = a.div_rem_vartime(&NonZero::new(b).unwrap());
-        prop_assert_eq!(expected_quotient, to_biguint(&actual_quotient));
-        prop_assert_eq!(expected_remainder, to_biguint(&actual_remainder));
+        let (actual_quotient, actual_remainder) =
+            Public::new(a).div_rem_vartime(&Public::new(NonZero::new(b).unwrap()));
+        prop_assert_eq!(expected_quotient, to_biguint(actual_quotient.as_inner()));
+        prop_assert_eq!(expected_remainder, to_biguint(actual_remainder.as_inner()));
+    }
+
+    #[test]
+    fn div_rem_with_reciprocal((a, b) in uint_pair()) {
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        let reciprocal = MultiLimbReciprocal::<BoxedUint>::new(&b);
+        prop_assert_eq!(bool::from(reciprocal.is_some()), !b_bi.is_zero());
+
+        if !b_bi.is_zero() {
+            let (quo, rem) = a.div_rem_with_reciprocal(&reciprocal.unwrap());
+            prop_assert_eq!(to_biguint(&quo), &a_bi / &b_bi);
+            prop_assert_eq!(to_biguint(&rem), &a_bi % &b_bi);
+        }
+    }
+
+    #[test]
+    fn div_exact((a, b) in uint_pair()) {
+        // Truncate the factors so that their product fits
+        let half = a.bits_precision() / 2;
+        let a = a.shr(half);
+        let b = b.shr(half).wrapping_add(&BoxedUint::one());
+        let product = a.wrapping_mul(&b);
+
+        prop_assert_eq!(product.div_exact(&NonZero::new(b).unwrap()), a);
     }
 
     #[test]
@@ -162,6 +239,27 @@ proptest! {
         }
     }
 
+    #[test]
+    fn mod_inv_even(a in uint(), (s, k) in (uint(), any::<u8>())) {
+        // `modulus = s * 2^k` with `k` up to the bit precision of the modulus
+        let bits_precision = a.bits_precision().max(s.bits_precision()).max(Limb::BITS);
+        let a = a.widen(bits_precision);
+        let k = u32::from(k) % bits_precision;
+        let modulus = s.widen(bits_precision).shl(k);
+        prop_assume!(bool::from(!modulus.is_zero()));
+
+        let a_bi = to_biguint(&a);
+        let modulus_bi = to_biguint(&modulus);
+        let expected = a_bi.mod_inverse(modulus_bi);
+        let actual = Option::from(a.inv_mod(&modulus));
+
+        match (expected, actual) {
+            (Some(exp), Some(act)) => prop_assert_eq!(exp, to_biguint(&act).into()),
+            (None, None) => (),
+            (_, _) => panic!("disagreement on if modular inverse exists")
+        }
+    }
+
     #[test]
     fn mul_mod(a in uint(), b in uint(), n in modulus()) {
         let a = reduce(&a, &n);
@@ -173,7 +271,30 @@ proptest! {
 
         let expected = to_uint((a_bi * b_bi) % n_bi);
         let actual = a.mul_mod(&b, &n);
-        assert_eq!(expected, actual);
+        assert_eq!(&expected, &actual);
+
+        let params = BoxedResidueParams::new(n).unwrap();
+        let mut scratch = BoxedResidueScratch::new(&params);
+        let mut out = BoxedUint::zero_with_precision(params.bits_precision());
+        a.mul_mod_into(&b, &params, &mut scratch, &mut out);
+        prop_assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn mul_mod_even(a in uint(), b in uint(), n in uint()) {
+        let n = (n << 1).wrapping_add(&BoxedUint::one()) << 1;
+        if bool::from(!n.is_zero()) {
+            let a = reduce(&a, &n);
+            let b = reduce(&b, &n);
+
+            let a_bi = to_biguint(&a);
+            let b_bi = to_biguint(&b);
+            let n_bi = to_biguint(&n);
+
+            let expected = (a_bi * b_bi) % n_bi;
+            let actual = a.mul_mod(&b, &n);
+            prop_assert_eq!(expected, to_biguint(&actual));
+        }
     }
 
     #[test]
@@ -207,9 +328,9 @@ proptest! {
             let b_bi = to_biguint(&b);
 
             let expected = a_bi % b_bi;
-            let actual = a.rem_vartime(&NonZero::new(b).unwrap());
+            let actual = Public::new(a).rem_vartime(&Public::new(NonZero::new(b).unwrap()));
 
-            prop_assert_eq!(expected, to_biguint(&actual));
+            prop_assert_eq!(expected, to_biguint(actual.as_inner()));
         }
     }
 }
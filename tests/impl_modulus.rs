@@ -1,5 +1,57 @@
 //! Test to ensure that `impl_modulus!` works from outside this crate.
 
-use crypto_bigint::{impl_modulus, U64};
+use crypto_bigint::{impl_modulus, modular::ResidueParams, U256, U64};
 
 impl_modulus!(TestMod, U64, "30e4b8f030ab42f3");
+impl_modulus!(ShortHexMod, U64, "30ab42f3");
+impl_modulus!(DecimalMod, U64, dec "3523144150258762483");
+impl_modulus!(Curve25519, U256, 2 ^ 255 - 19);
+impl_modulus!(P256, U256, 2 ^ 256 - 2 ^ 224 + 2 ^ 192 + 2 ^ 96 - 1);
+
+#[test]
+fn modulus_forms() {
+    assert_eq!(ShortHexMod::MODULUS, U64::from_be_hex("0000000030ab42f3"));
+    assert_eq!(DecimalMod::MODULUS, TestMod::MODULUS);
+    assert_eq!(
+        Curve25519::MODULUS,
+        U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffed")
+    );
+    assert_eq!(
+        P256::MODULUS,
+        U256::from_be_hex("ffffffff00000001000000000000000000000000ffffffffffffffffffffffff")
+    );
+}
+
+#[test]
+fn extra_constants() {
+    assert_eq!(TestMod::MODULUS_BITS, 62);
+    assert_eq!(Curve25519::MODULUS_BITS, 255);
+    assert_eq!(
+        Curve25519::MODULUS_MINUS_TWO,
+        U256::from_be_hex("7fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeb")
+    );
+    assert_eq!(
+        P256::MODULUS_PLUS_ONE_DIV_FOUR,
+        U256::from_be_hex("3fffffffc0000000400000000000000000000000400000000000000000000000")
+    );
+}
+
+mod prime {
+    use crypto_bigint::{
+        impl_multiplicative_generator, impl_prime_modulus,
+        modular::{GeneratorParams, PrimeParams},
+        U256, U64,
+    };
+
+    impl_prime_modulus!(P256, U256, 2 ^ 256 - 2 ^ 224 + 2 ^ 192 + 2 ^ 96 - 1);
+    impl_prime_modulus!(Goldilocks, U64, 2 ^ 64 - 2 ^ 32 + 1);
+    impl_multiplicative_generator!(Goldilocks, U64, 7, ["2", "3", "5", "11", "101", "10001"]);
+
+    #[test]
+    fn prime_params() {
+        assert_eq!(P256::S, 1);
+        assert_eq!(Goldilocks::S, 32);
+        assert_eq!(Goldilocks::NON_RESIDUE.retrieve(), U64::from_u8(7));
+        assert_eq!(Goldilocks::GENERATOR.retrieve(), U64::from_u8(7));
+    }
+}
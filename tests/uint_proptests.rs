@@ -2,7 +2,7 @@
 
 use crypto_bigint::{
     modular::{DynResidue, DynResidueParams},
-    CtChoice, Encoding, Limb, NonZero, Word, U256,
+    CtChoice, Encoding, Limb, MultiLimbReciprocal, NonZero, Public, Word, U256,
 };
 use num_bigint::BigUint;
 use num_integer::Integer;
@@ -53,7 +53,7 @@ proptest! {
     fn bits(a in uint()) {
         let expected = to_biguint(&a).bits() as u32;
         assert_eq!(expected, a.bits());
-        assert_eq!(expected, a.bits_vartime());
+        assert_eq!(expected, Public::new(a).bits_vartime());
     }
 
     #[test]
@@ -61,7 +61,7 @@ proptest! {
         let a_bi = to_biguint(&a);
 
         let expected = to_uint(a_bi << shift.into());
-        let actual = a.shl_vartime(shift.into());
+        let actual = Public::new(a).shl_vartime(shift.into()).into_inner();
 
         assert_eq!(expected, actual);
     }
@@ -196,11 +196,52 @@ proptest! {
             let expected = to_uint(a_bi / b_bi);
             let actual = a.wrapping_div(&b);
             assert_eq!(expected, actual);
-            let actual_vartime = a.wrapping_div_vartime(&b);
+            let actual_vartime = Public::new(a).wrapping_div_vartime(&Public::new(b)).into_inner();
             assert_eq!(expected, actual_vartime);
         }
     }
 
+    #[test]
+    fn div_rem_with_reciprocal(a in uint(), b in uint()) {
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+
+        let reciprocal = MultiLimbReciprocal::<U256>::new(&b);
+        prop_assert_eq!(bool::from(reciprocal.is_some()), !b_bi.is_zero());
+
+        if !b_bi.is_zero() {
+            let (quo, rem) = a.div_rem_with_reciprocal(&reciprocal.unwrap());
+            prop_assert_eq!(to_biguint(&quo), &a_bi / &b_bi);
+            prop_assert_eq!(to_biguint(&rem), &a_bi % &b_bi);
+        }
+    }
+
+    #[test]
+    fn div_exact(a in uint(), b in uint(), shift in any::<u8>()) {
+        // Truncate the factors so that their product fits
+        let a = a >> 128;
+        let b = (b >> (128 + u32::from(shift % 128))).wrapping_add(&U256::ONE);
+        let product = a.wrapping_mul(&b);
+
+        prop_assert_eq!(product.div_exact(&NonZero::new(b).unwrap()), a);
+    }
+
+    #[test]
+    fn to_digits_fixed(a in uint(), radix in 2u32..=256) {
+        let expected = to_biguint(&a).to_radix_be(radix);
+
+        let mut digits = vec![0u8; expected.len() + 2];
+        prop_assert!(bool::from(a.to_digits_fixed(radix, &mut digits)));
+        prop_assert_eq!(&digits[..2], &[0, 0]);
+        prop_assert_eq!(&digits[2..], &expected[..]);
+        prop_assert_eq!(U256::from_digits(radix, &digits).unwrap(), a);
+
+        if a != U256::ZERO {
+            let mut digits = vec![0u8; expected.len() - 1];
+            prop_assert!(!bool::from(a.to_digits_fixed(radix, &mut digits)));
+        }
+    }
+
     #[test]
     fn div_rem_limb(a in uint(), b in nonzero_limb()) {
         let a_bi = to_biguint(&a);
@@ -246,7 +287,7 @@ proptest! {
         let m_bi = BigUint::one() << k as usize;
 
         let actual = a.inv_mod2k(k);
-        let actual_vartime = a.inv_mod2k_vartime(k);
+        let actual_vartime = Public::new(a).inv_mod2k_vartime(k).into_inner();
         assert_eq!(actual, actual_vartime);
 
         if k == 0 {
@@ -265,11 +306,11 @@ proptest! {
         let b_bi = to_biguint(&b);
 
         let expected_is_some = if a_bi.gcd(&b_bi) == BigUint::one() { CtChoice::TRUE } else { CtChoice::FALSE };
-        let (actual, actual_is_some) = a.inv_mod(&b);
+        let actual = a.inv_mod(&b);
 
-        assert_eq!(bool::from(expected_is_some), bool::from(actual_is_some));
+        assert_eq!(bool::from(expected_is_some), bool::from(actual.is_some()));
 
-        if actual_is_some.into() {
+        if let Some(actual) = actual.into_option() {
             let inv_bi = to_biguint(&actual);
             let res = (inv_bi * a_bi) % b_bi;
             assert_eq!(res, BigUint::one());
@@ -282,7 +323,7 @@ proptest! {
         let expected = to_uint(a_bi.sqrt());
         let actual_ct = a.wrapping_sqrt();
         assert_eq!(expected, actual_ct);
-        let actual_vartime = a.wrapping_sqrt_vartime();
+        let actual_vartime = Public::new(a).wrapping_sqrt_vartime().into_inner();
         assert_eq!(expected, actual_vartime);
     }
 
@@ -373,6 +414,70 @@ proptest! {
         assert_eq!(expected, actual);
     }
 
+    #[test]
+    fn residue_pow_vartime(a in uint_mod_p(P), b in uint(), exponent_bits in any::<u8>()) {
+        let b = b >> (255 - u32::from(exponent_bits));
+
+        let a_bi = to_biguint(&a);
+        let b_bi = to_biguint(&b);
+        let p_bi = to_biguint(&P);
+
+        let expected = to_uint(a_bi.modpow(&b_bi, &p_bi));
+
+        let params = DynResidueParams::new(&P).unwrap();
+        let a_m = DynResidue::new(&a, params);
+        let actual = a_m.pow_vartime(&Public::new(b)).retrieve();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn residue_multi_exponentiate_vartime(a in uint_mod_p(P), b in uint(), c in uint_mod_p(P), d in uint()) {
+        let p_bi = to_biguint(&P);
+        let expected = to_uint(
+            (to_biguint(&a).modpow(&to_biguint(&b), &p_bi)
+                * to_biguint(&c).modpow(&to_biguint(&d), &p_bi))
+                % &p_bi,
+        );
+
+        let params = DynResidueParams::new(&P).unwrap();
+        let a_m = DynResidue::new(&a, params);
+        let c_m = DynResidue::new(&c, params);
+        let actual = DynResidue::multi_exponentiate_vartime(&[(a_m, Public::new(b)), (c_m, Public::new(d))]).retrieve();
+
+        assert_eq!(expected, actual);
+    }
+
+    #[test]
+    fn residue_sum_of_products(pairs in proptest::collection::vec((uint_mod_p(P), uint_mod_p(P)), 1..40)) {
+        let p_bi = to_biguint(&P);
+        let expected = pairs
+            .iter()
+            .fold(BigUint::zero(), |acc, (a, b)| acc + to_biguint(a) * to_biguint(b))
+            % &p_bi;
+
+        let params = DynResidueParams::new(&P).unwrap();
+        let pairs = pairs
+            .iter()
+            .map(|(a, b)| (DynResidue::new(a, params), DynResidue::new(b, params)))
+            .collect::<Vec<_>>();
+
+        assert_eq!(DynResidue::sum_of_products(&pairs).retrieve(), to_uint(expected));
+    }
+
+    #[test]
+    fn residue_mul_by_small(a in uint_mod_p(P), k in any::<u32>()) {
+        let a_bi = to_biguint(&a);
+        let p_bi = to_biguint(&P);
+
+        let params = DynResidueParams::new(&P).unwrap();
+        let a_m = DynResidue::new(&a, params);
+
+        assert_eq!(a_m.mul_by_small(k).retrieve(), to_uint((&a_bi * k) % &p_bi));
+        assert_eq!(a_m.double().retrieve(), to_uint((&a_bi * 2u32) % &p_bi));
+        assert_eq!(a_m.triple().retrieve(), to_uint((&a_bi * 3u32) % &p_bi));
+    }
+
     #[test]
     fn residue_div_by_2(a in uint_mod_p(P)) {
         let a_bi = to_biguint(&a);